@@ -10,16 +10,23 @@
 //!
 //! [`Simulacrum`]: crate::Simulacrum
 
+use std::collections::BTreeMap;
 use std::num::NonZeroUsize;
 
 use anyhow::{anyhow, Result};
 use fastcrypto::traits::Signer;
+use fastcrypto_zkp::bn254::zk_login::{JwkId, JWK};
+use fastcrypto_zkp::bn254::zk_login_api::ZkLoginEnv;
+use im::hashmap::HashMap as ImHashMap;
 use rand::rngs::OsRng;
 use sui_config::{genesis, transaction_deny_config::TransactionDenyConfig};
 use sui_protocol_config::ProtocolVersion;
 use sui_swarm_config::genesis_config::AccountConfig;
 use sui_swarm_config::network_config::NetworkConfig;
 use sui_swarm_config::network_config_builder::ConfigBuilder;
+use sui_types::authenticator_state::{
+    get_authenticator_state_obj_initial_shared_version, ActiveJwk,
+};
 use sui_types::base_types::{AuthorityName, ObjectID, VersionNumber};
 use sui_types::crypto::AuthoritySignature;
 use sui_types::error::SuiError;
@@ -32,6 +39,7 @@ use sui_types::{
     error::ExecutionError,
     gas_coin::MIST_PER_SUI,
     inner_temporary_store::InnerTemporaryStore,
+    message_envelope::Message,
     messages_checkpoint::{EndOfEpochData, VerifiedCheckpoint},
     signature::VerifyParams,
     transaction::{Transaction, VerifiedTransaction},
@@ -67,6 +75,8 @@ pub struct Simulacrum<R = OsRng, Store: SimulatorStore = InMemoryStore> {
 
     // Other
     deny_config: TransactionDenyConfig,
+    /// JWKs that zkLogin signatures are verified against.
+    jwks: ImHashMap<JwkId, JWK>,
 }
 
 impl Simulacrum {
@@ -136,6 +146,7 @@ where
             checkpoint_builder,
             epoch_state,
             deny_config: TransactionDenyConfig::default(),
+            jwks: ImHashMap::new(),
         }
     }
 }
@@ -156,7 +167,14 @@ impl<R> Simulacrum<R> {
         &mut self,
         transaction: Transaction,
     ) -> anyhow::Result<(TransactionEffects, Option<ExecutionError>)> {
-        let transaction = transaction.verify(&VerifyParams::default())?;
+        transaction.data().verify_epoch(self.epoch_state.epoch())?;
+        let verify_params = VerifyParams::new(
+            self.jwks.clone(),
+            vec![],
+            ZkLoginEnv::Test,
+            /* verify_legacy_zklogin_address */ false,
+        );
+        let transaction = transaction.verify(&verify_params)?;
 
         let (inner_temporary_store, effects, execution_error_opt) = self
             .epoch_state
@@ -191,6 +209,12 @@ impl<R> Simulacrum<R> {
         checkpoint
     }
 
+    /// The number of transactions executed since the last checkpoint was created, which the next
+    /// call to [`Simulacrum::create_checkpoint`] will include.
+    pub fn pending_checkpoint_tx_count(&self) -> usize {
+        self.checkpoint_builder.size()
+    }
+
     /// Advances the clock by `duration`.
     ///
     /// This creates and executes a ConsensusCommitPrologue transaction which advances the chain
@@ -213,11 +237,19 @@ impl<R> Simulacrum<R> {
     /// epoch. Since the EpochChange transaction is required to be the final transaction in an
     /// epoch, the final checkpoint in the epoch is also created.
     ///
-    /// NOTE: This function does not currently support updating the protocol version or the system
-    /// packages
+    /// NOTE: This function does not currently support updating the system packages
     pub fn advance_epoch(&mut self) {
+        self.advance_epoch_with_protocol_version(self.epoch_state.protocol_version());
+    }
+
+    /// Advances the epoch, like [`Self::advance_epoch`], switching to `next_epoch_protocol_version`
+    /// for the next epoch. Transactions in the next epoch are executed under the rules of the new
+    /// version, but the system packages are left as they are.
+    pub fn advance_epoch_with_protocol_version(
+        &mut self,
+        next_epoch_protocol_version: ProtocolVersion,
+    ) {
         let next_epoch = self.epoch_state.epoch() + 1;
-        let next_epoch_protocol_version = self.epoch_state.protocol_version();
         let gas_cost_summary = self.checkpoint_builder.epoch_rolling_gas_cost_summary();
         let epoch_start_timestamp_ms = self.store.get_clock().timestamp_ms();
         let next_epoch_system_package_bytes = vec![];
@@ -254,6 +286,66 @@ impl<R> Simulacrum<R> {
         self.epoch_state = new_epoch_state;
     }
 
+    /// Inserts `object` directly into the store, without executing a transaction.
+    ///
+    /// This is intended for setting up state that cannot be reached through transactions, like a
+    /// shared object with a particular initial shared version.
+    pub fn insert_object(&mut self, object: Object) {
+        self.store
+            .update_objects(BTreeMap::from([(object.id(), object)]), vec![]);
+    }
+
+    /// Removes the object with id `id` from the live objects, without executing a transaction.
+    /// Its past versions stay in the store.
+    pub fn remove_object(&mut self, id: ObjectID) {
+        if let Some(object) = self.store.get_object(&id) {
+            let object_ref = object.compute_object_reference();
+            self.store.update_objects(BTreeMap::new(), vec![object_ref]);
+        }
+    }
+
+    /// Accept zkLogin signatures whose proofs were issued against `jwk`.
+    ///
+    /// Validators learn JWKs from their OIDC providers, which a `Simulacrum` has no access to, so
+    /// they have to be supplied up front for zkLogin transactions to verify.
+    pub fn insert_jwk(&mut self, jwk_id: JwkId, jwk: JWK) {
+        self.jwks.insert(jwk_id, jwk);
+    }
+
+    /// Updates the JWKs in the authenticator state.
+    ///
+    /// This creates and executes an AuthenticatorStateUpdate transaction, like the ones validators
+    /// run once they agree on the JWKs published by OIDC providers. zkLogin signatures whose proofs
+    /// were issued against the new JWKs are accepted from then on.
+    pub fn update_authenticator_state(
+        &mut self,
+        mut new_active_jwks: Vec<ActiveJwk>,
+    ) -> Result<TransactionEffects> {
+        let initial_shared_version =
+            get_authenticator_state_obj_initial_shared_version(&self.store)?
+                .ok_or_else(|| anyhow!("The authenticator state object does not exist"))?;
+        // The authenticator state rejects updates whose JWKs are out of order
+        new_active_jwks.sort();
+        new_active_jwks.dedup();
+        let epoch = self.epoch_state.epoch();
+        let round = self.epoch_state.next_consensus_round();
+        let tx = VerifiedTransaction::new_authenticator_state_update(
+            epoch,
+            round,
+            new_active_jwks.clone(),
+            initial_shared_version,
+        );
+
+        let (effects, error) = self.execute_transaction(tx.into())?;
+        if let Some(error) = error {
+            return Err(anyhow!("Updating the authenticator state failed: {error}"));
+        }
+        for ActiveJwk { jwk_id, jwk, .. } in new_active_jwks {
+            self.jwks.insert(jwk_id, jwk);
+        }
+        Ok(effects)
+    }
+
     pub fn store(&self) -> &InMemoryStore {
         &self.store
     }
@@ -518,4 +610,23 @@ mod tests {
         assert_eq!(&checkpoint.epoch_rolling_gas_cost_summary, gas_summary);
         assert_eq!(checkpoint.network_total_transactions, 2); // genesis + 1 txn
     }
+
+    #[test]
+    fn dump_objects_round_trip() {
+        let mut sim = Simulacrum::new();
+        let recipient = SuiAddress::generate(sim.rng());
+        sim.request_gas(recipient, 1_000).unwrap();
+
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("objects.bcs");
+        let written = sim.store().dump_objects(&path).unwrap();
+        let loaded = InMemoryStore::load_object_dump(&path).unwrap();
+        assert_eq!(written, loaded.len());
+
+        // Every version of every object is present, including the ones that are no longer live.
+        let expected: Vec<_> = sim.store().all_objects().into_iter().cloned().collect();
+        assert_eq!(expected, loaded);
+        let coin = sim.store().owned_objects(recipient).next().unwrap();
+        assert!(loaded.contains(coin));
+    }
 }
@@ -1,16 +1,18 @@
 // Copyright (c) Mysten Labs, Inc.
 // SPDX-License-Identifier: Apache-2.0
 
+use anyhow::anyhow;
 use move_binary_format::CompiledModule;
 use move_bytecode_utils::module_cache::GetModule;
 use move_core_types::{language_storage::ModuleId, resolver::ModuleResolver};
 use std::collections::{BTreeMap, HashMap};
+use std::path::Path;
 use sui_config::genesis;
 use sui_types::storage::{get_module, load_package_object_from_object_store, PackageObjectArc};
 use sui_types::{
     base_types::{AuthorityName, ObjectID, ObjectRef, SequenceNumber, SuiAddress},
     committee::{Committee, EpochId},
-    crypto::{AccountKeyPair, AuthorityKeyPair},
+    crypto::{AccountKeyPair, AuthorityKeyPair, SuiKeyPair},
     digests::{ObjectDigest, TransactionDigest, TransactionEventsDigest},
     effects::{TransactionEffects, TransactionEffectsAPI, TransactionEvents},
     error::{SuiError, SuiResult, UserInputError},
@@ -160,13 +162,45 @@ impl InMemoryStore {
             .expect("clock object should deserialize")
     }
 
-    pub fn owned_objects(&self, owner: SuiAddress) -> impl Iterator<Item = &Object> {
+    /// Every version of every object ever written to the store, including objects that have
+    /// since been deleted or wrapped, ordered by ID and then version.
+    pub fn all_objects(&self) -> Vec<&Object> {
+        let mut objects: Vec<_> = self.objects.values().flat_map(BTreeMap::values).collect();
+        objects.sort_by_key(|object| (object.id(), object.version()));
+        objects
+    }
+
+    /// Writes [`Self::all_objects`] to `path` as a BCS-encoded `Vec<Object>`, returning the
+    /// number of objects written.
+    ///
+    /// This is a debugging aid, for inspecting the state of a simulation offline. The dump can be
+    /// read back with [`InMemoryStore::load_object_dump`].
+    pub fn dump_objects(&self, path: &Path) -> anyhow::Result<usize> {
+        let objects = self.all_objects();
+        let bytes = bcs::to_bytes(&objects)?;
+        std::fs::write(path, bytes)
+            .map_err(|e| anyhow!("Failed to write object dump to {}: {e}", path.display()))?;
+        Ok(objects.len())
+    }
+
+    /// Reads the objects written to `path` by [`InMemoryStore::dump_objects`].
+    pub fn load_object_dump(path: &Path) -> anyhow::Result<Vec<Object>> {
+        let bytes = std::fs::read(path)
+            .map_err(|e| anyhow!("Failed to read object dump from {}: {e}", path.display()))?;
+        Ok(bcs::from_bytes(&bytes)?)
+    }
+
+    /// The latest version of every object that has not been deleted or wrapped.
+    pub fn live_objects(&self) -> impl Iterator<Item = &Object> {
         self.live_objects
             .iter()
             .flat_map(|(id, version)| self.get_object_at_version(id, *version))
-            .filter(
-                move |object| matches!(object.owner, Owner::AddressOwner(addr) if addr == owner),
-            )
+    }
+
+    pub fn owned_objects(&self, owner: SuiAddress) -> impl Iterator<Item = &Object> {
+        self.live_objects().filter(
+            move |object| matches!(object.owner, Owner::AddressOwner(addr) if addr == owner),
+        )
     }
 }
 
@@ -368,6 +402,7 @@ pub struct KeyStore {
     validator_keys: BTreeMap<AuthorityName, AuthorityKeyPair>,
     #[allow(unused)]
     account_keys: BTreeMap<SuiAddress, AccountKeyPair>,
+    validator_account_keys: BTreeMap<SuiAddress, SuiKeyPair>,
 }
 
 impl KeyStore {
@@ -392,9 +427,20 @@ impl KeyStore {
             .iter()
             .map(|key| (key.public().into(), key.copy()))
             .collect();
+
+        let validator_account_keys = network_config
+            .validator_configs()
+            .iter()
+            .map(|config| {
+                let key = config.account_key_pair.keypair();
+                ((&key.public()).into(), key.clone())
+            })
+            .collect();
+
         Self {
             validator_keys,
             account_keys,
+            validator_account_keys,
         }
     }
 
@@ -405,6 +451,12 @@ impl KeyStore {
     pub fn accounts(&self) -> impl Iterator<Item = (&SuiAddress, &AccountKeyPair)> {
         self.account_keys.iter()
     }
+
+    /// The key of the account that validator `address` controls its staking pool and operation
+    /// cap with.
+    pub fn validator_account(&self, address: &SuiAddress) -> Option<&SuiKeyPair> {
+        self.validator_account_keys.get(address)
+    }
 }
 
 // TODO: After we abstract object storage into the ExecutionCache trait, we can replace this with
@@ -421,6 +473,16 @@ impl InMemoryStore {
             let obj = match kind {
                 InputObjectKind::MovePackage(id) => self.get_object(id).cloned(),
                 InputObjectKind::ImmOrOwnedMoveObject(objref) => {
+                    // Past versions are kept after an object is deleted, so they would otherwise
+                    // still be found.
+                    if !self.live_objects.contains_key(&objref.0)
+                        && self.objects.contains_key(&objref.0)
+                    {
+                        return Err(UserInputError::ObjectDeleted {
+                            object_ref: *objref,
+                        }
+                        .into());
+                    }
                     self.get_object_by_key(&objref.0, objref.1)?
                 }
 
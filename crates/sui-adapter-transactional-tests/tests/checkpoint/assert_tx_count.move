@@ -0,0 +1,30 @@
+// Copyright (c) Mysten Labs, Inc.
+// SPDX-License-Identifier: Apache-2.0
+
+// tests asserting how many transactions went into the latest checkpoint
+
+//# init --accounts A B --simulator
+
+//# programmable --sender A --inputs 10 @B
+//> SplitCoins(Gas, [Input(0)]);
+//> TransferObjects([Result(0)], Input(1))
+
+//# programmable --sender A --inputs 20 @B
+//> SplitCoins(Gas, [Input(0)]);
+//> TransferObjects([Result(0)], Input(1))
+
+//# programmable --sender B --inputs 30 @A
+//> SplitCoins(Gas, [Input(0)]);
+//> TransferObjects([Result(0)], Input(1))
+
+//# create-checkpoint
+
+//# assert-checkpoint-tx-count 3
+
+//# assert-checkpoint-tx-count 2
+
+// an empty checkpoint
+
+//# create-checkpoint
+
+//# assert-checkpoint-tx-count 0
@@ -0,0 +1,18 @@
+// Copyright (c) Mysten Labs, Inc.
+// SPDX-License-Identifier: Apache-2.0
+
+// tests creating many checkpoints at once
+
+//# init --accounts A B --simulator
+
+//# programmable --sender A --inputs 10 @B
+//> SplitCoins(Gas, [Input(0)]);
+//> TransferObjects([Result(0)], Input(1))
+
+//# create-checkpoints --count 100
+
+//# assert-checkpoint-tx-count 0
+
+//# create-checkpoint
+
+//# create-checkpoints --count 0
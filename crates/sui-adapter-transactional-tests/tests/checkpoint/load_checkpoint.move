@@ -0,0 +1,31 @@
+// Copyright (c) Mysten Labs, Inc.
+// SPDX-License-Identifier: Apache-2.0
+
+// Write a checkpoint to a file and load it back. Loading rewinds the store to how it was before the
+// checkpoint, and executes the checkpoint's transactions again
+
+//# init --accounts A --simulator
+
+//# programmable --sender A --inputs 10 @A
+//> SplitCoins(Gas, [Input(0)]);
+//> TransferObjects([Result(0)], Input(1))
+
+//# create-checkpoint
+
+//# write-checkpoint load_checkpoint.bcs
+
+//# programmable --sender A --inputs 20 @A
+//> SplitCoins(Gas, [Input(0)]);
+//> TransferObjects([Result(0)], Input(1))
+
+//# programmable --sender A --inputs object(1,0)
+//> MergeCoins(Gas, [Input(0)])
+
+//# view-object 1,0
+
+//# load-checkpoint load_checkpoint.bcs
+
+// the coin split off after the checkpoint is gone, and the coin merged away after it is back
+//# view-object 4,0
+
+//# view-object 1,0
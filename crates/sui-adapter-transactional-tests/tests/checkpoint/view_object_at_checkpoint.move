@@ -0,0 +1,26 @@
+// Copyright (c) Mysten Labs, Inc.
+// SPDX-License-Identifier: Apache-2.0
+
+// View an object as it was at an earlier checkpoint, after it has been mutated since
+
+//# init --accounts A --simulator
+
+//# programmable --sender A --inputs 10 @A
+//> SplitCoins(Gas, [Input(0)]);
+//> TransferObjects([Result(0)], Input(1))
+
+//# create-checkpoint
+
+//# programmable --sender A --inputs object(1,0) 4 @A
+//> SplitCoins(Input(0), [Input(1)]);
+//> TransferObjects([Result(0)], Input(2))
+
+//# create-checkpoint
+
+//# view-object-at-checkpoint 1,0 --checkpoint 1
+
+//# view-object-at-checkpoint 1,0 --checkpoint 2
+
+//# view-object-at-checkpoint 3,0 --checkpoint 1
+
+//# view-object-at-checkpoint 1,0 --checkpoint 3
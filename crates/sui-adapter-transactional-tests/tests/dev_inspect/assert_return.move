@@ -0,0 +1,41 @@
+// Copyright (c) Mysten Labs, Inc.
+// SPDX-License-Identifier: Apache-2.0
+
+// checking what a function returns by dev-inspecting a call to it
+
+//# init --addresses test=0x0 --accounts A
+
+//# publish
+
+module test::m {
+    struct Point has copy, drop {
+        x: u64,
+        y: u64,
+    }
+
+    public fun add(a: u64, b: u64): u64 {
+        a + b
+    }
+
+    public fun point(x: u64, y: u64): Point {
+        Point { x, y }
+    }
+
+    public fun id<T: drop>(x: T): T {
+        x
+    }
+
+    public fun pair(): (u64, bool) {
+        (1, true)
+    }
+}
+
+//# assert-dev-inspect-return test::m::add --args 1 1 --expected 2
+
+//# assert-dev-inspect-return test::m::point --args 1 2 --sender A --expected struct(1u64,2u64)
+
+//# assert-dev-inspect-return test::m::id<vector<u8>> --args b"hi" --expected vector[104u8,105u8]
+
+//# assert-dev-inspect-return test::m::add --args 1 1 --expected 3
+
+//# assert-dev-inspect-return test::m::pair --expected 1
@@ -0,0 +1,44 @@
+// Copyright (c) Mysten Labs, Inc.
+// SPDX-License-Identifier: Apache-2.0
+
+// dev-inspect without an explicit gas price uses the reference gas price, so repeated
+// inspections report identical gas figures
+
+//# init --addresses test=0x0 --accounts A
+
+//# publish
+
+module test::m {
+    use sui::object::{Self, UID};
+    use sui::tx_context::TxContext;
+
+    struct S has key, store {
+        id: UID,
+        value: u64
+    }
+
+    public fun new(ctx: &mut TxContext): S {
+        S {
+            id: object::new(ctx),
+            value: 0,
+        }
+    }
+
+    public fun set(s: &mut S, value: u64) {
+        s.value = value
+    }
+
+    public fun check(s: &S, expected: u64) {
+        assert!(s.value == expected, 0);
+    }
+}
+
+//# programmable --sender A --inputs @A
+//> 0: test::m::new();
+//> TransferObjects([Result(0)], Input(0))
+
+//# programmable --sender A --inputs object(2,0) 0 --dev-inspect
+//> test::m::check(Input(0), Input(1))
+
+//# programmable --sender A --inputs object(2,0) 0 --dev-inspect
+//> test::m::check(Input(0), Input(1))
@@ -0,0 +1,23 @@
+// Copyright (c) Mysten Labs, Inc.
+// SPDX-License-Identifier: Apache-2.0
+
+// tests dev-inspecting with one of the sender's gas coins, rather than a fresh one. The coin's
+// storage rebate is only refunded when it is the one paying for gas.
+
+//# init --accounts A
+
+//# programmable --sender A --inputs 10 @A --dev-inspect --dev-inspect-gas 0,0
+//> SplitCoins(Gas, [Input(0)]);
+//> TransferObjects([Result(0)], Input(1))
+
+//# programmable --sender A --inputs 10 @A
+//> SplitCoins(Gas, [Input(0)]);
+//> TransferObjects([Result(0)], Input(1))
+
+//# programmable --sender A --inputs 10 @A --dev-inspect --dev-inspect-gas 0,0
+//> SplitCoins(Gas, [Input(0)]);
+//> TransferObjects([Result(0)], Input(1))
+
+//# programmable --sender A --inputs 10 @A --dev-inspect
+//> SplitCoins(Gas, [Input(0)]);
+//> TransferObjects([Result(0)], Input(1))
@@ -0,0 +1,26 @@
+// Copyright (c) Mysten Labs, Inc.
+// SPDX-License-Identifier: Apache-2.0
+
+// tests calling a function gated on the system address as the system sender
+
+//# init --addresses test=0x0 --accounts A
+
+//# publish
+module test::m {
+    use sui::tx_context::{Self, TxContext};
+
+    public fun only_system(ctx: &TxContext) {
+        assert!(tx_context::sender(ctx) == @0x0, 0);
+    }
+}
+
+//# programmable --sender system --dev-inspect
+//> test::m::only_system()
+
+// other senders are rejected by the function itself
+//# programmable --sender A
+//> test::m::only_system()
+
+// the system address has no key to sign with
+//# programmable --sender system
+//> test::m::only_system()
@@ -0,0 +1,43 @@
+// Copyright (c) Mysten Labs, Inc.
+// SPDX-License-Identifier: Apache-2.0
+
+// tests viewing the chain of owners of an object nested under other objects
+
+//# init --addresses a=0x0 --accounts A
+
+//# publish
+module a::m {
+
+use sui::dynamic_object_field::add;
+use sui::object;
+use sui::tx_context::{sender, TxContext};
+
+struct Obj has key, store {
+    id: object::UID,
+}
+
+entry fun create(ctx: &mut TxContext) {
+    sui::transfer::public_transfer(Obj { id: object::new(ctx) }, sender(ctx))
+}
+
+entry fun nest(parent: &mut Obj, child: Obj) {
+    add<u64, Obj>(&mut parent.id, 0, child)
+}
+
+}
+
+//# run a::m::create --sender A
+
+//# run a::m::create --sender A
+
+//# run a::m::create --sender A
+
+// object(4,0) under object(3,0)
+//# run a::m::nest --sender A --args object(3,0) object(4,0)
+
+// object(3,0) under object(2,0)
+//# run a::m::nest --sender A --args object(2,0) object(3,0)
+
+//# view-owner-chain 4,0
+
+//# view-owner-chain 2,0
@@ -0,0 +1,48 @@
+// Copyright (c) Mysten Labs, Inc.
+// SPDX-License-Identifier: Apache-2.0
+
+// tests viewing the net dynamic field changes a transaction made under a parent
+
+//# init --addresses a=0x0 --accounts A
+
+//# publish
+module a::m {
+
+use sui::dynamic_field::{add, remove};
+use sui::object;
+use sui::tx_context::{sender, TxContext};
+
+struct Obj has key {
+    id: object::UID,
+}
+
+entry fun create(ctx: &mut TxContext) {
+    sui::transfer::transfer(Obj { id: object::new(ctx) }, sender(ctx))
+}
+
+entry fun populate(obj: &mut Obj) {
+    add<u64, u64>(&mut obj.id, 1, 10);
+    add<u64, u64>(&mut obj.id, 2, 20);
+}
+
+entry fun update(obj: &mut Obj) {
+    let _: u64 = remove<u64, u64>(&mut obj.id, 1);
+    add<u64, u64>(&mut obj.id, 3, 30);
+    // added and removed again, so not a net change
+    add<u64, u64>(&mut obj.id, 4, 40);
+    let _: u64 = remove<u64, u64>(&mut obj.id, 4);
+}
+
+}
+
+//# run a::m::create --sender A
+
+//# view-dynamic-field-changes 2 2,0
+
+//# run a::m::populate --sender A --args object(2,0)
+
+//# view-dynamic-field-changes 4 2,0
+
+//# run a::m::update --sender A --args object(2,0)
+
+//# view-dynamic-field-changes 6 2,0
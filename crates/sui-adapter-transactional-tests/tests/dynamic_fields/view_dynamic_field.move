@@ -0,0 +1,38 @@
+// Copyright (c) Mysten Labs, Inc.
+// SPDX-License-Identifier: Apache-2.0
+
+// tests reading a dynamic field's value by name. Only names of the right type and value find the
+// field
+
+//# init --addresses a=0x0 --accounts A
+
+//# publish
+module a::m {
+
+use sui::dynamic_field::{add, borrow};
+use sui::object;
+use sui::tx_context::TxContext;
+
+struct Obj has key {
+    id: object::UID,
+}
+
+entry fun add_then_freeze(ctx: &mut TxContext) {
+    let id = object::new(ctx);
+    add<u64, u64>(&mut id, 0, 0);
+    sui::transfer::freeze_object(Obj { id })
+}
+
+entry fun read_from_frozen(obj: &Obj) {
+    let _ = borrow<u64, u64>(&obj.id, 0);
+}
+
+}
+
+//# run a::m::add_then_freeze --sender A
+
+//# view-dynamic-field 2,0 --name 0u64
+
+//# view-dynamic-field 2,0 --name 1u64
+
+//# view-dynamic-field 2,0 --name 0u8
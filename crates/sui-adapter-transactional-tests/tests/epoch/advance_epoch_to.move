@@ -0,0 +1,20 @@
+// Copyright (c) Mysten Labs, Inc.
+// SPDX-License-Identifier: Apache-2.0
+
+// advancing several epochs at once. The output reports the last epoch that was closed.
+
+//# init --addresses test=0x0 --simulator
+
+//# advance-epoch
+
+//# advance-epoch --to 5
+
+//# advance-epoch --count 2
+
+// we are now in epoch 7, so these targets are not in the future
+
+//# advance-epoch --to 7
+
+//# advance-epoch --to 3
+
+//# advance-epoch --count 0
@@ -0,0 +1,24 @@
+// Copyright (c) Mysten Labs, Inc.
+// SPDX-License-Identifier: Apache-2.0
+
+// asserting which epoch transactions were executed in, either side of an epoch change
+
+//# init --accounts A --simulator
+
+//# programmable --sender A --inputs 10 @A
+//> SplitCoins(Gas, [Input(0)]);
+//> TransferObjects([Result(0)], Input(1))
+
+//# advance-epoch
+
+//# programmable --sender A --inputs 10 @A
+//> SplitCoins(Gas, [Input(0)]);
+//> TransferObjects([Result(0)], Input(1))
+
+//# assert-tx-epoch 1 0
+
+//# assert-tx-epoch 3 1
+
+//# assert-tx-epoch 1 1
+
+//# assert-tx-epoch 2 0
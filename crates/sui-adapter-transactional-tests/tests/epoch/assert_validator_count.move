@@ -0,0 +1,14 @@
+// Copyright (c) Mysten Labs, Inc.
+// SPDX-License-Identifier: Apache-2.0
+
+// the size of the active validator set is kept across epoch changes
+
+//# init --accounts A --simulator
+
+//# assert-validator-count 1
+
+//# advance-epoch
+
+//# assert-validator-count 1
+
+//# assert-validator-count 2
@@ -0,0 +1,28 @@
+// Copyright (c) Mysten Labs, Inc.
+// SPDX-License-Identifier: Apache-2.0
+
+// inspecting the end-of-epoch data carried by checkpoints
+
+//# init --accounts A --protocol-version 30 --simulator
+
+//# create-checkpoint
+
+//# view-checkpoint-epoch-info
+
+// a checkpoint in the middle of an epoch carries no next-epoch info
+//# view-checkpoint-epoch-info --expect-protocol-version 30
+
+//# advance-epoch
+
+//# view-checkpoint-epoch-info --expect-protocol-version 30
+
+//# upgrade-protocol --to 31
+
+//# view-checkpoint-epoch-info --expect-protocol-version 31
+
+// the earlier boundary checkpoint rolled over to version 30, not 31
+//# view-checkpoint-epoch-info --checkpoint 2 --expect-protocol-version 31
+
+//# view-checkpoint-epoch-info --checkpoint 1
+
+//# view-checkpoint-epoch-info --checkpoint 100
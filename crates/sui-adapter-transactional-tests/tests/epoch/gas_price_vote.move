@@ -0,0 +1,21 @@
+// Copyright (c) Mysten Labs, Inc.
+// SPDX-License-Identifier: Apache-2.0
+
+// validators voting for a gas price, and the reference gas price being recomputed from their votes
+// at the next epoch
+
+//# init --accounts A --simulator
+
+//# view-system-state
+
+//# set-gas-price-vote validator_0 2000
+
+// the vote only takes effect once the epoch ends
+
+//# view-system-state
+
+//# advance-epoch
+
+//# view-system-state
+
+//# set-gas-price-vote 0x42 2000
@@ -0,0 +1,40 @@
+// Copyright (c) Mysten Labs, Inc.
+// SPDX-License-Identifier: Apache-2.0
+
+// upgrading the protocol version mid-test, to one that allows deleting shared objects
+
+//# init --addresses t2=0x0 --accounts A --protocol-version 30 --simulator
+
+//# publish
+
+module t2::o2 {
+    use sui::object::{Self, UID};
+    use sui::transfer;
+    use sui::tx_context::TxContext;
+
+    struct Obj2 has key, store {
+        id: UID,
+    }
+
+    public entry fun create(ctx: &mut TxContext) {
+        let o = Obj2 { id: object::new(ctx) };
+        transfer::public_share_object(o)
+    }
+
+    public entry fun consume_o2(o2: Obj2) {
+        let Obj2 { id } = o2;
+        object::delete(id);
+    }
+}
+
+//# run t2::o2::create --sender A
+
+//# run t2::o2::consume_o2 --sender A --args object(2,0)
+
+//# upgrade-protocol --to 29
+
+//# upgrade-protocol --to 31
+
+//# run t2::o2::consume_o2 --sender A --args object(2,0)
+
+//# upgrade-protocol --to 31
@@ -0,0 +1,22 @@
+// Copyright (c) Mysten Labs, Inc.
+// SPDX-License-Identifier: Apache-2.0
+
+// staking with a validator, and seeing the stake become active at the next epoch
+
+//# init --accounts A --simulator
+
+//# view-validator validator_0
+
+//# programmable --sender A --inputs object(0x5) 1000000000 @validator_0
+//> 0: SplitCoins(Gas, [Input(1)]);
+//> sui_system::sui_system::request_add_stake(Input(0), Result(0), Input(2))
+
+// the stake is pending until the epoch ends
+
+//# view-validator validator_0
+
+//# advance-epoch
+
+//# view-validator validator_0
+
+//# view-validator 0x42
@@ -0,0 +1,9 @@
+// Copyright (c) Mysten Labs, Inc.
+// SPDX-License-Identifier: Apache-2.0
+
+// a test can declare its own address for a name that would otherwise refer to a genesis
+// validator
+
+//# init --addresses validator_0=0x42 --accounts A --simulator
+
+//# view-validator validator_0
@@ -0,0 +1,23 @@
+// Copyright (c) Mysten Labs, Inc.
+// SPDX-License-Identifier: Apache-2.0
+
+// tests passing addresses as 32-byte `vector<u8>` with the address_bytes(...) input syntax
+
+//# init --addresses test=0x0 --accounts A
+
+//# publish
+module test::m1 {
+    use std::vector;
+    use sui::address;
+
+    public fun check(bytes: vector<u8>, expected: address) {
+        assert!(vector::length(&bytes) == 32, 0);
+        assert!(bytes == address::to_bytes(expected), 1);
+    }
+}
+
+//# programmable --sender A --inputs address_bytes(@A) @A
+//> 0: test::m1::check(Input(0), Input(1));
+
+//# programmable --sender A --inputs address_bytes(0x2) @0x2
+//> 0: test::m1::check(Input(0), Input(1));
@@ -0,0 +1,12 @@
+// Copyright (c) Mysten Labs, Inc.
+// SPDX-License-Identifier: Apache-2.0
+
+// tests picking a transaction's gas budget from a dry run of it
+
+//# init --accounts A B
+
+//# programmable --sender A --inputs 10 @B --auto-gas-budget
+//> 0: SplitCoins(Gas, [Input(0)]);
+//> TransferObjects([Result(0)], Input(1))
+
+//# view-object 1,0
@@ -0,0 +1,19 @@
+// Copyright (c) Mysten Labs, Inc.
+// SPDX-License-Identifier: Apache-2.0
+
+// tests computing a transaction's digest without executing it. The digest is stable while state
+// is unchanged, and the transaction is then executed as if it had never been built.
+
+//# init --accounts A B
+
+//# programmable --sender A --inputs 10 @B --compute-digest-only
+//> SplitCoins(Gas, [Input(0)]);
+//> TransferObjects([Result(0)], Input(1))
+
+//# programmable --sender A --inputs 10 @B --compute-digest-only
+//> SplitCoins(Gas, [Input(0)]);
+//> TransferObjects([Result(0)], Input(1))
+
+//# programmable --sender A --inputs 10 @B
+//> SplitCoins(Gas, [Input(0)]);
+//> TransferObjects([Result(0)], Input(1))
@@ -0,0 +1,23 @@
+// Copyright (c) Mysten Labs, Inc.
+// SPDX-License-Identifier: Apache-2.0
+
+// tests submitting a batch of independent transactions at once, which only touch owned objects and
+// so are all executed on the fast path, without going through consensus
+
+//# init --accounts A B C
+
+//# programmable --sender A --inputs 10 @B --compute-digest-only
+//> SplitCoins(Gas, [Input(0)]);
+//> TransferObjects([Result(0)], Input(1))
+
+//# programmable --sender B --inputs 20 @C --compute-digest-only
+//> SplitCoins(Gas, [Input(0)]);
+//> TransferObjects([Result(0)], Input(1))
+
+//# programmable --sender A --inputs 30 @C --compute-digest-only
+//> SplitCoins(Gas, [Input(0)]);
+//> TransferObjects([Result(0)], Input(1))
+
+//# execute-batch 1 3
+
+//# execute-batch 1 2
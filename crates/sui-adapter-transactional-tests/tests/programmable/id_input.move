@@ -0,0 +1,36 @@
+// Copyright (c) Mysten Labs, Inc.
+// SPDX-License-Identifier: Apache-2.0
+
+// tests passing object IDs and addresses as `ID` with the id(...) input syntax
+
+//# init --addresses test=0x0 --accounts A
+
+//# publish
+module test::m1 {
+    use std::option::Option;
+    use sui::object::ID;
+    use std::string::String;
+    use std::ascii;
+
+    public fun fix<T>(_: &mut T) {}
+
+    public fun addr(_: address) {}
+    public fun id(_: ID) {}
+
+    public fun ascii(_: ascii::String) {}
+    public fun string(_: String) {}
+
+    public fun vec<T: drop>(_: vector<T>) {}
+    public fun opt<T: drop>(_: Option<T>) {}
+
+
+}
+
+//# programmable --sender A --inputs id(1,0)
+//> 0: test::m1::id(Input(0));
+
+//# programmable --sender A --inputs id(@A)
+//> 0: test::m1::id(Input(0));
+
+//# programmable --sender A --inputs id(2,0)
+//> 0: test::m1::id(Input(0));
@@ -0,0 +1,66 @@
+// Copyright (c) Mysten Labs, Inc.
+// SPDX-License-Identifier: Apache-2.0
+
+// tests struct inputs given by field name
+
+//# init --addresses test=0x0 --accounts A
+
+//# publish
+module test::m {
+    use std::bcs;
+    use std::string::{Self, String};
+    use sui::object::{Self, ID};
+
+    struct Point has copy, drop {
+        x: u64,
+        y: u64,
+        label: vector<u8>,
+    }
+
+    struct Pair has copy, drop {
+        first: u64,
+        second: u64,
+    }
+
+    public fun check_string(s: String) {
+        assert!(string::bytes(&s) == &b"hello", 0)
+    }
+
+    public fun check_id(id: ID, expected: address) {
+        assert!(object::id_to_address(&id) == expected, 1)
+    }
+
+    // Only primitive types can be pure inputs, so a `Pair` is received as the `u128` with the
+    // same BCS encoding
+    public fun check_pair(bits: u128) {
+        assert!(bcs::to_bytes(&Pair { first: 1, second: 2 }) == bcs::to_bytes(&bits), 2)
+    }
+}
+
+//# programmable --sender A --inputs std::string::String{bytes:b"hello"} sui::object::ID{bytes:@A} @A
+//> test::m::check_string(Input(0));
+//> test::m::check_id(Input(1), Input(2));
+
+// a struct with several fields is encoded field by field, in declaration order
+//# programmable --sender A --inputs test::m::Pair{first:1u64,second:2u64}
+//> test::m::check_pair(Input(0));
+
+// fields must be given in declaration order
+//# programmable --sender A --inputs test::m::Point{y:1u64,x:2u64,label:b""}
+//> test::m::check_string(Input(0));
+
+//# programmable --sender A --inputs test::m::Point{x:2u64,label:b""}
+//> test::m::check_string(Input(0));
+
+//# programmable --sender A --inputs test::m::Point{x:2u64,y:1u64,name:b""}
+//> test::m::check_string(Input(0));
+
+//# programmable --sender A --inputs test::m::Point{x:2u64,y:1u64,label:b"",x:3u64}
+//> test::m::check_string(Input(0));
+
+// each field's value must have the field's type
+//# programmable --sender A --inputs test::m::Point{x:1u8,y:true,label:b""}
+//> test::m::check_string(Input(0));
+
+//# programmable --sender A --inputs test::m::Point{x:2u64,y:1u64,label:std::string::String{bytes:b""}}
+//> test::m::check_string(Input(0));
@@ -0,0 +1,29 @@
+// Copyright (c) Mysten Labs, Inc.
+// SPDX-License-Identifier: Apache-2.0
+
+// tests passing nested vectors, including empty inner vectors and inner vectors of different
+// lengths, as pure inputs
+
+//# init --addresses test=0x0
+
+//# publish
+module test::m {
+    use std::vector;
+
+    public fun check(v: vector<vector<u8>>) {
+        assert!(vector::length(&v) == 3, 0);
+        assert!(*vector::borrow(&v, 0) == vector[1, 2], 1);
+        assert!(vector::is_empty(vector::borrow(&v, 1)), 2);
+        assert!(*vector::borrow(&v, 2) == x"030405", 3);
+    }
+
+    public fun check_empty(v: vector<vector<u64>>) {
+        assert!(vector::is_empty(&v), 4);
+    }
+}
+
+//# programmable --inputs vector[vector[1u8,2u8],vector[],x"030405"]
+//> test::m::check(Input(0))
+
+//# programmable --inputs vector[]
+//> test::m::check_empty(Input(0))
@@ -0,0 +1,34 @@
+// Copyright (c) Mysten Labs, Inc.
+// SPDX-License-Identifier: Apache-2.0
+
+// tests type arguments that refer to a published package by the task that published it
+
+//# init --addresses test=0x0 --accounts A
+
+//# publish
+module test::m {
+    struct A has copy, drop { value: u64 }
+
+    public fun a(): A { A { value: 0 } }
+
+    public fun id<T: drop>(x: T): T { x }
+
+    public fun eat<T: drop>(_: vector<T>) {}
+}
+
+//# programmable
+//> 0: test::m::a();
+//> 1: test::m::id<package(1)::m::A>(Result(0));
+//> 2: MakeMoveVec<package(1)::m::A>([Result(1)]);
+//> test::m::eat<package(1)::m::A>(Result(2));
+
+// nested in another type argument
+//# programmable
+//> 0: test::m::a();
+//> 1: MakeMoveVec<package(1)::m::A>([Result(0)]);
+//> test::m::id<vector<package(1)::m::A>>(Result(1));
+
+// task 2 did not publish a package
+//# programmable
+//> 0: test::m::a();
+//> test::m::id<package(2)::m::A>(Result(0));
@@ -0,0 +1,18 @@
+// Copyright (c) Mysten Labs, Inc.
+// SPDX-License-Identifier: Apache-2.0
+
+// tests listing a transaction's input objects, and whether it modified them
+
+//# init --accounts A B
+
+//# programmable --sender A --inputs 10
+//> 0: SplitCoins(Gas, [Input(0)]);
+//> sui::transfer::public_freeze_object<sui::coin::Coin<sui::sui::SUI>>(Result(0))
+
+//# programmable --sender A --inputs 20 @A
+//> 0: SplitCoins(Gas, [Input(0)]);
+//> TransferObjects([Result(0)], Input(1))
+
+//# programmable --sender A --inputs object(1,0) object(2,0) 5 @B --show-inputs
+//> 0: SplitCoins(Input(1), [Input(2)]);
+//> TransferObjects([Result(0)], Input(3))
@@ -0,0 +1,12 @@
+// Copyright (c) Mysten Labs, Inc.
+// SPDX-License-Identifier: Apache-2.0
+
+// Transactions up to the size limit are accepted, and larger ones are rejected before execution
+
+//# init --protocol-version 29 --accounts A
+
+//# programmable --sender A --inputs @A --pad-to 131072
+//> TransferObjects([Gas], Input(0))
+
+//# programmable --sender A --inputs @A --pad-to 131073
+//> TransferObjects([Gas], Input(0))
@@ -0,0 +1,26 @@
+// Copyright (c) Mysten Labs, Inc.
+// SPDX-License-Identifier: Apache-2.0
+
+// Check whether packages were published with an UpgradeCap
+
+//# init --addresses Upgradeable=0x0 Immutable=0x0
+
+//# publish --upgradeable
+module Upgradeable::M1 {
+    use sui::tx_context::TxContext;
+    fun init(_ctx: &mut TxContext) { }
+}
+
+//# publish
+module Immutable::M1 {
+    use sui::tx_context::TxContext;
+    fun init(_ctx: &mut TxContext) { }
+}
+
+//# assert-upgradeable 1,0 --yes
+
+//# assert-upgradeable 2,0 --no
+
+//# assert-upgradeable 1,0 --no
+
+//# assert-upgradeable 2,0 --yes
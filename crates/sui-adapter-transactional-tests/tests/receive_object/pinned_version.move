@@ -0,0 +1,43 @@
+// Copyright (c) Mysten Labs, Inc.
+// SPDX-License-Identifier: Apache-2.0
+
+//# init --addresses tto=0x0
+
+//# publish
+module tto::M1 {
+    use sui::object::{Self, UID};
+    use sui::tx_context::{Self, TxContext};
+    use sui::transfer::{Self, Receiving};
+
+    struct A has key, store {
+        id: UID,
+    }
+
+    struct B has key, store {
+        id: UID,
+    }
+
+    public fun start(ctx: &mut TxContext) {
+        let a = A { id: object::new(ctx) };
+        let a_address = object::id_address(&a);
+        let b = B { id: object::new(ctx) };
+        transfer::public_transfer(a, tx_context::sender(ctx));
+        transfer::public_transfer(b, a_address);
+    }
+
+    public entry fun receiver(parent: &mut A, x: Receiving<B>) {
+        let b = transfer::receive(&mut parent.id, x);
+        transfer::public_transfer(b, @tto);
+    }
+}
+
+//# run tto::M1::start
+
+// The object is not at that version yet
+//# run tto::M1::receiver --args object(2,0) receiving(2,1)@4
+
+// The parent was never sent to an object
+//# run tto::M1::receiver --args object(2,0) receiving(2,0)@3
+
+// Can receive the object at the version it was sent to the parent at
+//# run tto::M1::receiver --args object(2,0) receiving(2,1)@3
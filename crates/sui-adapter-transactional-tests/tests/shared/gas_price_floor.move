@@ -0,0 +1,33 @@
+// Copyright (c) Mysten Labs, Inc.
+// SPDX-License-Identifier: Apache-2.0
+
+// tests that shared object transactions offering less than their gas price floor are rejected
+
+//# init --addresses a=0x0 --accounts A
+
+//# publish
+module a::m {
+    use sui::transfer;
+    use sui::object;
+    use sui::tx_context::TxContext;
+
+    struct Obj has key {
+        id: object::UID,
+    }
+
+    public entry fun create(ctx: &mut TxContext) {
+        transfer::share_object(Obj { id: object::new(ctx) })
+    }
+
+    public entry fun touch(_: &mut Obj) {}
+}
+
+//# run a::m::create --sender A
+
+//# run a::m::touch --sender A --args object(2,0) --gas-price 999
+
+//# view-gas-price 3
+
+//# run a::m::touch --sender A --args object(2,0)
+
+//# view-gas-price 5
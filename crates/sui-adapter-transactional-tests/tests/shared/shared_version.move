@@ -0,0 +1,43 @@
+// Copyright (c) Mysten Labs, Inc.
+// SPDX-License-Identifier: Apache-2.0
+
+// tests that each mutation of a shared object advances its version
+
+//# init --addresses t2=0x0
+
+//# publish
+
+module t2::o2 {
+    use sui::object::{Self, UID};
+    use sui::transfer;
+    use sui::tx_context::TxContext;
+
+    struct Obj2 has key, store {
+        id: UID,
+        value: u64,
+    }
+
+    public entry fun create(ctx: &mut TxContext) {
+        let o = Obj2 { id: object::new(ctx), value: 0 };
+        transfer::public_share_object(o)
+    }
+
+    public entry fun increment(o2: &mut Obj2) {
+        o2.value = o2.value + 1
+    }
+}
+
+//# run t2::o2::create
+
+//# assert-shared-version 2,0 3
+
+//# run t2::o2::increment --args object(2,0)
+
+//# run t2::o2::increment --args object(2,0)
+
+//# assert-shared-version 2,0 5
+
+//# assert-shared-version 2,0 4
+
+// not a shared object
+//# assert-shared-version 1,0 1
@@ -0,0 +1,19 @@
+// Copyright (c) Mysten Labs, Inc.
+// SPDX-License-Identifier: Apache-2.0
+
+// tests asserting the net change in an account's balance over a transaction
+
+//# init --accounts A B
+
+//# programmable --sender A --inputs 10 @B
+//> SplitCoins(Gas, [Input(0)]);
+//> TransferObjects([Result(0)], Input(1))
+
+//# assert-balance-change 1 B 0x2::sui::SUI 10
+
+// gas is included in the sender's balance change
+//# assert-balance-change 1 A 0x2::sui::SUI 2976010 --decrease
+
+//# assert-balance-change 1 A 0x2::sui::SUI 10 --decrease
+
+//# assert-balance-change 1 B 0x2::sui::SUI 10 --decrease
@@ -0,0 +1,20 @@
+// Copyright (c) Mysten Labs, Inc.
+// SPDX-License-Identifier: Apache-2.0
+
+// Assert how many objects a transaction created, exactly or as an upper bound
+
+//# init --accounts A
+
+//# programmable --sender A --inputs 10 @A
+//> 0: SplitCoins(Gas, [Input(0), Input(0), Input(0)]);
+//> TransferObjects([NestedResult(0,0), NestedResult(0,1), NestedResult(0,2)], Input(1))
+
+//# assert-created-count 1 3
+
+//# assert-created-count 1 5 --at-most
+
+//# assert-created-count 1 2
+
+//# assert-created-count 1 2 --at-most
+
+//# assert-created-count 0 0
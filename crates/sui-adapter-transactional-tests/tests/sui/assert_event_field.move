@@ -0,0 +1,44 @@
+// Copyright (c) Mysten Labs, Inc.
+// SPDX-License-Identifier: Apache-2.0
+
+// Assert individual fields of an event's payload, found by path
+
+//# init --addresses test=0x0 --accounts A
+
+//# publish
+
+module test::m {
+    use sui::event;
+
+    struct Inner has copy, drop { value: u64, flag: bool }
+
+    struct Outer has copy, drop { owner: address, inner: Inner, values: vector<u64> }
+
+    public entry fun emit(value: u64, ctx: &mut sui::tx_context::TxContext) {
+        event::emit(Outer {
+            owner: sui::tx_context::sender(ctx),
+            inner: Inner { value, flag: true },
+            values: vector[value, value + 1],
+        });
+    }
+}
+
+//# run test::m::emit --sender A --args 7
+
+//# assert-event-field 2 0 inner.value 7u64
+
+//# assert-event-field 2 0 inner.flag true
+
+//# assert-event-field 2 0 owner @A
+
+//# assert-event-field 2 0 values.1 8u64
+
+//# assert-event-field 2 0 inner struct(7u64, true)
+
+//# assert-event-field 2 0 inner.value 8u64
+
+//# assert-event-field 2 0 inner.missing 7u64
+
+//# assert-event-field 2 0 values.2 7u64
+
+//# assert-event-field 2 1 inner.value 7u64
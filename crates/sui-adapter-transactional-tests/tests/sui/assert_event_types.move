@@ -0,0 +1,39 @@
+// Copyright (c) Mysten Labs, Inc.
+// SPDX-License-Identifier: Apache-2.0
+
+// Assert the set of event types a transaction emitted, regardless of their contents
+
+//# init --addresses test=0x0 --accounts A
+
+//# publish
+
+module test::m {
+    use sui::event;
+
+    struct Created has copy, drop { value: u64 }
+
+    struct Flagged has copy, drop { flag: bool }
+
+    public entry fun emit_both(value: u64) {
+        event::emit(Created { value });
+        event::emit(Flagged { flag: true });
+        event::emit(Created { value: value + 1 });
+    }
+
+    public entry fun emit_none() {}
+}
+
+//# run test::m::emit_both --sender A --args 7
+
+//# assert-event-types 2 test::m::Created test::m::Flagged
+
+// Order and repetition do not matter
+//# assert-event-types 2 test::m::Flagged test::m::Created test::m::Flagged
+
+//# assert-event-types 2 test::m::Created
+
+//# run test::m::emit_none --sender A
+
+//# assert-event-types 6
+
+//# assert-event-types 6 test::m::Flagged
@@ -0,0 +1,18 @@
+// Copyright (c) Mysten Labs, Inc.
+// SPDX-License-Identifier: Apache-2.0
+
+// Assert the full type of objects, including their type arguments
+
+//# init --accounts A
+
+//# programmable --sender A --inputs @A
+//> 0: sui::table::new<u64, u64>();
+//> TransferObjects([Result(0)], Input(0))
+
+//# assert-object-type 1,0 sui::table::Table<u64, u64>
+
+//# assert-object-type 1,0 sui::table::Table<u64, bool>
+
+//# assert-object-type 1,0 sui::bag::Bag
+
+//# assert-object-type 0,0 sui::coin::Coin<sui::sui::SUI>
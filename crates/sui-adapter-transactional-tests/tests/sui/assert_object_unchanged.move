@@ -0,0 +1,27 @@
+// Copyright (c) Mysten Labs, Inc.
+// SPDX-License-Identifier: Apache-2.0
+
+// tests asserting that a transaction left an object's owner and fields alone, even if it took the
+// object as input
+
+//# init --accounts A B
+
+//# programmable --sender A --inputs 10 @A
+//> SplitCoins(Gas, [Input(0)]);
+//> TransferObjects([Result(0)], Input(1))
+
+//# programmable --sender A --inputs 20 @A
+//> SplitCoins(Gas, [Input(0)]);
+//> TransferObjects([Result(0)], Input(1))
+
+// object(1,0) is an input, but only object(2,0) is split
+//# programmable --sender A --inputs object(2,0) object(1,0) 5 @B
+//> SplitCoins(Input(0), [Input(2)]);
+//> TransferObjects([Result(0)], Input(3))
+
+//# assert-object-unchanged 1,0
+
+//# assert-object-unchanged 2,0
+
+// task 1 did not touch B's gas coin
+//# assert-object-unchanged 0,1 --task 1
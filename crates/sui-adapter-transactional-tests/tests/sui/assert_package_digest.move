@@ -0,0 +1,36 @@
+// Copyright (c) Mysten Labs, Inc.
+// SPDX-License-Identifier: Apache-2.0
+
+// tests asserting the digest of a published package. A mismatch reports the package's actual
+// digest
+
+//# init --addresses a=0x0 --accounts A
+
+//# publish
+module a::m {
+
+use sui::dynamic_field::{add, borrow};
+use sui::object;
+use sui::tx_context::TxContext;
+
+struct Obj has key {
+    id: object::UID,
+}
+
+entry fun add_then_freeze(ctx: &mut TxContext) {
+    let id = object::new(ctx);
+    add<u64, u64>(&mut id, 0, 0);
+    sui::transfer::freeze_object(Obj { id })
+}
+
+entry fun read_from_frozen(obj: &Obj) {
+    let _ = borrow<u64, u64>(&obj.id, 0);
+}
+
+}
+
+//# assert-package-digest 1,0 0x0000000000000000000000000000000000000000000000000000000000000000
+
+//# assert-package-digest 1,0 0xnotahexdigest
+
+//# assert-package-digest 0,0 0x00
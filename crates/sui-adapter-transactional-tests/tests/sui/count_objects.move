@@ -0,0 +1,19 @@
+// Copyright (c) Mysten Labs, Inc.
+// SPDX-License-Identifier: Apache-2.0
+
+// tests counting live objects, filtered by owner and type
+
+//# init --accounts A B --simulator
+
+//# programmable --sender A --inputs 10 20 @A @B
+//> SplitCoins(Gas, [Input(0), Input(1)]);
+//> TransferObjects([NestedResult(0,0)], Input(2));
+//> TransferObjects([NestedResult(0,1)], Input(3))
+
+//# count-objects --owner A --type 0x2::coin::Coin<0x2::sui::SUI>
+
+//# count-objects --owner B
+
+//# count-objects --type 0x2::clock::Clock
+
+//# count-objects --owner A --type 0x2::clock::Clock
@@ -0,0 +1,18 @@
+// Copyright (c) Mysten Labs, Inc.
+// SPDX-License-Identifier: Apache-2.0
+
+// tests creating a shared object at a chosen initial shared version, and using it as a shared
+// input
+
+//# init --accounts A --simulator
+
+//# create-shared-object --initial-shared-version 5
+
+//# view-object 1,0
+
+//# programmable --sender A --inputs object(1,0) 1 2
+//> sui::bag::add<u64, u64>(Input(0), Input(1), Input(2))
+
+//# view-object 1,0
+
+//# create-shared-object --initial-shared-version 0
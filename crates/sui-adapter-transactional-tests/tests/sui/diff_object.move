@@ -0,0 +1,23 @@
+// Copyright (c) Mysten Labs, Inc.
+// SPDX-License-Identifier: Apache-2.0
+
+// tests diffing the contents and owner of an object between two of its versions
+
+//# init --accounts A B
+
+//# programmable --sender A --inputs 10 @A
+//> SplitCoins(Gas, [Input(0)]);
+//> TransferObjects([Result(0)], Input(1))
+
+//# programmable --sender A --inputs object(1,0) 3 @B
+//> SplitCoins(Input(0), [Input(1)]);
+//> TransferObjects([Result(0)], Input(2))
+
+//# diff-object 1,0 --from 2 --to 3
+
+//# programmable --sender A --inputs object(1,0) @B
+//> TransferObjects([Input(0)], Input(1))
+
+//# diff-object 1,0 --from 3 --to 4
+
+//# diff-object 1,0 --from 2 --to 2
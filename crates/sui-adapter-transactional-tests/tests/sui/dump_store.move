@@ -0,0 +1,16 @@
+// Copyright (c) Mysten Labs, Inc.
+// SPDX-License-Identifier: Apache-2.0
+
+// tests dumping the object store to disk, and that the dump contains every known object. The dump
+// is kept after the test, under the workspace's target directory
+
+//# init --accounts A B --dump-dir ../../target/transactional-test-dumps --simulator
+
+//# programmable --sender A --inputs 10 @B
+//> SplitCoins(Gas, [Input(0)]);
+//> TransferObjects([Result(0)], Input(1))
+
+//# programmable --sender B --inputs object(1,0) @A
+//> TransferObjects([Input(0)], Input(1))
+
+//# dump-store dump_store_test_objects.bcs
@@ -0,0 +1,9 @@
+// Copyright (c) Mysten Labs, Inc.
+// SPDX-License-Identifier: Apache-2.0
+
+// Submit a transaction from 0xaa..aa that carries a signature from a different key, so it must be
+// rejected before execution.
+
+//# init
+
+//# execute-signed --tx-bytes AQAAAAAAAACqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqgCqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqugDAAAAAAAAAPIFKgEAAAAAAWEAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAFhmZmZmZmZmZmZmZmZmZmZmZmZmZmZmZmZmZmZmZmZm
@@ -0,0 +1,16 @@
+// Copyright (c) Mysten Labs, Inc.
+// SPDX-License-Identifier: Apache-2.0
+
+// tests starting each account with several gas coins
+
+//# init --accounts A B --gas-coins-per-account 3
+
+//# list-objects --owner A
+
+//# list-objects --owner B
+
+// a coin other than the account's gas coin can be used as an input
+//# programmable --sender A --inputs object(0,1) @B
+//> TransferObjects([Input(0)], Input(1))
+
+//# list-objects --owner B
@@ -0,0 +1,12 @@
+// Copyright (c) Mysten Labs, Inc.
+// SPDX-License-Identifier: Apache-2.0
+
+// tests adding objects from a fixture to the genesis state. The fixture holds an immutable coin
+// with ID 0xc0de and a balance of 1234.
+
+//# init --objects tests/sui/genesis_objects.bcs
+
+//# view-object 0,1
+
+//# programmable --inputs object(0,1)
+//> 0x2::coin::value<0x2::sui::SUI>(Input(0))
@@ -0,0 +1,21 @@
+// Copyright (c) Mysten Labs, Inc.
+// SPDX-License-Identifier: Apache-2.0
+
+// tests passing byte vectors written as hex string literals, in either case
+
+//# init --addresses test=0x0 --accounts A
+
+//# publish
+module test::m {
+    public entry fun check(bytes: vector<u8>) {
+        assert!(bytes == x"deadbeef", 0);
+    }
+}
+
+//# run test::m::check --sender A --args x"deadbeef"
+
+//# programmable --sender A --inputs x"DEADBEEF"
+//> test::m::check(Input(0))
+
+//# programmable --sender A --inputs vector[222u8,173u8,190u8,239u8]
+//> test::m::check(Input(0))
@@ -0,0 +1,21 @@
+// Copyright (c) Mysten Labs, Inc.
+// SPDX-License-Identifier: Apache-2.0
+
+// An account imported from a known private key has the address other tools derive for it, and
+// signs as it
+
+//# init --addresses test=0x0 --accounts A --imported-account K:0x9f7a1c2e4b6d8a0f3e5c7b9d1a2c4e6f8b0d2a4c6e8f0b1d3a5c7e9f1b3d5a7c
+
+//# publish
+
+module test::m {
+    use sui::tx_context::{Self, TxContext};
+
+    public entry fun from_fixture(ctx: &TxContext) {
+        assert!(tx_context::sender(ctx) == @0x8a7c94b5aa3db1110ace45f7a12c702f1e9c23df392ae1be173f2467efb78534, 0);
+    }
+}
+
+//# run test::m::from_fixture --sender K
+
+//# view-tx 2
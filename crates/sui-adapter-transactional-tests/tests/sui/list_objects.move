@@ -0,0 +1,20 @@
+// Copyright (c) Mysten Labs, Inc.
+// SPDX-License-Identifier: Apache-2.0
+
+// tests paging through the objects owned by an account
+
+//# init --accounts A B
+
+//# programmable --sender A --inputs 10 20 30 @A
+//> SplitCoins(Gas, [Input(0), Input(1), Input(2)]);
+//> TransferObjects([NestedResult(0,0), NestedResult(0,1), NestedResult(0,2)], Input(3))
+
+//# list-objects --owner A --limit 2
+
+//# list-objects --owner A --limit 2 --cursor 1,0
+
+//# list-objects --owner A --limit 2 --cursor 1,2
+
+//# list-objects --owner B
+
+//# list-objects --owner C
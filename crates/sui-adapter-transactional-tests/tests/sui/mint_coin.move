@@ -0,0 +1,33 @@
+// Copyright (c) Mysten Labs, Inc.
+// SPDX-License-Identifier: Apache-2.0
+
+// tests minting coins of a custom type with a TreasuryCap
+
+//# init --addresses test=0x0 --accounts A B
+
+//# publish --sender A
+module test::fake {
+    use std::option;
+    use sui::coin;
+    use sui::transfer;
+    use sui::tx_context::{Self, TxContext};
+
+    struct FAKE has drop {}
+
+    fun init(witness: FAKE, ctx: &mut TxContext) {
+        let (treasury_cap, metadata) = coin::create_currency(witness, 2, b"FAKE", b"", b"", option::none(), ctx);
+        transfer::public_freeze_object(metadata);
+        transfer::public_transfer(treasury_cap, tx_context::sender(ctx));
+    }
+
+}
+
+//# mint-coin --treasury 1,2 --amount 100 --recipient B --sender A
+
+//# view-object 2,0
+
+//# assert-balance-change 2 B test::fake::FAKE 100
+
+//# mint-coin --treasury 1,0 --amount 100 --recipient B --sender A
+
+//# mint-coin --treasury 1,2 --amount 100 --recipient C --sender A
@@ -0,0 +1,17 @@
+// Copyright (c) Mysten Labs, Inc.
+// SPDX-License-Identifier: Apache-2.0
+
+// tests sending transactions from a multisig account, signed by a subset of its members
+
+//# init --accounts A B C --multisig M:2:A,B,C
+
+//# programmable --sender A --inputs 10 @M
+//> SplitCoins(Gas, [Input(0)]);
+//> TransferObjects([Result(0)], Input(1))
+
+// below threshold
+//# programmable --sender M --multisig-signers A --inputs object(1,0) @B
+//> TransferObjects([Input(0)], Input(1))
+
+//# programmable --sender M --inputs object(1,0) @B
+//> TransferObjects([Input(0)], Input(1))
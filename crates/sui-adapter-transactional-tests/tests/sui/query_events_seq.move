@@ -0,0 +1,25 @@
+// Copyright (c) Mysten Labs, Inc.
+// SPDX-License-Identifier: Apache-2.0
+
+// Query events along with their sequence numbers within the transaction that emitted them
+
+//# init --addresses test=0x0 --accounts A
+
+//# publish
+module test::m {
+    use sui::event;
+
+    struct E has copy, drop {
+        value: u64
+    }
+
+    public entry fun emit_three() {
+        event::emit(E { value: 1 });
+        event::emit(E { value: 2 });
+        event::emit(E { value: 3 });
+    }
+}
+
+//# run test::m::emit_three --sender A
+
+//# query-events-by-type test::m::E --show-seq
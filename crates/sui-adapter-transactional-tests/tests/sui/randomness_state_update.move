@@ -0,0 +1,8 @@
+// Copyright (c) Mysten Labs, Inc.
+// SPDX-License-Identifier: Apache-2.0
+
+// randomness is not available in this protocol version, so the update is rejected
+
+//# init --addresses test=0x0
+
+//# randomness-state-update --round 0 --random-bytes 0xdeadbeef
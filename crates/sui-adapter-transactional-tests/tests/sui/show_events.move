@@ -0,0 +1,28 @@
+// Copyright (c) Mysten Labs, Inc.
+// SPDX-License-Identifier: Apache-2.0
+
+// List the events emitted by a programmable transaction alongside its effects
+
+//# init --addresses test=0x0 --accounts A
+
+//# publish
+module test::m {
+    use sui::event;
+
+    struct E has copy, drop {
+        value: u64
+    }
+
+    public entry fun emit_three() {
+        event::emit(E { value: 1 });
+        event::emit(E { value: 2 });
+        event::emit(E { value: 3 });
+    }
+}
+
+//# programmable --sender A --show-events
+//> test::m::emit_three();
+
+// no events
+//# programmable --sender A --show-events
+//> SplitCoins(Gas, []);
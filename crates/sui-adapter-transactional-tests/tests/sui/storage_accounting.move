@@ -0,0 +1,42 @@
+// Copyright (c) Mysten Labs, Inc.
+// SPDX-License-Identifier: Apache-2.0
+
+// view how creating and deleting objects moves funds in and out of the storage fund
+
+//# init --addresses test=0x0 --accounts A
+
+//# publish
+
+module test::object_basics {
+    use sui::object::{Self, UID};
+    use sui::tx_context::TxContext;
+    use sui::transfer;
+
+    struct Object has key, store {
+        id: UID,
+        value: u64,
+    }
+
+    public entry fun create(value: u64, recipient: address, ctx: &mut TxContext) {
+        transfer::public_transfer(
+            Object { id: object::new(ctx), value },
+            recipient
+        )
+    }
+
+    public entry fun delete(o: Object) {
+        let Object { id, value: _ } = o;
+        object::delete(id);
+    }
+}
+
+//# run test::object_basics::create --sender A --args 10 @A
+
+//# view-storage-accounting 2
+
+//# run test::object_basics::delete --sender A --args object(2,0)
+
+//# view-storage-accounting 4
+
+// not a transaction
+//# view-storage-accounting 3
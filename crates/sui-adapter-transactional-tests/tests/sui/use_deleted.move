@@ -0,0 +1,21 @@
+// Copyright (c) Mysten Labs, Inc.
+// SPDX-License-Identifier: Apache-2.0
+
+// tests that a deleted object cannot be used again, even at the version it had before it was
+// deleted
+
+//# init --accounts A --simulator
+
+//# programmable --sender A --inputs 10 @A
+//> SplitCoins(Gas, [Input(0)]);
+//> TransferObjects([Result(0)], Input(1))
+
+//# programmable --sender A --inputs object(1,0)
+//> MergeCoins(Gas, [Input(0)])
+
+//# expect-use-deleted 1,0 --sender A
+
+//# programmable --sender A --inputs object(1,0)
+//> MergeCoins(Gas, [Input(0)])
+
+//# expect-use-deleted 0,0 --sender A
@@ -0,0 +1,20 @@
+// Copyright (c) Mysten Labs, Inc.
+// SPDX-License-Identifier: Apache-2.0
+
+// tests reading values from the active protocol config
+
+//# init --accounts A --protocol-version 29
+
+//# view-protocol-config --key version
+
+// enabled in version 29
+//# view-protocol-config --key verify_legacy_zklogin_address
+
+// not enabled until version 30
+//# view-protocol-config --key narwhal_certificate_v2
+
+//# view-protocol-config --key random_beacon_reduction_allowed_delta
+
+//# view-protocol-config --key max_move_object_size
+
+//# view-protocol-config --key not_a_config
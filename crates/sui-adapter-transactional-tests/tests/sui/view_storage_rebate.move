@@ -0,0 +1,14 @@
+// Copyright (c) Mysten Labs, Inc.
+// SPDX-License-Identifier: Apache-2.0
+
+// View the storage rebate recorded on objects, which matches the storage cost paid for them
+
+//# init --accounts A
+
+//# programmable --sender A --inputs 10 @A
+//> SplitCoins(Gas, [Input(0)]);
+//> TransferObjects([Result(0)], Input(1))
+
+//# view-storage-rebate 1,0
+
+//# view-storage-rebate 0,0
@@ -0,0 +1,20 @@
+// Copyright (c) Mysten Labs, Inc.
+// SPDX-License-Identifier: Apache-2.0
+
+// tests viewing the signers of transactions, including a multisig one sponsored by the default account
+
+//# init --accounts A B C --multisig M:2:A,B,C
+
+//# programmable --sender A --inputs 10 @M
+//> SplitCoins(Gas, [Input(0)]);
+//> TransferObjects([Result(0)], Input(1))
+
+//# view-tx 1
+
+// the default account is unnamed, so shows up as _
+//# programmable --sender M --inputs object(1,0) @B
+//> TransferObjects([Input(0)], Input(1))
+
+//# view-tx 3
+
+//# view-tx 2
@@ -0,0 +1,15 @@
+// Copyright (c) Mysten Labs, Inc.
+// SPDX-License-Identifier: Apache-2.0
+
+// tests viewing the transactions a transaction depends on
+
+//# init --accounts A B
+
+//# programmable --sender A --inputs 10 @B
+//> SplitCoins(Gas, [Input(0)]);
+//> TransferObjects([Result(0)], Input(1))
+
+//# programmable --sender B --inputs object(1,0) @A
+//> TransferObjects([Input(0)], Input(1))
+
+//# view-tx-dependencies 2
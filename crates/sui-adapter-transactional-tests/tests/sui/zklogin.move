@@ -0,0 +1,20 @@
+// Copyright (c) Mysten Labs, Inc.
+// SPDX-License-Identifier: Apache-2.0
+
+// tests sending transactions from a zkLogin identity, sponsored by the default account
+
+//# init --accounts A --zklogin Z --simulator
+
+//# programmable --sender A --inputs 10 @Z
+//> SplitCoins(Gas, [Input(0)]);
+//> TransferObjects([Result(0)], Input(1))
+
+//# programmable --sender Z --inputs object(1,0) @Z
+//> TransferObjects([Input(0)], Input(1))
+
+// the fixture proof expires after epoch 10
+
+//# advance-epoch --to 11
+
+//# programmable --sender Z --inputs object(1,0) @A
+//> TransferObjects([Input(0)], Input(1))
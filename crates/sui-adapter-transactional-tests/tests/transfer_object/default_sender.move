@@ -0,0 +1,37 @@
+// Copyright (c) Mysten Labs, Inc.
+// SPDX-License-Identifier: Apache-2.0
+
+// commands without --sender are signed by the --default-sender declared in init
+
+//# init --accounts A B --default-sender A --addresses test=0x0
+
+//# publish
+
+module test::m {
+    use sui::transfer;
+    use sui::tx_context::{Self, TxContext};
+    use sui::object::{Self, UID};
+
+    struct S has store, key { id: UID }
+    struct Cup<phantom T: store> has store, key { id: UID }
+
+    public entry fun mint_s(ctx: &mut TxContext) {
+        let id = object::new(ctx);
+        transfer::public_transfer(S { id }, tx_context::sender(ctx))
+    }
+
+    public entry fun mint_cup<T: store>(ctx: &mut TxContext) {
+        let id = object::new(ctx);
+        transfer::public_transfer(Cup<T> { id }, tx_context::sender(ctx))
+    }
+}
+
+// Mint S to A and transfer it to B, without naming the sender
+
+//# run test::m::mint_s
+
+//# view-object 2,0
+
+//# transfer-object 2,0 --recipient B
+
+//# view-object 2,0
@@ -0,0 +1,23 @@
+// Copyright (c) Mysten Labs, Inc.
+// SPDX-License-Identifier: Apache-2.0
+
+// tests transferring an object to a literal address, and naming that address afterwards. A name
+// that was never bound is rejected
+
+//# init --accounts A
+
+//# programmable --sender A --inputs 10 @A
+//> SplitCoins(Gas, [Input(0)]);
+//> TransferObjects([Result(0)], Input(1))
+
+//# transfer-object 1,0 --sender A --recipient 0xcafe
+
+//# view-object 1,0
+
+//# set-address R @0xcafe
+
+//# view-object 1,0
+
+//# list-objects --owner R
+
+//# transfer-object 1,0 --sender A --recipient C
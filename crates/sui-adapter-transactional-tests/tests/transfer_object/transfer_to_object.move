@@ -0,0 +1,43 @@
+// Copyright (c) Mysten Labs, Inc.
+// SPDX-License-Identifier: Apache-2.0
+
+// tests transfer-to-object, which places an object under the ownership of another object
+
+//# init --accounts A --addresses test=0x0
+
+//# publish
+
+module test::m {
+    use sui::transfer;
+    use sui::tx_context::{Self, TxContext};
+    use sui::object::{Self, UID};
+
+    struct S has store, key { id: UID }
+    struct NoStore has key { id: UID }
+
+    public entry fun mint_s(ctx: &mut TxContext) {
+        let id = object::new(ctx);
+        transfer::public_transfer(S { id }, tx_context::sender(ctx))
+    }
+
+    public entry fun mint_no_store(ctx: &mut TxContext) {
+        let id = object::new(ctx);
+        transfer::transfer(NoStore { id }, tx_context::sender(ctx))
+    }
+}
+
+// Mint a parent and a child, then nest the child under the parent
+
+//# run test::m::mint_s --sender A
+
+//# run test::m::mint_s --sender A
+
+//# transfer-to-object --child 3,0 --parent 2,0 --sender A
+
+//# view-object 3,0
+
+// Objects without store cannot be transferred to an object
+
+//# run test::m::mint_no_store --sender A
+
+//# transfer-to-object --child 6,0 --parent 2,0 --sender A
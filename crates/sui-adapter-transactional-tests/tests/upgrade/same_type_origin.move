@@ -0,0 +1,43 @@
+// Copyright (c) Mysten Labs, Inc.
+// SPDX-License-Identifier: Apache-2.0
+
+//# init --addresses Test_DepV1=0x0 Test_DepV2=0x0 --accounts A
+
+//# publish --upgradeable --sender A
+module Test_DepV1::DepM1 {
+
+    struct DepObj has key, store { id: sui::object::UID, v: u64 }
+
+    public fun foo(ctx: &mut sui::tx_context::TxContext) {
+        sui::transfer::share_object(DepObj { id: sui::object::new(ctx), v: 42 });
+    }
+
+    public fun mod_obj(o: &mut DepObj) {
+        o.v = o.v - 1;
+    }
+}
+
+
+//# upgrade --package Test_DepV1 --upgrade-capability 1,1 --sender A
+module Test_DepV2::DepM1 {
+
+    struct DepObj has key, store { id: sui::object::UID, v: u64 }
+
+    public fun foo(ctx: &mut sui::tx_context::TxContext) {
+        sui::transfer::share_object(DepObj { id: sui::object::new(ctx), v: 7 });
+    }
+
+    public fun mod_obj(o: &mut DepObj) {
+        o.v = o.v - 2;
+    }
+}
+
+
+//# run Test_DepV1::DepM1::foo
+
+//# run Test_DepV2::DepM1::foo
+
+// objects created by the original and upgraded package have the same type
+//# assert-same-type-origin 3,0 4,0
+
+//# assert-same-type-origin 3,0 1,1
@@ -0,0 +1,30 @@
+// Copyright (c) Mysten Labs, Inc.
+// SPDX-License-Identifier: Apache-2.0
+
+//# init --addresses Test_DepV1=0x0 Test_DepV2=0x0 --accounts A
+
+//# publish --upgradeable --sender A
+module Test_DepV1::DepM1 {
+    struct DepObj has key, store { id: sui::object::UID, v: u64 }
+    public fun mod_obj(o: &mut DepObj) {
+        o.v = 0;
+    }
+}
+
+//# upgrade --package Test_DepV1 --upgrade-capability 1,1 --sender A
+module Test_DepV2::DepM1 {
+    struct DepObj has key, store { id: sui::object::UID, v: u64 }
+    public fun mod_obj(o: &mut DepObj) {
+        o.v = 0;
+    }
+
+    public fun only_defined(o: &mut DepObj) {
+        o.v = 1
+    }
+}
+
+//# view-package-ids 1,0
+
+//# view-package-ids 2,0
+
+//# view-package-ids 1,1
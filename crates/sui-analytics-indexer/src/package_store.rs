@@ -2,12 +2,17 @@
 // SPDX-License-Identifier: Apache-2.0
 
 use async_trait::async_trait;
+use futures::future::try_join_all;
+use serde::Serialize;
+use std::collections::BTreeMap;
 use std::path::Path;
-use std::sync::Arc;
+use std::sync::{Arc, Mutex};
 
 use move_core_types::account_address::AccountAddress;
+use move_core_types::language_storage::TypeTag;
+use move_core_types::value::MoveTypeLayout;
 use sui_package_resolver::{
-    error::Error as PackageResolverError, make_package, Package, PackageStore, Result,
+    error::Error as PackageResolverError, make_package, Package, PackageStore, Resolver, Result,
 };
 use sui_rest_api::Client;
 use sui_types::base_types::{ObjectID, SequenceNumber};
@@ -41,6 +46,9 @@ impl From<Error> for PackageResolverError {
 #[derive(DBMapUtils)]
 pub struct PackageStoreTables {
     pub(crate) packages: DBMap<ObjectID, Object>,
+    /// Every version of every package seen, including ones since superseded by upgrades in place
+    /// (as happens to system packages).
+    pub(crate) package_versions: DBMap<(ObjectID, SequenceNumber), Object>,
 }
 
 impl PackageStoreTables {
@@ -57,26 +65,135 @@ impl PackageStoreTables {
         batch
             .insert_batch(&self.packages, std::iter::once((package.id(), package)))
             .map_err(Error::TypedStore)?;
+        batch
+            .insert_batch(
+                &self.package_versions,
+                std::iter::once(((package.id(), package.version()), package)),
+            )
+            .map_err(Error::TypedStore)?;
         batch.write().map_err(Error::TypedStore)?;
         Ok(())
     }
 }
 
-/// Store which keeps package objects in a local rocksdb store. It is expected that this store is
-/// kept updated with latest version of package objects while iterating over checkpoints. If the
-/// local db is missing (or gets deleted), packages are fetched from a full node and local store is
-/// updated
-pub struct LocalDBPackageStore {
-    package_store_tables: Arc<PackageStoreTables>,
+/// Cache of package objects that [`LocalDBPackageStore`] reads packages from, before falling back
+/// to fetching them from a full node.
+pub trait PackageCache: Send + Sync + 'static {
+    /// The package object at `id`, if it is cached.
+    fn get(&self, id: ObjectID) -> Result<Option<Object>>;
+    /// Cache `package`, replacing the object previously cached at its id, if any.
+    fn insert(&self, package: &Object) -> Result<()>;
+    /// Forget the package object at `id`, if it is cached.
+    fn invalidate(&self, id: ObjectID) -> Result<()>;
+
+    /// The package object at `id` as of `version`, if it is cached. Caches that only keep the
+    /// latest version of each package can only serve that version.
+    fn get_version(&self, id: ObjectID, version: SequenceNumber) -> Result<Option<Object>> {
+        Ok(self.get(id)?.filter(|object| object.version() == version))
+    }
+
+    /// Cache `package` as a past version, without replacing the latest version cached at its id.
+    /// Caches that only keep the latest version of each package can ignore this.
+    fn insert_version(&self, _package: &Object) -> Result<()> {
+        Ok(())
+    }
+}
+
+impl PackageCache for PackageStoreTables {
+    fn get(&self, id: ObjectID) -> Result<Option<Object>> {
+        Ok(self.packages.get(&id).map_err(Error::TypedStore)?)
+    }
+
+    fn insert(&self, package: &Object) -> Result<()> {
+        self.update(package)
+    }
+
+    fn invalidate(&self, id: ObjectID) -> Result<()> {
+        self.packages.remove(&id).map_err(Error::TypedStore)?;
+        Ok(())
+    }
+
+    fn get_version(&self, id: ObjectID, version: SequenceNumber) -> Result<Option<Object>> {
+        Ok(self
+            .package_versions
+            .get(&(id, version))
+            .map_err(Error::TypedStore)?)
+    }
+
+    fn insert_version(&self, package: &Object) -> Result<()> {
+        self.package_versions
+            .insert(&(package.id(), package.version()), package)
+            .map_err(Error::TypedStore)?;
+        Ok(())
+    }
+}
+
+/// Store which keeps package objects in a local rocksdb store (or any other [`PackageCache`]). It
+/// is expected that this store is kept updated with latest version of package objects while
+/// iterating over checkpoints. If the local db is missing (or gets deleted), packages are fetched
+/// from a full node and local store is updated
+pub struct LocalDBPackageStore<C = PackageStoreTables> {
+    cache: Arc<C>,
     fallback_client: Client,
+    /// Layouts resolved so far, shared between clones of the store.
+    layouts: Arc<Mutex<BTreeMap<TypeTag, MoveTypeLayout>>>,
+}
+
+impl<C> Clone for LocalDBPackageStore<C> {
+    fn clone(&self) -> Self {
+        Self {
+            cache: self.cache.clone(),
+            fallback_client: self.fallback_client.clone(),
+            layouts: self.layouts.clone(),
+        }
+    }
 }
 
 impl LocalDBPackageStore {
     pub fn new(path: &Path, rest_url: &str) -> Self {
+        Self::with_cache(PackageStoreTables::new(path), rest_url)
+    }
+
+    /// Summarise the packages currently held in the local store. Only the local db is consulted,
+    /// packages that have not been fetched from the fallback client yet are not accounted for.
+    pub fn stats(&self) -> std::result::Result<PackageStoreStats, Error> {
+        let mut packages: BTreeMap<ObjectID, PackageVersionStats> = BTreeMap::new();
+        for entry in self.cache.packages.safe_iter() {
+            let (_, object) = entry?;
+            let Some(package) = object.data.try_as_package() else {
+                continue;
+            };
+            let size_bytes = object.object_size_for_gas_metering() as u64;
+            let stats = packages
+                .entry(package.original_package_id())
+                .or_insert_with(|| PackageVersionStats {
+                    package_id: package.original_package_id(),
+                    versions: 0,
+                    latest_version: package.version().value(),
+                    total_bytes: 0,
+                });
+            stats.versions += 1;
+            stats.latest_version = stats.latest_version.max(package.version().value());
+            stats.total_bytes += size_bytes;
+        }
+
+        let packages: Vec<_> = packages.into_values().collect();
+        Ok(PackageStoreStats {
+            package_count: packages.iter().map(|p| p.versions).sum(),
+            total_bytes: packages.iter().map(|p| p.total_bytes).sum(),
+            packages,
+        })
+    }
+}
+
+impl<C: PackageCache> LocalDBPackageStore<C> {
+    /// Keep packages in `cache` rather than a local rocksdb store.
+    pub fn with_cache(cache: Arc<C>, rest_url: &str) -> Self {
         let rest_api_url = format!("{}/rest", rest_url);
         Self {
-            package_store_tables: PackageStoreTables::new(path),
+            cache,
             fallback_client: Client::new(rest_api_url),
+            layouts: Arc::new(Mutex::new(BTreeMap::new())),
         }
     }
 
@@ -84,17 +201,18 @@ impl LocalDBPackageStore {
         let Some(_package) = object.data.try_as_package() else {
             return Ok(());
         };
-        self.package_store_tables.update(object)?;
+        self.cache.insert(object)?;
         Ok(())
     }
 
+    /// Drop the package at `id` from the cache, so that it is fetched from the full node again the
+    /// next time it is needed.
+    pub fn invalidate(&self, id: ObjectID) -> Result<()> {
+        self.cache.invalidate(id)
+    }
+
     pub async fn get(&self, id: AccountAddress) -> Result<Object> {
-        let object = if let Some(object) = self
-            .package_store_tables
-            .packages
-            .get(&ObjectID::from(id))
-            .map_err(Error::TypedStore)?
-        {
+        let object = if let Some(object) = self.cache.get(ObjectID::from(id))? {
             object
         } else {
             let object = self
@@ -107,10 +225,101 @@ impl LocalDBPackageStore {
         };
         Ok(object)
     }
+
+    /// The package object at `id` as of `version`, from the cache if it holds that version, and
+    /// from the full node otherwise.
+    pub async fn get_version(&self, id: AccountAddress, version: SequenceNumber) -> Result<Object> {
+        let object_id = ObjectID::from(id);
+        if let Some(object) = self.cache.get_version(object_id, version)? {
+            return Ok(object);
+        }
+        let object = self
+            .fallback_client
+            .get_object_with_version(object_id, version)
+            .await
+            .map_err(|_| PackageResolverError::PackageNotFound(id))?;
+        if object.data.try_as_package().is_some() {
+            self.cache.insert_version(&object)?;
+        }
+        Ok(object)
+    }
+
+    /// Resolve each package in `ids` at the version it is paired with, fetching them concurrently.
+    /// Packages are returned in the same order as `ids`.
+    pub async fn fetch_versions(
+        &self,
+        ids: &[(AccountAddress, SequenceNumber)],
+    ) -> Result<Vec<Arc<Package>>> {
+        try_join_all(ids.iter().map(|(id, version)| async move {
+            let object = self.get_version(*id, *version).await?;
+            Ok(Arc::new(make_package(*id, object.version(), &object)?))
+        }))
+        .await
+    }
+
+    /// Resolve the full layout of `tag`, fetching any packages its types (and type parameters)
+    /// are defined in through this store. Resolved layouts are cached, so they should not be
+    /// expected to reflect packages that are updated afterwards.
+    pub async fn resolve_type_layout(&self, tag: &TypeTag) -> Result<MoveTypeLayout> {
+        if let Some(layout) = self.layouts.lock().unwrap().get(tag) {
+            return Ok(layout.clone());
+        }
+
+        let layout = Resolver::new(self.clone()).type_layout(tag.clone()).await?;
+        self.layouts
+            .lock()
+            .unwrap()
+            .insert(tag.clone(), layout.clone());
+        Ok(layout)
+    }
+}
+
+/// Format used when dumping [`PackageStoreStats`].
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub enum PackageStatsFormat {
+    Json,
+    Csv,
+}
+
+/// Versions held in the local store for a single package, keyed by its original id.
+#[derive(Clone, Debug, Eq, PartialEq, Serialize)]
+pub struct PackageVersionStats {
+    pub package_id: ObjectID,
+    pub versions: u64,
+    pub latest_version: u64,
+    pub total_bytes: u64,
+}
+
+/// Snapshot of the contents of a [`LocalDBPackageStore`].
+#[derive(Clone, Debug, Default, Eq, PartialEq, Serialize)]
+pub struct PackageStoreStats {
+    /// Number of package objects (counting every version).
+    pub package_count: u64,
+    /// Sum of the sizes of all package objects.
+    pub total_bytes: u64,
+    /// Per-package breakdown, ordered by original package id.
+    pub packages: Vec<PackageVersionStats>,
+}
+
+impl PackageStoreStats {
+    /// Render the stats in the given format. JSON contains the full report, while CSV has one
+    /// row per package (the totals can be derived by summing the rows).
+    pub fn serialize(&self, format: PackageStatsFormat) -> anyhow::Result<String> {
+        match format {
+            PackageStatsFormat::Json => Ok(serde_json::to_string_pretty(self)?),
+            PackageStatsFormat::Csv => {
+                let mut writer = csv::Writer::from_writer(vec![]);
+                for package in &self.packages {
+                    writer.serialize(package)?;
+                }
+                Ok(String::from_utf8(writer.into_inner()?)?)
+            }
+        }
+    }
 }
 
 #[async_trait]
-impl PackageStore for LocalDBPackageStore {
+impl<C: PackageCache> PackageStore for LocalDBPackageStore<C> {
     async fn version(&self, id: AccountAddress) -> Result<SequenceNumber> {
         let object = self.get(id).await?;
         Ok(object.version())
@@ -126,3 +335,222 @@ impl PackageStore for LocalDBPackageStore {
         Ok(package)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use move_binary_format::file_format::empty_module;
+    use std::path::PathBuf;
+    use std::str::FromStr;
+    use sui_move_build::BuildConfig;
+    use sui_types::digests::TransactionDigest;
+    use sui_types::move_package::MovePackage;
+
+    fn package(original_id: ObjectID, id: ObjectID, version: u64) -> Object {
+        let mut module = empty_module();
+        module.address_identifiers[0] = original_id.into();
+        let mut bytes = vec![];
+        module.serialize(&mut bytes).unwrap();
+        let package = MovePackage::new(
+            id,
+            SequenceNumber::from_u64(version),
+            BTreeMap::from([("empty".to_string(), bytes)]),
+            u64::MAX,
+            vec![],
+            BTreeMap::new(),
+        )
+        .unwrap();
+        Object::new_from_package(package, TransactionDigest::genesis())
+    }
+
+    /// In-memory cache that records which packages were looked up in it.
+    #[derive(Default)]
+    struct MockCache {
+        packages: Mutex<BTreeMap<ObjectID, Object>>,
+        gets: Mutex<Vec<ObjectID>>,
+    }
+
+    impl PackageCache for MockCache {
+        fn get(&self, id: ObjectID) -> Result<Option<Object>> {
+            self.gets.lock().unwrap().push(id);
+            Ok(self.packages.lock().unwrap().get(&id).cloned())
+        }
+
+        fn insert(&self, package: &Object) -> Result<()> {
+            self.packages
+                .lock()
+                .unwrap()
+                .insert(package.id(), package.clone());
+            Ok(())
+        }
+
+        fn invalidate(&self, id: ObjectID) -> Result<()> {
+            self.packages.lock().unwrap().remove(&id);
+            Ok(())
+        }
+    }
+
+    /// Build one of the package resolver's test packages, published at its own address.
+    fn build_package(dir: &str, dependencies: &[&Object]) -> Object {
+        let mut path = PathBuf::from(env!("CARGO_MANIFEST_DIR"));
+        path.extend(["..", "sui-package-resolver", "tests", "packages", dir]);
+        let modules = BuildConfig::new_for_testing()
+            .build(path)
+            .unwrap()
+            .into_modules();
+        let dependencies = dependencies
+            .iter()
+            .map(|object| object.data.try_as_package().unwrap().clone());
+        Object::new_package_for_testing(&modules, TransactionDigest::genesis(), dependencies)
+            .unwrap()
+    }
+
+    #[test]
+    fn stats_count_versions_per_package() {
+        let dir = tempfile::tempdir().unwrap();
+        let store = LocalDBPackageStore::new(dir.path(), "http://localhost:9000");
+
+        let (a, b) = (
+            ObjectID::from_single_byte(0xa),
+            ObjectID::from_single_byte(0xb),
+        );
+        let a_v2 = ObjectID::from_single_byte(0xa2);
+        let packages = [package(a, a, 1), package(a, a_v2, 2), package(b, b, 1)];
+        for p in &packages {
+            store.update(p).unwrap();
+        }
+
+        let stats = store.stats().unwrap();
+        assert_eq!(stats.package_count, 3);
+        assert_eq!(
+            stats.total_bytes,
+            packages
+                .iter()
+                .map(|p| p.object_size_for_gas_metering() as u64)
+                .sum::<u64>()
+        );
+        let versions: Vec<_> = stats
+            .packages
+            .iter()
+            .map(|p| (p.package_id, p.versions, p.latest_version))
+            .collect();
+        assert_eq!(versions, vec![(a, 2, 2), (b, 1, 1)]);
+
+        let json: serde_json::Value =
+            serde_json::from_str(&stats.serialize(PackageStatsFormat::Json).unwrap()).unwrap();
+        assert_eq!(json["package_count"], 3);
+        assert_eq!(json["packages"].as_array().unwrap().len(), 2);
+
+        let csv = stats.serialize(PackageStatsFormat::Csv).unwrap();
+        let mut lines = csv.lines();
+        assert_eq!(
+            lines.next(),
+            Some("package_id,versions,latest_version,total_bytes")
+        );
+        assert_eq!(lines.count(), 2);
+    }
+
+    #[tokio::test]
+    async fn resolve_generic_type_across_packages() {
+        let dir = tempfile::tempdir().unwrap();
+        let store = LocalDBPackageStore::new(dir.path(), "http://localhost:9000");
+
+        let a0 = build_package("a0", &[]);
+        let b0 = build_package("b0", &[&a0]);
+        store.update(&a0).unwrap();
+        store.update(&b0).unwrap();
+
+        let tag = TypeTag::from_str("0xa0::m::T1<0xb0::m::T0, u8>").unwrap();
+        let layout = store.resolve_type_layout(&tag).await.unwrap();
+        assert_eq!(
+            format!("{layout:#}"),
+            r#"struct 0xa0::m::T1<0xb0::m::T0, u8> {
+    a: address,
+    p: struct 0xb0::m::T0 {
+        m: struct 0xa0::m::T2 {
+            x: u8,
+        },
+        n: struct 0xa0::n::T0 {
+            t: struct 0xa0::m::T1<u16, u32> {
+                a: address,
+                p: u16,
+                q: vector<u32>,
+            },
+            u: struct 0xa0::m::T2 {
+                x: u8,
+            },
+        },
+    },
+    q: vector<u8>,
+}"#
+        );
+
+        // The second resolution is served from the cache.
+        assert_eq!(store.layouts.lock().unwrap().len(), 1);
+        let cached = store.resolve_type_layout(&tag).await.unwrap();
+        assert_eq!(format!("{cached:#}"), format!("{layout:#}"));
+    }
+
+    #[tokio::test]
+    async fn fetch_pinned_package_versions() {
+        let dir = tempfile::tempdir().unwrap();
+        let store = LocalDBPackageStore::new(dir.path(), "http://localhost:9000");
+
+        // `a` is upgraded in place, like a system package
+        let a = ObjectID::from_single_byte(0xa);
+        let b = ObjectID::from_single_byte(0xb);
+        let (a_v1, a_v2, b_v1) = (package(a, a, 1), package(a, a, 2), package(b, b, 1));
+        for p in [&a_v1, &a_v2, &b_v1] {
+            store.update(p).unwrap();
+        }
+
+        let pinned: [(AccountAddress, SequenceNumber); 2] = [
+            (a.into(), SequenceNumber::from_u64(1)),
+            (b.into(), SequenceNumber::from_u64(1)),
+        ];
+        let fetched = store.fetch_versions(&pinned).await.unwrap();
+        let expected = [&a_v1, &b_v1]
+            .map(|object| make_package(object.id().into(), object.version(), object).unwrap());
+        assert_eq!(
+            fetched.iter().map(|p| format!("{p:?}")).collect::<Vec<_>>(),
+            expected
+                .iter()
+                .map(|p| format!("{p:?}"))
+                .collect::<Vec<_>>(),
+        );
+
+        // The latest version is still what the resolver sees
+        assert_eq!(
+            store.version(a.into()).await.unwrap(),
+            SequenceNumber::from_u64(2)
+        );
+    }
+
+    #[tokio::test]
+    async fn packages_are_routed_through_custom_cache() {
+        let cache = Arc::new(MockCache::default());
+        let store = LocalDBPackageStore::with_cache(cache.clone(), "http://localhost:9000");
+
+        let a = ObjectID::from_single_byte(0xa);
+        let a_v2 = ObjectID::from_single_byte(0xa2);
+        store.update(&package(a, a, 1)).unwrap();
+        store.update(&package(a, a_v2, 2)).unwrap();
+        assert_eq!(
+            cache.packages.lock().unwrap().keys().collect::<Vec<_>>(),
+            vec![&a, &a_v2]
+        );
+
+        assert_eq!(
+            store.version(a_v2.into()).await.unwrap(),
+            SequenceNumber::from_u64(2)
+        );
+        store.fetch(a.into()).await.unwrap();
+        assert_eq!(*cache.gets.lock().unwrap(), vec![a_v2, a]);
+
+        store.invalidate(a).unwrap();
+        assert_eq!(
+            cache.packages.lock().unwrap().keys().collect::<Vec<_>>(),
+            vec![&a_v2]
+        );
+    }
+}
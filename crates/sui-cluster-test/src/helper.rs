@@ -146,6 +146,9 @@ impl ObjectChecker {
             (None, Some(SuiObjectResponseError::DisplayError { error })) => {
                 panic!("Display Error: {error:?}");
             }
+            (None, Some(SuiObjectResponseError::ContentsDecodeError { error })) => {
+                panic!("Contents Decode Error: {error:?}");
+            }
             (None, None) | (None, Some(SuiObjectResponseError::Unknown)) => {
                 panic!("Unexpected response: object not found and no specific error provided");
             }
@@ -1561,12 +1561,16 @@ impl AuthorityState {
         ))
     }
 
-    /// The object ID for gas can be any object ID, even for an uncreated object
+    /// The object ID for gas can be any object ID, even for an uncreated object. Gas is paid
+    /// from a mock coin, unless `gas_object` names an existing gas coin to pay from instead, on
+    /// behalf of its owner, who need not be `sender` (as with a sponsored transaction). Nothing is
+    /// committed either way, so that coin is left untouched.
     pub async fn dev_inspect_transaction_block(
         &self,
         sender: SuiAddress,
         transaction_kind: TransactionKind,
         gas_price: Option<u64>,
+        gas_object: Option<ObjectID>,
     ) -> SuiResult<DevInspectResults> {
         let epoch_store = self.load_epoch_store_one_call_per_task();
         if !self.is_fullnode(&epoch_store) {
@@ -1590,16 +1594,44 @@ impl AuthorityState {
                 }
             }
         };
+        let (gas_object, gas_budget) = match gas_object {
+            Some(gas_object_id) => {
+                let gas_object = self.get_object(&gas_object_id).await?.ok_or(
+                    UserInputError::ObjectNotFound {
+                        object_id: gas_object_id,
+                        version: None,
+                    },
+                )?;
+                if !gas_object.is_gas_coin() {
+                    return Err(UserInputError::InvalidGasObject {
+                        object_id: gas_object_id,
+                    }
+                    .into());
+                }
+                if !matches!(gas_object.owner, Owner::AddressOwner(_)) {
+                    return Err(UserInputError::GasObjectNotOwnedObject {
+                        owner: gas_object.owner,
+                    }
+                    .into());
+                }
+                // the budget cannot exceed what the coin can pay for
+                let balance = gas_object.get_coin_value_unsafe();
+                (gas_object, max_tx_gas.min(balance))
+            }
+            None => {
+                let gas_object_id = ObjectID::random();
+                // give the gas object 2x the max gas to have coin balance to play with during
+                // execution
+                let gas_object = Object::new_move(
+                    MoveObject::new_gas_coin(SequenceNumber::new(), gas_object_id, max_tx_gas * 2),
+                    Owner::AddressOwner(sender),
+                    TransactionDigest::genesis(),
+                );
+                (gas_object, max_tx_gas)
+            }
+        };
         let gas_status =
-            SuiGasStatus::new(max_tx_gas, gas_price, reference_gas_price, protocol_config)?;
-
-        let gas_object_id = ObjectID::random();
-        // give the gas object 2x the max gas to have coin balance to play with during execution
-        let gas_object = Object::new_move(
-            MoveObject::new_gas_coin(SequenceNumber::new(), gas_object_id, max_tx_gas * 2),
-            Owner::AddressOwner(sender),
-            TransactionDigest::genesis(),
-        );
+            SuiGasStatus::new(gas_budget, gas_price, reference_gas_price, protocol_config)?;
 
         let input_object_kinds = transaction_kind.input_objects()?;
         let receiving_object_refs = transaction_kind.receiving_objects();
@@ -1620,7 +1652,6 @@ impl AuthorityState {
                 gas_object,
             )?;
 
-        let gas_budget = max_tx_gas;
         let data = TransactionData::new(
             transaction_kind,
             sender,
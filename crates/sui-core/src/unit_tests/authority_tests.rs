@@ -572,7 +572,7 @@ async fn test_dev_inspect_dynamic_field() {
     };
     let kind = TransactionKind::programmable(pt);
     let DevInspectResults { error, .. } = fullnode
-        .dev_inspect_transaction_block(sender, kind, None)
+        .dev_inspect_transaction_block(sender, kind, None, None)
         .await
         .unwrap();
     // produces an error
@@ -876,7 +876,7 @@ async fn test_dev_inspect_gas_coin_argument() {
     };
     let kind = TransactionKind::programmable(pt);
     let results = fullnode
-        .dev_inspect_transaction_block(sender, kind, None)
+        .dev_inspect_transaction_block(sender, kind, None, None)
         .await
         .unwrap()
         .results
@@ -906,6 +906,93 @@ async fn test_dev_inspect_gas_coin_argument() {
     assert!(return_values.is_empty());
 }
 
+#[tokio::test]
+async fn test_dev_inspect_with_gas_object() {
+    let sender = SuiAddress::random_for_testing_only();
+    let gas_object_id = ObjectID::random();
+    let (_validator, fullnode, _object_basics) =
+        init_state_with_ids_and_object_basics_with_fullnode(vec![(sender, gas_object_id)]).await;
+    let epoch_store = fullnode.epoch_store_for_testing();
+    let max_tx_gas = epoch_store.protocol_config().max_tx_gas();
+
+    let recipient = SuiAddress::random_for_testing_only();
+    let amount = 500;
+    let kind = {
+        let mut builder = ProgrammableTransactionBuilder::new();
+        builder.pay_sui(vec![recipient], vec![amount]).unwrap();
+        TransactionKind::programmable(builder.finish())
+    };
+    // The value of the gas coin after the split, as seen by the transaction
+    let split_gas_coin_value = |results: DevInspectResults| {
+        let results = results.results.unwrap();
+        let (arg, arg_value, arg_type) = &results[0].mutable_reference_outputs[0];
+        assert_eq!(arg, &SuiArgument::GasCoin);
+        let arg_type: TypeTag = arg_type.clone().try_into().unwrap();
+        assert_eq!(arg_type, TypeTag::Struct(Box::new(GasCoin::type_())));
+        bcs::from_bytes::<GasCoin>(arg_value).unwrap().value()
+    };
+
+    // Without a gas object, gas is paid from a mock coin holding twice the max budget
+    let results = fullnode
+        .dev_inspect_transaction_block(sender, kind.clone(), None, None)
+        .await
+        .unwrap();
+    assert_eq!(results.error, None);
+    assert_eq!(split_gas_coin_value(results), max_tx_gas - amount);
+
+    // With one, the transaction sees the sender's coin instead
+    let gas_object = fullnode.get_object(&gas_object_id).await.unwrap().unwrap();
+    let results = fullnode
+        .dev_inspect_transaction_block(sender, kind.clone(), None, Some(gas_object_id))
+        .await
+        .unwrap();
+    assert_eq!(results.error, None);
+    assert_eq!(
+        split_gas_coin_value(results),
+        GAS_VALUE_FOR_TESTING - max_tx_gas - amount
+    );
+    // ...but nothing is committed
+    assert_eq!(
+        fullnode.get_object(&gas_object_id).await.unwrap().unwrap(),
+        gas_object
+    );
+
+    // The gas object can belong to someone other than the sender, who sponsors the transaction
+    let results = fullnode
+        .dev_inspect_transaction_block(recipient, kind, None, Some(gas_object_id))
+        .await
+        .unwrap();
+    assert_eq!(results.error, None);
+    assert_eq!(
+        split_gas_coin_value(results),
+        GAS_VALUE_FOR_TESTING - max_tx_gas - amount
+    );
+
+    // The gas object cannot also be an input to the transaction
+    let kind = {
+        let mut builder = ProgrammableTransactionBuilder::new();
+        builder
+            .obj(ObjectArg::ImmOrOwnedObject(
+                gas_object.compute_object_reference(),
+            ))
+            .unwrap();
+        TransactionKind::programmable(builder.finish())
+    };
+    let error = fullnode
+        .dev_inspect_transaction_block(sender, kind, None, Some(gas_object_id))
+        .await
+        .unwrap_err();
+    assert!(
+        matches!(
+            error,
+            SuiError::UserInputError {
+                error: UserInputError::MutableObjectUsedMoreThanOnce { object_id }
+            } if object_id == gas_object_id
+        ),
+        "unexpected error: {error:?}"
+    );
+}
+
 #[tokio::test]
 async fn test_dev_inspect_gas_price() {
     let (_, fullnode, _object_basics) =
@@ -921,7 +1008,7 @@ async fn test_dev_inspect_gas_price() {
     };
     let kind = TransactionKind::programmable(pt);
     let error = fullnode
-        .dev_inspect_transaction_block(sender, kind.clone(), Some(1))
+        .dev_inspect_transaction_block(sender, kind.clone(), Some(1), None)
         .await
         .unwrap_err();
     assert!(
@@ -935,7 +1022,12 @@ async fn test_dev_inspect_gas_price() {
     let epoch_store = fullnode.epoch_store_for_testing();
     let protocol_config = epoch_store.protocol_config();
     let error = fullnode
-        .dev_inspect_transaction_block(sender, kind, Some(protocol_config.max_gas_price() + 1))
+        .dev_inspect_transaction_block(
+            sender,
+            kind,
+            Some(protocol_config.max_gas_price() + 1),
+            None,
+        )
         .await
         .unwrap_err();
     assert!(
@@ -984,6 +1076,7 @@ async fn test_dev_inspect_uses_unbound_object() {
             sender,
             kind,
             Some(fullnode.reference_gas_price_for_testing().unwrap()),
+            None,
         )
         .await;
     let Err(err) = result else { panic!() };
@@ -1125,7 +1218,7 @@ async fn test_dry_run_dev_inspect_dynamic_field_too_new() {
     let rgp = fullnode.reference_gas_price_for_testing().unwrap();
     // dev inspect
     let DevInspectResults { effects, .. } = fullnode
-        .dev_inspect_transaction_block(sender, kind, Some(rgp))
+        .dev_inspect_transaction_block(sender, kind, Some(rgp), None)
         .await
         .unwrap();
     assert_eq!(effects.deleted().len(), 0);
@@ -1178,7 +1271,7 @@ async fn test_dry_run_dev_inspect_max_gas_version() {
     let kind = TransactionKind::programmable(pt.clone());
     // dev inspect
     let DevInspectResults { effects, .. } = fullnode
-        .dev_inspect_transaction_block(sender, kind, Some(rgp + 100))
+        .dev_inspect_transaction_block(sender, kind, Some(rgp + 100), None)
         .await
         .unwrap();
     assert_eq!(effects.status(), &SuiExecutionStatus::Success);
@@ -4458,7 +4551,7 @@ pub async fn call_dev_inspect(
     let kind = TransactionKind::programmable(builder.finish());
     let rgp = authority.reference_gas_price_for_testing().unwrap();
     authority
-        .dev_inspect_transaction_block(*sender, kind, Some(rgp))
+        .dev_inspect_transaction_block(*sender, kind, Some(rgp), None)
         .await
 }
 
@@ -5130,6 +5223,7 @@ async fn test_for_inc_201_dev_inspect() {
             sender,
             kind,
             Some(fullnode.reference_gas_price_for_testing().unwrap() + 1000),
+            None,
         )
         .await
         .unwrap();
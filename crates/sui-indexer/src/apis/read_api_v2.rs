@@ -1,39 +1,191 @@
 // Copyright (c) Mysten Labs, Inc.
 // SPDX-License-Identifier: Apache-2.0
 
+use std::future::Future;
+use std::sync::{Arc, Mutex};
+use std::time::Instant;
+
 use async_trait::async_trait;
+use cached::{Cached, SizedCache};
 use jsonrpsee::core::RpcResult;
 use jsonrpsee::RpcModule;
 use move_core_types::value::MoveStructLayout;
+use prometheus::HistogramTimer;
 use sui_json_rpc::error::SuiRpcInputError;
 use sui_types::error::SuiObjectResponseError;
-use sui_types::object::ObjectRead;
+use sui_types::object::{Object, ObjectRead};
+use tracing::field::Empty;
+use tracing::{instrument, Span};
 
 use crate::errors::IndexerError;
 use crate::indexer_reader::IndexerReader;
+use crate::metrics::ReadApiV2Metrics;
 use sui_json_rpc::api::{ReadApiServer, QUERY_MAX_RESULT_LIMIT};
 use sui_json_rpc::SuiRpcModule;
 use sui_json_rpc_types::{
     Checkpoint, CheckpointId, CheckpointPage, DisplayFieldsResponse, ProtocolConfigResponse,
-    SuiEvent, SuiGetPastObjectRequest, SuiObjectDataOptions, SuiObjectResponse,
+    SuiEvent, SuiGetPastObjectRequest, SuiObjectData, SuiObjectDataOptions, SuiObjectResponse,
     SuiPastObjectResponse, SuiTransactionBlockResponse, SuiTransactionBlockResponseOptions,
 };
 use sui_open_rpc::Module;
 use sui_protocol_config::{ProtocolConfig, ProtocolVersion};
-use sui_types::base_types::{ObjectID, SequenceNumber};
+use sui_types::base_types::{ObjectID, ObjectRef, SequenceNumber};
 use sui_types::digests::{ChainIdentifier, TransactionDigest};
 use sui_types::sui_serde::BigInt;
 
 use sui_json_rpc_types::SuiLoadedChildObjectsResponse;
 
+/// Converts an object into the data returned for it. If its contents are requested but cannot be
+/// decoded, e.g. because its layout could not be resolved, the raw BCS is returned in their place,
+/// along with a [`SuiObjectResponseError::ContentsDecodeError`].
+fn object_data(
+    object_ref: ObjectRef,
+    o: Object,
+    layout: Option<MoveStructLayout>,
+    options: SuiObjectDataOptions,
+    display_fields: Option<DisplayFieldsResponse>,
+) -> anyhow::Result<(SuiObjectData, Option<SuiObjectResponseError>)> {
+    match (
+        object_ref,
+        o.clone(),
+        layout,
+        options.clone(),
+        display_fields.clone(),
+    )
+        .try_into()
+    {
+        Ok(data) => Ok((data, None)),
+        Err(e) if options.show_content => {
+            let options = SuiObjectDataOptions {
+                show_content: false,
+                show_bcs: true,
+                ..options
+            };
+            let data = (object_ref, o, None, options, display_fields).try_into()?;
+            Ok((
+                data,
+                Some(SuiObjectResponseError::ContentsDecodeError {
+                    error: e.to_string(),
+                }),
+            ))
+        }
+        Err(e) => Err(e),
+    }
+}
+
+/// The error returned in place of a Display when rendering them is disabled.
+const DISPLAY_DISABLED: &str = "Display rendering is disabled on this indexer";
+
+/// Displays rendered for objects, by object ID and version.
+type RenderedDisplayCache = SizedCache<(ObjectID, SequenceNumber), DisplayFieldsResponse>;
+
+/// A part of serving a request that is timed separately, both on the request's span and in
+/// [`ReadApiV2Metrics::stage_latency`].
+#[derive(Clone, Copy)]
+enum Stage {
+    Db,
+    Render,
+}
+
+impl Stage {
+    fn label(self) -> &'static str {
+        match self {
+            Stage::Db => "db",
+            Stage::Render => "render",
+        }
+    }
+
+    /// The field on the request's span that the stage's duration is recorded in.
+    fn span_field(self) -> &'static str {
+        match self {
+            Stage::Db => "db_ms",
+            Stage::Render => "render_ms",
+        }
+    }
+}
+
 #[derive(Clone)]
-pub(crate) struct ReadApiV2 {
+pub struct ReadApiV2 {
     inner: IndexerReader,
+    display_cache: Option<Arc<Mutex<RenderedDisplayCache>>>,
+    metrics: Option<ReadApiV2Metrics>,
+    max_checkpoint_tx_digests: Option<usize>,
+    display_disabled: bool,
 }
 
 impl ReadApiV2 {
     pub fn new(inner: IndexerReader) -> Self {
-        Self { inner }
+        Self {
+            inner,
+            display_cache: None,
+            metrics: None,
+            max_checkpoint_tx_digests: None,
+            display_disabled: false,
+        }
+    }
+
+    /// Bound the number of transaction digests returned by a page of `get_checkpoints`. Pages
+    /// end early rather than exceed it, but always hold at least one checkpoint, with all its
+    /// transaction digests. A page that ends early reports that it has a next page, which starts
+    /// from the first checkpoint that was left out.
+    pub fn with_max_checkpoint_tx_digests(mut self, max: usize) -> Self {
+        self.max_checkpoint_tx_digests = Some(max);
+        self
+    }
+
+    /// Record how long each method takes to serve, and how much of that is spent in the DB and
+    /// rendering Displays.
+    pub fn with_metrics(mut self, metrics: ReadApiV2Metrics) -> Self {
+        self.metrics = Some(metrics);
+        self
+    }
+
+    /// Time a request to `method`, until the returned timer is dropped.
+    fn start_timer(&self, method: &str) -> Option<HistogramTimer> {
+        self.metrics
+            .as_ref()
+            .map(|m| m.request_latency.with_label_values(&[method]).start_timer())
+    }
+
+    /// Run `f` as `stage` of serving `method`, recording its duration on the current span.
+    async fn timed<T>(&self, method: &str, stage: Stage, f: impl Future<Output = T>) -> T {
+        let start = Instant::now();
+        let result = f.await;
+        let elapsed = start.elapsed();
+        Span::current().record(stage.span_field(), elapsed.as_millis() as u64);
+        if let Some(metrics) = &self.metrics {
+            metrics
+                .stage_latency
+                .with_label_values(&[method, stage.label()])
+                .observe(elapsed.as_secs_f64());
+        }
+        result
+    }
+
+    /// Remember up to `capacity` rendered Displays, so that reading an object again at the same
+    /// version does not render it again. A new version of an object is always rendered afresh,
+    /// but an update to the Display for its type is only picked up once the object changes
+    /// version or is evicted. Renders that fail, even in part, are not remembered, so they are
+    /// retried on the next read.
+    pub fn with_display_cache(mut self, capacity: usize) -> Self {
+        self.display_cache = Some(Arc::new(Mutex::new(SizedCache::with_size(capacity))));
+        self
+    }
+
+    /// Never render Displays: requests that ask for them get an empty Display with an error
+    /// explaining that rendering is disabled instead.
+    pub fn with_display_disabled(mut self) -> Self {
+        self.display_disabled = true;
+        self
+    }
+
+    fn display_disabled_response() -> DisplayFieldsResponse {
+        DisplayFieldsResponse {
+            data: None,
+            error: Some(SuiObjectResponseError::DisplayError {
+                error: DISPLAY_DISABLED.to_string(),
+            }),
+        }
     }
 
     async fn get_checkpoint(&self, id: CheckpointId) -> Result<Checkpoint, IndexerError> {
@@ -56,12 +208,92 @@ impl ReadApiV2 {
             .await
     }
 
+    /// The range of checkpoints this indexer can serve, as `(lowest_available, highest)`.
+    /// Clients should not request checkpoints below the low watermark, as they have been pruned.
+    pub async fn get_available_checkpoint_range(&self) -> Result<(u64, u64), IndexerError> {
+        self.inner
+            .spawn_blocking(|this| this.get_available_checkpoint_range())
+            .await
+    }
+
     async fn get_chain_identifier(&self) -> RpcResult<ChainIdentifier> {
         let genesis_checkpoint = self.get_checkpoint(CheckpointId::SequenceNumber(0)).await?;
         Ok(ChainIdentifier::from(genesis_checkpoint.digest))
     }
 
-    async fn get_display_fields(
+    /// Render the Display registered for `original_object`'s type, if there is one. Fails if
+    /// `original_layout` does not describe the object's contents.
+    pub async fn get_display_fields(
+        &self,
+        original_object: &sui_types::object::Object,
+        original_layout: &Option<MoveStructLayout>,
+    ) -> Result<DisplayFieldsResponse, IndexerError> {
+        if self.display_disabled {
+            return Ok(Self::display_disabled_response());
+        }
+        let Some(cache) = &self.display_cache else {
+            return self
+                .render_display_fields(original_object, original_layout)
+                .await;
+        };
+
+        let key = (original_object.id(), original_object.version());
+        if let Some(rendered) = cache.lock().unwrap().cache_get(&key) {
+            return Ok(rendered.clone());
+        }
+        let rendered = self
+            .render_display_fields(original_object, original_layout)
+            .await?;
+        if rendered.error.is_none() {
+            cache.lock().unwrap().cache_set(key, rendered.clone());
+        }
+        Ok(rendered)
+    }
+
+    /// Render the Displays for `objects` in one batch (see
+    /// [`IndexerReader::batch_resolve_display`]), skipping any that are already cached.
+    pub async fn batch_display_fields(
+        &self,
+        objects: Vec<(sui_types::object::Object, Option<MoveStructLayout>)>,
+    ) -> Result<Vec<DisplayFieldsResponse>, IndexerError> {
+        if self.display_disabled {
+            return Ok(vec![Self::display_disabled_response(); objects.len()]);
+        }
+        let Some(cache) = &self.display_cache else {
+            return self.inner.batch_resolve_display(objects).await;
+        };
+
+        let mut rendered: Vec<_> = {
+            let mut cache = cache.lock().unwrap();
+            objects
+                .iter()
+                .map(|(o, _)| cache.cache_get(&(o.id(), o.version())).cloned())
+                .collect()
+        };
+        let misses: Vec<_> = objects
+            .into_iter()
+            .zip(&rendered)
+            .filter(|(_, cached)| cached.is_none())
+            .map(|(object, _)| object)
+            .collect();
+        let keys: Vec<_> = misses.iter().map(|(o, _)| (o.id(), o.version())).collect();
+        let mut fresh = keys
+            .into_iter()
+            .zip(self.inner.batch_resolve_display(misses).await?);
+
+        let mut cache = cache.lock().unwrap();
+        for slot in rendered.iter_mut().filter(|cached| cached.is_none()) {
+            let (key, display) = fresh.next().expect("a Display is rendered for every miss");
+            // Like `get_display_fields`, only successful renders are remembered
+            if display.error.is_none() {
+                cache.cache_set(key, display.clone());
+            }
+            *slot = Some(display);
+        }
+        Ok(rendered.into_iter().flatten().collect())
+    }
+
+    async fn render_display_fields(
         &self,
         original_object: &sui_types::object::Object,
         original_layout: &Option<MoveStructLayout>,
@@ -91,15 +323,23 @@ impl ReadApiV2 {
 
 #[async_trait]
 impl ReadApiServer for ReadApiV2 {
+    #[instrument(skip_all, fields(%object_id, db_ms = Empty, render_ms = Empty))]
     async fn get_object(
         &self,
         object_id: ObjectID,
         options: Option<SuiObjectDataOptions>,
     ) -> RpcResult<SuiObjectResponse> {
+        let _timer = self.start_timer("get_object");
         let options = options.unwrap_or_default();
         let object_read = self
-            .inner
-            .get_object_read_in_blocking_task(object_id)
+            .timed(
+                "get_object",
+                Stage::Db,
+                self.inner.get_object_read_with_layout_in_blocking_task(
+                    object_id,
+                    options.requires_layout(),
+                ),
+            )
             .await?;
 
         match object_read {
@@ -109,11 +349,19 @@ impl ReadApiServer for ReadApiV2 {
             ObjectRead::Exists(object_ref, o, layout) => {
                 let mut display_fields = None;
                 if options.show_display {
-                    match self.get_display_fields(&o, &layout).await {
+                    match self
+                        .timed(
+                            "get_object",
+                            Stage::Render,
+                            self.get_display_fields(&o, &layout),
+                        )
+                        .await
+                    {
                         Ok(rendered_fields) => display_fields = Some(rendered_fields),
                         Err(e) => {
+                            let (data, _) = object_data(object_ref, o, layout, options, None)?;
                             return Ok(SuiObjectResponse::new(
-                                Some((object_ref, o, layout, options, None).try_into()?),
+                                Some(data),
                                 Some(SuiObjectResponseError::DisplayError {
                                     error: e.to_string(),
                                 }),
@@ -121,9 +369,8 @@ impl ReadApiServer for ReadApiV2 {
                         }
                     }
                 }
-                Ok(SuiObjectResponse::new_with_data(
-                    (object_ref, o, layout, options, display_fields).try_into()?,
-                ))
+                let (data, error) = object_data(object_ref, o, layout, options, display_fields)?;
+                Ok(SuiObjectResponse::new(Some(data), error))
             }
             ObjectRead::Deleted((object_id, version, digest)) => Ok(
                 SuiObjectResponse::new_with_error(SuiObjectResponseError::Deleted {
@@ -135,41 +382,116 @@ impl ReadApiServer for ReadApiV2 {
         }
     }
 
-    // For ease of implementation we just forward to the single object query, although in the
-    // future we may want to improve the performance by having a more naitive multi_get
-    // functionality
+    #[instrument(skip_all, fields(ids = object_ids.len(), db_ms = Empty, render_ms = Empty))]
     async fn multi_get_objects(
         &self,
         object_ids: Vec<ObjectID>,
         options: Option<SuiObjectDataOptions>,
     ) -> RpcResult<Vec<SuiObjectResponse>> {
+        let _timer = self.start_timer("multi_get_objects");
         if object_ids.len() > *QUERY_MAX_RESULT_LIMIT {
             return Err(
                 SuiRpcInputError::SizeLimitExceeded(QUERY_MAX_RESULT_LIMIT.to_string()).into(),
             );
         }
 
+        let options = options.unwrap_or_default();
+        if !options.show_display {
+            // For ease of implementation we just forward to the single object query, although in
+            // the future we may want to improve the performance by having a more naitive
+            // multi_get functionality
+            let mut futures = vec![];
+            for object_id in object_ids {
+                futures.push(self.get_object(object_id, Some(options.clone())));
+            }
+
+            return futures::future::join_all(futures)
+                .await
+                .into_iter()
+                .collect::<Result<Vec<_>, _>>();
+        }
+
+        // Read the objects first, and then render all their Displays together, so the Display for
+        // each type is only fetched once.
         let mut futures = vec![];
         for object_id in object_ids {
-            futures.push(self.get_object(object_id, options.clone()));
+            futures.push(
+                self.inner
+                    .get_object_read_with_layout_in_blocking_task(object_id, true),
+            );
         }
-
-        futures::future::join_all(futures)
+        let object_reads = self
+            .timed(
+                "multi_get_objects",
+                Stage::Db,
+                futures::future::join_all(futures),
+            )
             .await
             .into_iter()
-            .collect::<Result<Vec<_>, _>>()
+            .collect::<Result<Vec<_>, _>>()?;
+
+        let objects = object_reads
+            .iter()
+            .filter_map(|object_read| match object_read {
+                ObjectRead::Exists(_, o, layout) => Some((o.clone(), layout.clone())),
+                _ => None,
+            })
+            .collect();
+        let mut display_fields = self
+            .timed(
+                "multi_get_objects",
+                Stage::Render,
+                self.batch_display_fields(objects),
+            )
+            .await?
+            .into_iter();
+
+        let mut responses = vec![];
+        for object_read in object_reads {
+            responses.push(match object_read {
+                ObjectRead::NotExists(id) => {
+                    SuiObjectResponse::new_with_error(SuiObjectResponseError::NotExists {
+                        object_id: id,
+                    })
+                }
+                ObjectRead::Exists(object_ref, o, layout) => {
+                    let display_fields = display_fields.next();
+                    let (data, error) =
+                        object_data(object_ref, o, layout, options.clone(), display_fields)?;
+                    SuiObjectResponse::new(Some(data), error)
+                }
+                ObjectRead::Deleted((object_id, version, digest)) => {
+                    SuiObjectResponse::new_with_error(SuiObjectResponseError::Deleted {
+                        object_id,
+                        version,
+                        digest,
+                    })
+                }
+            });
+        }
+        Ok(responses)
     }
 
+    #[instrument(skip_all, fields(db_ms = Empty))]
     async fn get_total_transaction_blocks(&self) -> RpcResult<BigInt<u64>> {
-        let checkpoint = self.get_latest_checkpoint().await?;
+        let _timer = self.start_timer("get_total_transaction_blocks");
+        let checkpoint = self
+            .timed(
+                "get_total_transaction_blocks",
+                Stage::Db,
+                self.get_latest_checkpoint(),
+            )
+            .await?;
         Ok(BigInt::from(checkpoint.network_total_transactions))
     }
 
+    #[instrument(skip_all, fields(%digest))]
     async fn get_transaction_block(
         &self,
         digest: TransactionDigest,
         options: Option<SuiTransactionBlockResponseOptions>,
     ) -> RpcResult<SuiTransactionBlockResponse> {
+        let _timer = self.start_timer("get_transaction_block");
         let mut txn = self
             .multi_get_transaction_blocks(vec![digest], options)
             .await?;
@@ -181,11 +503,13 @@ impl ReadApiServer for ReadApiV2 {
         Ok(txn)
     }
 
+    #[instrument(skip_all, fields(ids = digests.len(), db_ms = Empty))]
     async fn multi_get_transaction_blocks(
         &self,
         digests: Vec<TransactionDigest>,
         options: Option<SuiTransactionBlockResponseOptions>,
     ) -> RpcResult<Vec<SuiTransactionBlockResponse>> {
+        let _timer = self.start_timer("multi_get_transaction_blocks");
         let num_digests = digests.len();
         if num_digests > *sui_json_rpc::api::QUERY_MAX_RESULT_LIMIT {
             Err(SuiRpcInputError::SizeLimitExceeded(
@@ -195,51 +519,73 @@ impl ReadApiServer for ReadApiV2 {
 
         let options = options.unwrap_or_default();
         let txns = self
-            .inner
-            .multi_get_transaction_block_response_in_blocking_task(digests, options)
+            .timed(
+                "multi_get_transaction_blocks",
+                Stage::Db,
+                self.inner
+                    .multi_get_transaction_block_response_in_blocking_task(digests, options),
+            )
             .await?;
 
         Ok(txns)
     }
 
+    #[instrument(skip_all)]
     async fn try_get_past_object(
         &self,
         _object_id: ObjectID,
         _version: SequenceNumber,
         _options: Option<SuiObjectDataOptions>,
     ) -> RpcResult<SuiPastObjectResponse> {
+        let _timer = self.start_timer("try_get_past_object");
         Err(jsonrpsee::types::error::CallError::Custom(
             jsonrpsee::types::error::ErrorCode::MethodNotFound.into(),
         )
         .into())
     }
 
+    #[instrument(skip_all, fields(ids = _past_objects.len()))]
     async fn try_multi_get_past_objects(
         &self,
         _past_objects: Vec<SuiGetPastObjectRequest>,
         _options: Option<SuiObjectDataOptions>,
     ) -> RpcResult<Vec<SuiPastObjectResponse>> {
+        let _timer = self.start_timer("try_multi_get_past_objects");
         Err(jsonrpsee::types::error::CallError::Custom(
             jsonrpsee::types::error::ErrorCode::MethodNotFound.into(),
         )
         .into())
     }
 
+    #[instrument(skip_all, fields(db_ms = Empty))]
     async fn get_latest_checkpoint_sequence_number(&self) -> RpcResult<BigInt<u64>> {
-        let checkpoint = self.get_latest_checkpoint().await?;
+        let _timer = self.start_timer("get_latest_checkpoint_sequence_number");
+        let checkpoint = self
+            .timed(
+                "get_latest_checkpoint_sequence_number",
+                Stage::Db,
+                self.get_latest_checkpoint(),
+            )
+            .await?;
         Ok(BigInt::from(checkpoint.sequence_number))
     }
 
+    #[instrument(skip_all, fields(?id, db_ms = Empty))]
     async fn get_checkpoint(&self, id: CheckpointId) -> RpcResult<Checkpoint> {
-        self.get_checkpoint(id).await.map_err(Into::into)
+        let _timer = self.start_timer("get_checkpoint");
+        self.timed("get_checkpoint", Stage::Db, self.get_checkpoint(id))
+            .await
+            .map_err(Into::into)
     }
 
+    #[instrument(skip_all, fields(?limit, db_ms = Empty))]
     async fn get_checkpoints(
         &self,
         cursor: Option<BigInt<u64>>,
         limit: Option<usize>,
         descending_order: bool,
     ) -> RpcResult<CheckpointPage> {
+        let _timer = self.start_timer("get_checkpoints");
         let cursor = cursor.map(BigInt::into_inner);
         let limit = sui_json_rpc::api::validate_limit(
             limit,
@@ -247,12 +593,27 @@ impl ReadApiServer for ReadApiV2 {
         )
         .map_err(SuiRpcInputError::from)?;
 
-        let mut checkpoints = self
-            .inner
-            .spawn_blocking(move |this| this.get_checkpoints(cursor, limit + 1, descending_order))
+        let max_tx_digests = self.max_checkpoint_tx_digests;
+        let (mut checkpoints, ended_early) = self
+            .timed(
+                "get_checkpoints",
+                Stage::Db,
+                self.inner.spawn_blocking(move |this| match max_tx_digests {
+                    Some(max) => this.get_checkpoints_within_tx_digests(
+                        cursor,
+                        limit + 1,
+                        descending_order,
+                        max,
+                    ),
+                    None => Ok((
+                        this.get_checkpoints(cursor, limit + 1, descending_order)?,
+                        false,
+                    )),
+                }),
+            )
             .await?;
 
-        let has_next_page = checkpoints.len() > limit;
+        let has_next_page = ended_early || checkpoints.len() > limit;
         checkpoints.truncate(limit);
 
         let next_cursor = checkpoints.last().map(|d| d.sequence_number.into());
@@ -264,6 +625,7 @@ impl ReadApiServer for ReadApiV2 {
         })
     }
 
+    #[instrument(skip_all)]
     async fn get_checkpoints_deprecated_limit(
         &self,
         cursor: Option<BigInt<u64>>,
@@ -278,37 +640,52 @@ impl ReadApiServer for ReadApiV2 {
         .await
     }
 
+    #[instrument(skip_all, fields(%transaction_digest, db_ms = Empty))]
     async fn get_events(&self, transaction_digest: TransactionDigest) -> RpcResult<Vec<SuiEvent>> {
-        self.inner
-            .get_transaction_events_in_blocking_task(transaction_digest)
-            .await
-            .map_err(Into::into)
+        let _timer = self.start_timer("get_events");
+        self.timed(
+            "get_events",
+            Stage::Db,
+            self.inner
+                .get_transaction_events_in_blocking_task(transaction_digest),
+        )
+        .await
+        .map_err(Into::into)
     }
 
+    #[instrument(skip_all)]
     async fn get_loaded_child_objects(
         &self,
         _digest: TransactionDigest,
     ) -> RpcResult<SuiLoadedChildObjectsResponse> {
+        let _timer = self.start_timer("get_loaded_child_objects");
         Err(jsonrpsee::types::error::CallError::Custom(
             jsonrpsee::types::error::ErrorCode::MethodNotFound.into(),
         )
         .into())
     }
 
+    #[instrument(skip_all, fields(?version, db_ms = Empty))]
     async fn get_protocol_config(
         &self,
         version: Option<BigInt<u64>>,
     ) -> RpcResult<ProtocolConfigResponse> {
-        let chain = self.get_chain_identifier().await?.chain();
-        let version = if let Some(version) = version {
-            (*version).into()
-        } else {
-            let latest_epoch = self
-                .inner
-                .spawn_blocking(|this| this.get_latest_epoch_info_from_db())
-                .await?;
-            (latest_epoch.protocol_version as u64).into()
-        };
+        let _timer = self.start_timer("get_protocol_config");
+        let (chain, version) = self
+            .timed("get_protocol_config", Stage::Db, async {
+                let chain = self.get_chain_identifier().await?.chain();
+                let version: ProtocolVersion = if let Some(version) = version {
+                    (*version).into()
+                } else {
+                    let latest_epoch = self
+                        .inner
+                        .spawn_blocking(|this| this.get_latest_epoch_info_from_db())
+                        .await?;
+                    (latest_epoch.protocol_version as u64).into()
+                };
+                RpcResult::Ok((chain, version))
+            })
+            .await?;
 
         ProtocolConfig::get_for_version_if_supported(version, chain)
             .ok_or(SuiRpcInputError::ProtocolVersionUnsupported(
@@ -319,8 +696,16 @@ impl ReadApiServer for ReadApiV2 {
             .map(ProtocolConfigResponse::from)
     }
 
+    #[instrument(skip_all, fields(db_ms = Empty))]
     async fn get_chain_identifier(&self) -> RpcResult<String> {
-        self.get_chain_identifier().await.map(|id| id.to_string())
+        let _timer = self.start_timer("get_chain_identifier");
+        self.timed(
+            "get_chain_identifier",
+            Stage::Db,
+            self.get_chain_identifier(),
+        )
+        .await
+        .map(|id| id.to_string())
     }
 }
 
@@ -333,3 +718,64 @@ impl SuiRpcModule for ReadApiV2 {
         sui_json_rpc::api::ReadApiOpenRpc::module_doc()
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use sui_json_rpc_types::SuiData;
+    use sui_types::crypto::AggregateAuthoritySignature;
+    use sui_types::messages_checkpoint::CheckpointCommitment;
+
+    use crate::models_v2::checkpoints::StoredCheckpoint;
+
+    use super::*;
+
+    fn checkpoint(sequence_number: u64, tx_count: u8) -> Checkpoint {
+        let stored = StoredCheckpoint {
+            sequence_number: sequence_number as i64,
+            checkpoint_digest: vec![0; 32],
+            tx_digests: (0..tx_count).map(|i| Some(vec![i; 32])).collect(),
+            checkpoint_commitments: bcs::to_bytes(&Vec::<CheckpointCommitment>::new()).unwrap(),
+            validator_signature: bcs::to_bytes(&AggregateAuthoritySignature::default()).unwrap(),
+            ..Default::default()
+        };
+        Checkpoint::try_from(stored).unwrap()
+    }
+
+    #[test]
+    fn test_checkpoint_tx_digests_match_contents() {
+        let checkpoint = checkpoint(3, 2);
+        assert_eq!(
+            checkpoint.transactions,
+            vec![
+                TransactionDigest::new([0; 32]),
+                TransactionDigest::new([1; 32])
+            ]
+        );
+    }
+
+    #[test]
+    fn test_object_data_falls_back_to_bcs_without_layout() {
+        let object = Object::new_gas_for_testing();
+        let object_ref = object.compute_object_reference();
+        let options = SuiObjectDataOptions::new().with_content().with_owner();
+
+        let (data, error) = object_data(object_ref, object.clone(), None, options, None).unwrap();
+        assert_eq!(data.object_ref(), object_ref);
+        assert_eq!(data.owner, Some(object.owner));
+        assert!(data.content.is_none());
+        assert_eq!(
+            data.bcs.unwrap().try_as_move().unwrap().bcs_bytes,
+            object.data.try_as_move().unwrap().contents()
+        );
+        assert!(matches!(
+            error,
+            Some(SuiObjectResponseError::ContentsDecodeError { .. })
+        ));
+
+        // Without contents requested, there is nothing to decode.
+        let options = SuiObjectDataOptions::new().with_owner();
+        let (data, error) = object_data(object_ref, object, None, options, None).unwrap();
+        assert!(data.bcs.is_none());
+        assert!(error.is_none());
+    }
+}
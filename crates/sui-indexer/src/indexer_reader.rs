@@ -28,23 +28,27 @@ use anyhow::{anyhow, Result};
 use cached::proc_macro::cached;
 use cached::SizedCache;
 use diesel::{
-    r2d2::ConnectionManager, ExpressionMethods, OptionalExtension, PgConnection, QueryDsl,
-    RunQueryDsl,
+    dsl::{max, min, sql},
+    r2d2::ConnectionManager,
+    sql_types::Integer,
+    ExpressionMethods, OptionalExtension, PgConnection, QueryDsl, RunQueryDsl,
 };
 use fastcrypto::encoding::Encoding;
 use fastcrypto::encoding::Hex;
 use itertools::{any, Itertools};
 use move_core_types::language_storage::StructTag;
+use move_core_types::value::MoveStructLayout;
 use std::{
-    collections::{BTreeMap, HashMap},
+    collections::{BTreeMap, BTreeSet, HashMap},
     sync::{Arc, RwLock},
 };
+use sui_json_rpc::read_api::{get_object_type_and_struct, get_rendered_fields};
 use sui_json_rpc_types::{
     AddressMetrics, CheckpointId, EpochInfo, EventFilter, MoveCallMetrics, MoveFunctionName,
     NetworkMetrics, SuiEvent, SuiObjectDataFilter, SuiTransactionBlockResponse, TransactionFilter,
 };
 use sui_json_rpc_types::{
-    Balance, Coin as SuiCoin, SuiCoinMetadata, SuiTransactionBlockEffects,
+    Balance, Coin as SuiCoin, DisplayFieldsResponse, SuiCoinMetadata, SuiTransactionBlockEffects,
     SuiTransactionBlockEffectsAPI,
 };
 use sui_types::{balance::Supply, coin::TreasuryCap, dynamic_field::DynamicFieldName};
@@ -53,6 +57,7 @@ use sui_types::{
     committee::EpochId,
     digests::{ObjectDigest, TransactionDigest},
     dynamic_field::DynamicFieldInfo,
+    error::SuiObjectResponseError,
     is_system_package,
     move_package::MovePackage,
     object::{Object, ObjectRead},
@@ -64,10 +69,26 @@ pub const TX_SEQUENCE_NUMBER_STR: &str = "tx_sequence_number";
 pub const TRANSACTION_DIGEST_STR: &str = "transaction_digest";
 pub const EVENT_SEQUENCE_NUMBER_STR: &str = "event_sequence_number";
 
+/// Result of reading an object with an optional type filter.
+#[derive(Debug)]
+pub enum TypedObjectRead {
+    /// The object matched the requested type, or no type was requested.
+    Read(ObjectRead),
+    /// The object exists but is of a different type. `actual` is `None` for packages.
+    TypeMismatch {
+        object_id: ObjectID,
+        expected: String,
+        actual: Option<String>,
+    },
+}
+
 #[derive(Clone)]
 pub struct IndexerReader {
     pool: crate::PgConnectionPool,
     package_cache: PackageCache,
+    /// Threads reserved for blocking DB operations, so they do not compete with other blocking
+    /// work on Tokio's shared pool. `None` runs them on the shared pool.
+    blocking_pool: Option<Arc<rayon::ThreadPool>>,
 }
 
 // Impl for common initialization and utilities
@@ -95,9 +116,16 @@ impl IndexerReader {
             .build(manager)
             .map_err(|e| anyhow!("Failed to initialize connection pool. Error: {:?}. If Error is None, please check whether the configured pool size (currently {}) exceeds the maximum number of connections allowed by the database.", e, config.pool_size))?;
 
+        let blocking_pool = config
+            .blocking_pool_size()
+            .map(build_blocking_pool)
+            .transpose()?
+            .map(Arc::new);
+
         Ok(Self {
             pool,
             package_cache: Default::default(),
+            blocking_pool,
         })
     }
 
@@ -133,14 +161,11 @@ impl IndexerReader {
     {
         let this = self.clone();
         let current_span = tracing::Span::current();
-        tokio::task::spawn_blocking(move || {
-            CALLED_FROM_BLOCKING_POOL
-                .with(|in_blocking_pool| *in_blocking_pool.borrow_mut() = true);
+        spawn_blocking_on(self.blocking_pool.as_deref(), move || {
             let _guard = current_span.enter();
             f(this)
         })
         .await
-        .expect("propagate any panics")
     }
 
     pub async fn run_query_async<T, E, F>(&self, query: F) -> Result<T, IndexerError>
@@ -157,6 +182,69 @@ thread_local! {
     static CALLED_FROM_BLOCKING_POOL: std::cell::RefCell<bool> = std::cell::RefCell::new(false);
 }
 
+const BLOCKING_POOL_THREAD_NAME: &str = "indexer-reader-db";
+
+fn build_blocking_pool(size: usize) -> Result<rayon::ThreadPool> {
+    rayon::ThreadPoolBuilder::new()
+        .num_threads(size)
+        .thread_name(|i| format!("{BLOCKING_POOL_THREAD_NAME}-{i}"))
+        .build()
+        .map_err(|e| anyhow!("Failed to build blocking thread pool of size {size}: {e}"))
+}
+
+/// Run `f` on a thread where blocking DB operations are allowed: one from `pool` if given, or
+/// from Tokio's shared blocking pool otherwise. Panics in `f` are propagated to the caller.
+async fn spawn_blocking_on<F, R>(pool: Option<&rayon::ThreadPool>, f: F) -> R
+where
+    F: FnOnce() -> R + Send + 'static,
+    R: Send + 'static,
+{
+    let f = move || {
+        CALLED_FROM_BLOCKING_POOL.with(|in_blocking_pool| *in_blocking_pool.borrow_mut() = true);
+        f()
+    };
+    let Some(pool) = pool else {
+        return tokio::task::spawn_blocking(f)
+            .await
+            .expect("propagate any panics");
+    };
+    let (tx, rx) = tokio::sync::oneshot::channel();
+    pool.spawn(move || {
+        // Rayon aborts the process if a spawned task panics, so catch it and hand it back.
+        let _ = tx.send(std::panic::catch_unwind(std::panic::AssertUnwindSafe(f)));
+    });
+    match rx.await.expect("blocking pool dropped the task") {
+        Ok(result) => result,
+        Err(panic) => std::panic::resume_unwind(panic),
+    }
+}
+
+/// How many of a page of checkpoints, with `tx_counts` transactions each, to return so that they
+/// hold at most `max` transaction digests between them. At least one is kept, so that paginating
+/// through them makes progress.
+fn checkpoints_within_tx_digests(tx_counts: impl Iterator<Item = usize>, max: usize) -> usize {
+    let mut total = 0;
+    let mut keep = 0;
+    for count in tx_counts {
+        total += count;
+        if total > max && keep > 0 {
+            break;
+        }
+        keep += 1;
+    }
+    keep
+}
+
+/// Whether the `object_type` column of a stored object refers to exactly `expected`.
+fn object_type_matches(stored_type: Option<&str>, expected: &StructTag) -> bool {
+    stored_type
+        == Some(
+            expected
+                .to_canonical_string(/* with_prefix */ true)
+                .as_str(),
+        )
+}
+
 /// Check that we are in a context conducive to making blocking calls.
 /// This is done by either:
 /// - Checking that we are not inside a tokio runtime context
@@ -232,7 +320,28 @@ impl IndexerReader {
             .await
     }
 
+    /// Like `get_object_read_in_blocking_task`, but skips resolving the object's Move layout
+    /// unless `resolve_layout` is set, for callers that only need the raw object.
+    pub async fn get_object_read_with_layout_in_blocking_task(
+        &self,
+        object_id: ObjectID,
+        resolve_layout: bool,
+    ) -> Result<ObjectRead, IndexerError> {
+        self.spawn_blocking(move |this| {
+            this.get_object_read_with_layout(&object_id, resolve_layout)
+        })
+        .await
+    }
+
     fn get_object_read(&self, object_id: &ObjectID) -> Result<ObjectRead, IndexerError> {
+        self.get_object_read_with_layout(object_id, /* resolve_layout */ true)
+    }
+
+    fn get_object_read_with_layout(
+        &self,
+        object_id: &ObjectID,
+        resolve_layout: bool,
+    ) -> Result<ObjectRead, IndexerError> {
         let id = object_id.to_vec();
 
         let stored_object = self.run_query(|conn| {
@@ -243,12 +352,56 @@ impl IndexerReader {
         })?;
 
         if let Some(object) = stored_object {
-            object.try_into_object_read(self)
+            object.try_into_object_read_with_layout(self, resolve_layout)
         } else {
             Ok(ObjectRead::NotExists(*object_id))
         }
     }
 
+    pub async fn get_object_read_with_type_in_blocking_task(
+        &self,
+        object_id: ObjectID,
+        object_type: Option<StructTag>,
+    ) -> Result<TypedObjectRead, IndexerError> {
+        self.spawn_blocking(move |this| {
+            this.get_object_read_with_type(&object_id, object_type.as_ref())
+        })
+        .await
+    }
+
+    /// Like `get_object_read`, but when `object_type` is set, the object is only loaded if it is of
+    /// exactly that type. Otherwise a `TypeMismatch` is returned without fetching its contents.
+    fn get_object_read_with_type(
+        &self,
+        object_id: &ObjectID,
+        object_type: Option<&StructTag>,
+    ) -> Result<TypedObjectRead, IndexerError> {
+        let Some(expected) = object_type else {
+            return Ok(TypedObjectRead::Read(self.get_object_read(object_id)?));
+        };
+
+        let id = object_id.to_vec();
+        let stored_type = self.run_query(|conn| {
+            objects::dsl::objects
+                .filter(objects::dsl::object_id.eq(id))
+                .select(objects::dsl::object_type)
+                .first::<Option<String>>(conn)
+                .optional()
+        })?;
+
+        match stored_type {
+            None => Ok(TypedObjectRead::Read(ObjectRead::NotExists(*object_id))),
+            Some(actual) if !object_type_matches(actual.as_deref(), expected) => {
+                Ok(TypedObjectRead::TypeMismatch {
+                    object_id: *object_id,
+                    expected: expected.to_canonical_string(/* with_prefix */ true),
+                    actual,
+                })
+            }
+            Some(_) => Ok(TypedObjectRead::Read(self.get_object_read(object_id)?)),
+        }
+    }
+
     fn get_package_from_db(
         &self,
         package_id: &ObjectID,
@@ -419,6 +572,26 @@ impl IndexerReader {
         Ok(stored_checkpoint)
     }
 
+    /// The `(lowest_available, highest)` checkpoint sequence numbers stored in the DB. Anything
+    /// below the lowest available checkpoint has been pruned.
+    pub fn get_available_checkpoint_range(&self) -> Result<(u64, u64), IndexerError> {
+        let (lowest, highest) = self.run_query(|conn| {
+            checkpoints::dsl::checkpoints
+                .select((
+                    min(checkpoints::sequence_number),
+                    max(checkpoints::sequence_number),
+                ))
+                .first::<(Option<i64>, Option<i64>)>(conn)
+        })?;
+
+        match (lowest, highest) {
+            (Some(lowest), Some(highest)) => Ok((lowest as u64, highest as u64)),
+            _ => Err(IndexerError::PostgresReadError(
+                "No checkpoints available".to_string(),
+            )),
+        }
+    }
+
     pub fn get_checkpoint(
         &self,
         checkpoint_id: CheckpointId,
@@ -479,6 +652,70 @@ impl IndexerReader {
             .collect()
     }
 
+    /// Like `get_checkpoints`, but the page also ends early, before the checkpoint that would
+    /// take the transaction digests it holds over `max_tx_digests`. It always holds at least one
+    /// checkpoint, with all its transaction digests. Returns whether the page ended early.
+    ///
+    /// Only the digest counts of the candidate checkpoints are read to find where the page ends,
+    /// so the contents of checkpoints that are left out are never loaded.
+    pub fn get_checkpoints_within_tx_digests(
+        &self,
+        cursor: Option<u64>,
+        limit: usize,
+        descending_order: bool,
+        max_tx_digests: usize,
+    ) -> Result<(Vec<sui_json_rpc_types::Checkpoint>, bool), IndexerError> {
+        let tx_counts = self.run_query(|conn| {
+            let mut boxed_query = checkpoints::table
+                .select((
+                    checkpoints::sequence_number,
+                    sql::<Integer>("cardinality(tx_digests)"),
+                ))
+                .into_boxed();
+            if let Some(cursor) = cursor {
+                if descending_order {
+                    boxed_query =
+                        boxed_query.filter(checkpoints::sequence_number.lt(cursor as i64));
+                } else {
+                    boxed_query =
+                        boxed_query.filter(checkpoints::sequence_number.gt(cursor as i64));
+                }
+            }
+            if descending_order {
+                boxed_query = boxed_query.order_by(checkpoints::sequence_number.desc());
+            } else {
+                boxed_query = boxed_query.order_by(checkpoints::sequence_number.asc());
+            }
+
+            boxed_query.limit(limit as i64).load::<(i64, i32)>(conn)
+        })?;
+
+        let keep = checkpoints_within_tx_digests(
+            tx_counts.iter().map(|(_, count)| *count as usize),
+            max_tx_digests,
+        );
+        let sequence_numbers: Vec<_> = tx_counts[..keep].iter().map(|(seq, _)| *seq).collect();
+        let stored_checkpoints = self.run_query(|conn| {
+            let query =
+                checkpoints::table.filter(checkpoints::sequence_number.eq_any(sequence_numbers));
+            if descending_order {
+                query
+                    .order_by(checkpoints::sequence_number.desc())
+                    .load::<StoredCheckpoint>(conn)
+            } else {
+                query
+                    .order_by(checkpoints::sequence_number.asc())
+                    .load::<StoredCheckpoint>(conn)
+            }
+        })?;
+
+        let checkpoints = stored_checkpoints
+            .into_iter()
+            .map(sui_json_rpc_types::Checkpoint::try_from)
+            .collect::<Result<_, _>>()?;
+        Ok((checkpoints, keep < tx_counts.len()))
+    }
+
     fn get_transaction_effects_with_digest(
         &self,
         digest: TransactionDigest,
@@ -505,19 +742,22 @@ impl IndexerReader {
         stored_txn.try_into_sui_transaction_effects()
     }
 
+    /// Transactions with the given `digests`, in the same order as `digests`, including any
+    /// duplicates. Fails if any of the transactions is not found.
     fn multi_get_transactions(
         &self,
         digests: &[TransactionDigest],
     ) -> Result<Vec<StoredTransaction>, IndexerError> {
-        let digests = digests
+        let digest_bytes = digests
             .iter()
             .map(|digest| digest.inner().to_vec())
             .collect::<Vec<_>>();
-        self.run_query(|conn| {
+        let stored_txes = self.run_query(|conn| {
             transactions::table
-                .filter(transactions::transaction_digest.eq_any(digests))
+                .filter(transactions::transaction_digest.eq_any(digest_bytes))
                 .load::<StoredTransaction>(conn)
-        })
+        })?;
+        order_transactions_by_digests(digests, stored_txes)
     }
 
     fn stored_transaction_to_transaction_block(
@@ -1340,6 +1580,125 @@ impl IndexerReader {
         Ok(Some(display_update))
     }
 
+    /// Render the Display for each of `objects`, fetching the Display for each distinct object type
+    /// only once, in a single query. The responses are in the same order as `objects`. An object
+    /// whose contents cannot be rendered gets a response carrying the error, rather than failing
+    /// the whole batch.
+    pub async fn batch_resolve_display(
+        &self,
+        objects: Vec<(Object, Option<MoveStructLayout>)>,
+    ) -> Result<Vec<DisplayFieldsResponse>, IndexerError> {
+        let resolved: Vec<_> = objects
+            .iter()
+            .map(|(object, layout)| get_object_type_and_struct(object, layout))
+            .collect();
+        let object_types = distinct_object_types(
+            resolved
+                .iter()
+                .filter_map(|r| r.as_ref().ok().and_then(Option::as_ref))
+                .map(|(object_type, _)| object_type),
+        );
+        let displays = self
+            .spawn_blocking(move |this| this.get_display_update_events(object_types))
+            .await?;
+
+        let display_error = |error: String| DisplayFieldsResponse {
+            data: None,
+            error: Some(SuiObjectResponseError::DisplayError { error }),
+        };
+        Ok(resolved
+            .into_iter()
+            .map(|resolved| match resolved {
+                Err(e) => display_error(e.to_string()),
+                Ok(Some((object_type, move_struct))) => {
+                    match displays.get(&object_type.to_canonical_string(/* with_prefix */ true)) {
+                        Some(display) => get_rendered_fields(display.fields.clone(), &move_struct)
+                            .unwrap_or_else(|e| display_error(e.to_string())),
+                        None => DisplayFieldsResponse {
+                            data: None,
+                            error: None,
+                        },
+                    }
+                }
+                Ok(None) => DisplayFieldsResponse {
+                    data: None,
+                    error: None,
+                },
+            })
+            .collect())
+    }
+
+    fn get_display_update_events(
+        &self,
+        object_types: Vec<String>,
+    ) -> Result<HashMap<String, sui_types::display::DisplayVersionUpdatedEvent>, IndexerError> {
+        let stored_displays = self.run_query(|conn| {
+            display::table
+                .filter(display::object_type.eq_any(object_types))
+                .load::<StoredDisplay>(conn)
+        })?;
+
+        stored_displays
+            .into_iter()
+            .map(|stored| {
+                let display_update = stored.to_display_update_event()?;
+                Ok((stored.object_type, display_update))
+            })
+            .collect()
+    }
+
+    /// Store `object` as of checkpoint 1, as if it had been indexed. This lets object reads be
+    /// tested without the ingestion pipeline that normally fills the table.
+    #[cfg(any(test, feature = "pg_integration"))]
+    pub async fn insert_object_for_testing(&self, object: Object) -> Result<(), IndexerError> {
+        let stored_object =
+            StoredObject::from(crate::types_v2::IndexedObject::from_object(1, object, None));
+        self.spawn_blocking(move |this| {
+            let mut connection = this.get_connection()?;
+            diesel::insert_into(objects::table)
+                .values(&stored_object)
+                .execute(&mut connection)
+                .map_err(|e| IndexerError::PostgresWriteError(e.to_string()))?;
+            Ok(())
+        })
+        .await
+    }
+
+    /// Store `display` as the Display for `object_type`, replacing any existing one. This lets the
+    /// display rendering path be tested without the ingestion pipeline that normally fills the
+    /// table.
+    #[cfg(any(test, feature = "pg_integration"))]
+    pub async fn insert_display_for_testing(
+        &self,
+        object_type: &StructTag,
+        display: sui_types::display::DisplayVersionUpdatedEvent,
+    ) -> Result<(), IndexerError> {
+        use diesel::upsert::excluded;
+
+        let stored_display = StoredDisplay {
+            object_type: object_type.to_canonical_string(/* with_prefix */ true),
+            id: display.id.bytes.to_vec(),
+            version: display.version as i16,
+            bcs: bcs::to_bytes(&display)?,
+        };
+        self.spawn_blocking(move |this| {
+            let mut connection = this.get_connection()?;
+            diesel::insert_into(display::table)
+                .values(&stored_display)
+                .on_conflict(display::object_type)
+                .do_update()
+                .set((
+                    display::id.eq(excluded(display::id)),
+                    display::version.eq(excluded(display::version)),
+                    display::bcs.eq(excluded(display::bcs)),
+                ))
+                .execute(&mut connection)
+                .map_err(|e| IndexerError::PostgresWriteError(e.to_string()))?;
+            Ok(())
+        })
+        .await
+    }
+
     pub async fn get_owned_coins_in_blocking_task(
         &self,
         owner: SuiAddress,
@@ -1727,3 +2086,153 @@ fn get_single_obj_id_from_package_publish(
         Ok(None)
     }
 }
+
+/// Arrange `stored_txes` to correspond positionally with `digests`, repeating a transaction for
+/// every time its digest is repeated.
+fn order_transactions_by_digests(
+    digests: &[TransactionDigest],
+    stored_txes: Vec<StoredTransaction>,
+) -> Result<Vec<StoredTransaction>, IndexerError> {
+    let by_digest = stored_txes
+        .into_iter()
+        .map(|tx| (tx.transaction_digest.clone(), tx))
+        .collect::<HashMap<_, _>>();
+    digests
+        .iter()
+        .map(|digest| {
+            by_digest
+                .get(digest.inner().as_slice())
+                .cloned()
+                .ok_or_else(|| {
+                    IndexerError::InvalidArgumentError(format!("Transaction {digest} not found"))
+                })
+        })
+        .collect()
+}
+
+/// The canonical forms of the distinct types among `object_types`, which are the Displays to fetch
+/// to render objects of those types.
+fn distinct_object_types<'a>(object_types: impl IntoIterator<Item = &'a StructTag>) -> Vec<String> {
+    object_types
+        .into_iter()
+        .map(|object_type| object_type.to_canonical_string(/* with_prefix */ true))
+        .collect::<BTreeSet<_>>()
+        .into_iter()
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types_v2::IndexedObject;
+    use move_core_types::language_storage::TypeTag;
+    use sui_types::coin::Coin;
+    use sui_types::gas_coin::GasCoin;
+
+    #[tokio::test]
+    async fn test_dedicated_blocking_pool() {
+        let pool = build_blocking_pool(2).unwrap();
+        let thread_name = spawn_blocking_on(Some(&pool), || {
+            // Would panic if DB operations were not allowed here.
+            blocking_call_is_ok_or_panic();
+            std::thread::current().name().map(str::to_owned)
+        })
+        .await
+        .unwrap();
+        assert!(thread_name.starts_with(BLOCKING_POOL_THREAD_NAME));
+
+        let thread_name = spawn_blocking_on(None, || {
+            blocking_call_is_ok_or_panic();
+            std::thread::current().name().map(str::to_owned)
+        })
+        .await;
+        assert!(!thread_name.is_some_and(|name| name.starts_with(BLOCKING_POOL_THREAD_NAME)));
+    }
+
+    #[tokio::test]
+    #[should_panic(expected = "boom")]
+    async fn test_dedicated_blocking_pool_propagates_panics() {
+        let pool = build_blocking_pool(1).unwrap();
+        spawn_blocking_on(Some(&pool), || panic!("boom")).await
+    }
+
+    #[test]
+    fn test_checkpoints_within_tx_digests() {
+        let page = || [2, 3, 1].into_iter();
+
+        // Everything fits.
+        assert_eq!(checkpoints_within_tx_digests(page(), 6), 3);
+        // The page ends before the checkpoint that would exceed the bound.
+        assert_eq!(checkpoints_within_tx_digests(page(), 5), 2);
+        // A checkpoint with more digests than the bound is still returned on its own.
+        assert_eq!(checkpoints_within_tx_digests(page(), 1), 1);
+        assert_eq!(checkpoints_within_tx_digests(std::iter::empty(), 1), 0);
+    }
+
+    #[test]
+    fn test_object_type_filter() {
+        let stored = StoredObject::from(IndexedObject::from_object(
+            1,
+            Object::new_gas_for_testing(),
+            None,
+        ));
+        let stored_type = stored.object_type.as_deref();
+
+        assert!(object_type_matches(stored_type, &GasCoin::type_()));
+        assert!(!object_type_matches(
+            stored_type,
+            &Coin::type_(TypeTag::Bool)
+        ));
+        // Packages have no object type, so never match a type filter.
+        assert!(!object_type_matches(None, &GasCoin::type_()));
+    }
+
+    fn stored_transaction(tx_sequence_number: i64, digest: TransactionDigest) -> StoredTransaction {
+        StoredTransaction {
+            tx_sequence_number,
+            transaction_digest: digest.inner().to_vec(),
+            raw_transaction: vec![],
+            raw_effects: vec![],
+            checkpoint_sequence_number: 0,
+            timestamp_ms: 0,
+            object_changes: vec![],
+            balance_changes: vec![],
+            events: vec![],
+            transaction_kind: 0,
+            success_command_count: 0,
+        }
+    }
+
+    #[test]
+    fn test_order_transactions_by_digests_keeps_duplicates() {
+        let first = TransactionDigest::random();
+        let second = TransactionDigest::random();
+        // The database returns each matching row once, in no particular order.
+        let stored = vec![stored_transaction(1, second), stored_transaction(0, first)];
+
+        let ordered = order_transactions_by_digests(&[first, second, first], stored).unwrap();
+        let sequence_numbers = ordered
+            .iter()
+            .map(|tx| tx.tx_sequence_number)
+            .collect::<Vec<_>>();
+        assert_eq!(sequence_numbers, vec![0, 1, 0]);
+        assert_eq!(ordered[0].transaction_digest, ordered[2].transaction_digest);
+    }
+
+    #[test]
+    fn test_order_transactions_by_digests_missing() {
+        let stored = vec![stored_transaction(0, TransactionDigest::random())];
+        assert!(order_transactions_by_digests(&[TransactionDigest::random()], stored).is_err());
+    }
+
+    #[test]
+    fn test_distinct_object_types() {
+        let clock = sui_types::clock::Clock::type_();
+        let object_types = [GasCoin::type_(), clock.clone(), GasCoin::type_()];
+        let distinct = distinct_object_types(&object_types);
+        // Rendering three objects of two types only needs two Displays
+        assert_eq!(distinct.len(), 2);
+        assert!(distinct.contains(&GasCoin::type_().to_canonical_string(true)));
+        assert!(distinct.contains(&clock.to_canonical_string(true)));
+    }
+}
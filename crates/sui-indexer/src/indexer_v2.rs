@@ -7,7 +7,7 @@ use crate::apis::{
 };
 use crate::errors::IndexerError;
 use crate::indexer_reader::IndexerReader;
-use crate::metrics::IndexerMetrics;
+use crate::metrics::{IndexerMetrics, ReadApiV2Metrics};
 use crate::IndexerConfig;
 use anyhow::Result;
 use mysten_metrics::spawn_monitored_task;
@@ -122,7 +122,15 @@ pub async fn build_json_rpc_server(
     builder.register_module(TransactionBuilderApiV2::new(reader.clone()))?;
     builder.register_module(MoveUtilsApiV2::new(reader.clone()))?;
     builder.register_module(GovernanceReadApiV2::new(reader.clone()))?;
-    builder.register_module(ReadApiV2::new(reader.clone()))?;
+    let mut read_api =
+        ReadApiV2::new(reader.clone()).with_metrics(ReadApiV2Metrics::new(prometheus_registry));
+    if let Some(max) = config.max_checkpoint_tx_digests {
+        read_api = read_api.with_max_checkpoint_tx_digests(max);
+    }
+    if config.disable_display {
+        read_api = read_api.with_display_disabled();
+    }
+    builder.register_module(read_api)?;
     builder.register_module(CoinReadApiV2::new(reader.clone()))?;
     builder.register_module(ExtendedApiV2::new(reader.clone()))?;
 
@@ -118,6 +118,12 @@ pub struct IndexerConfig {
     pub skip_db_commit: bool,
     #[clap(long)]
     pub use_v2: bool,
+    /// The most transaction digests a page of checkpoints returned by the v2 read API can hold.
+    #[clap(long)]
+    pub max_checkpoint_tx_digests: Option<usize>,
+    /// Never render Displays in the v2 read API, even when requested, to save the work.
+    #[clap(long)]
+    pub disable_display: bool,
 }
 
 impl IndexerConfig {
@@ -174,6 +180,8 @@ impl Default for IndexerConfig {
             analytical_worker: false,
             skip_db_commit: false,
             use_v2: false,
+            max_checkpoint_tx_digests: None,
+            disable_display: false,
         }
     }
 }
@@ -295,6 +303,9 @@ pub struct PgConnectionPoolConfig {
     pool_size: u32,
     connection_timeout: Duration,
     statement_timeout: Duration,
+    /// Number of threads dedicated to running blocking reads. Reads share Tokio's blocking
+    /// thread pool if this is not set.
+    blocking_pool_size: Option<usize>,
 }
 
 impl PgConnectionPoolConfig {
@@ -320,6 +331,14 @@ impl PgConnectionPoolConfig {
     pub fn set_statement_timeout(&mut self, timeout: Duration) {
         self.statement_timeout = timeout;
     }
+
+    pub fn set_blocking_pool_size(&mut self, size: usize) {
+        self.blocking_pool_size = Some(size);
+    }
+
+    pub fn blocking_pool_size(&self) -> Option<usize> {
+        self.blocking_pool_size
+    }
 }
 
 impl Default for PgConnectionPoolConfig {
@@ -336,11 +355,15 @@ impl Default for PgConnectionPoolConfig {
             .ok()
             .and_then(|s| s.parse::<u64>().ok())
             .unwrap_or(Self::DEFAULT_STATEMENT_TIMEOUT);
+        let blocking_pool_size = std::env::var("DB_BLOCKING_POOL_SIZE")
+            .ok()
+            .and_then(|s| s.parse::<usize>().ok());
 
         Self {
             pool_size: db_pool_size,
             connection_timeout: Duration::from_secs(conn_timeout_secs),
             statement_timeout: Duration::from_secs(statement_timeout_secs),
+            blocking_pool_size,
         }
     }
 }
@@ -2,8 +2,9 @@
 // SPDX-License-Identifier: Apache-2.0
 
 use prometheus::{
-    register_histogram_with_registry, register_int_counter_with_registry,
-    register_int_gauge_with_registry, Histogram, IntCounter, IntGauge, Registry,
+    register_histogram_vec_with_registry, register_histogram_with_registry,
+    register_int_counter_with_registry, register_int_gauge_with_registry, Histogram, HistogramVec,
+    IntCounter, IntGauge, Registry,
 };
 
 /// Prometheus metrics for sui-indexer.
@@ -582,3 +583,33 @@ impl IndexerObjectProcessorMetrics {
         }
     }
 }
+
+/// Latencies of the RPC methods served from the indexer's own tables by `ReadApiV2`.
+#[derive(Clone, Debug)]
+pub struct ReadApiV2Metrics {
+    pub request_latency: HistogramVec,
+    pub stage_latency: HistogramVec,
+}
+
+impl ReadApiV2Metrics {
+    pub fn new(registry: &Registry) -> Self {
+        Self {
+            request_latency: register_histogram_vec_with_registry!(
+                "read_api_v2_request_latency",
+                "Time spent serving a ReadApiV2 method, by method.",
+                &["method"],
+                LATENCY_SEC_BUCKETS.to_vec(),
+                registry
+            )
+            .unwrap(),
+            stage_latency: register_histogram_vec_with_registry!(
+                "read_api_v2_stage_latency",
+                "Time spent reading from the DB or rendering Displays in a ReadApiV2 method, by method and stage.",
+                &["method", "stage"],
+                LATENCY_SEC_BUCKETS.to_vec(),
+                registry
+            )
+            .unwrap(),
+        }
+    }
+}
@@ -12,6 +12,7 @@ use sui_types::digests::ObjectDigest;
 use sui_types::dynamic_field::{DynamicFieldInfo, DynamicFieldName, DynamicFieldType, Field};
 use sui_types::object::Object;
 use sui_types::object::{ObjectFormatOptions, ObjectRead};
+use tracing::warn;
 
 use crate::errors::IndexerError;
 use crate::schema_v2::objects;
@@ -109,10 +110,30 @@ impl StoredObject {
     pub fn try_into_object_read(
         self,
         module_cache: &impl GetModule,
+    ) -> Result<ObjectRead, IndexerError> {
+        self.try_into_object_read_with_layout(module_cache, /* resolve_layout */ true)
+    }
+
+    /// Like `try_into_object_read`, but the Move layout is only resolved if `resolve_layout` is
+    /// set, otherwise the returned `ObjectRead` carries no layout. An object whose layout cannot
+    /// be resolved, e.g. because a package it depends on is missing, is still read, without one.
+    pub fn try_into_object_read_with_layout(
+        self,
+        module_cache: &impl GetModule,
+        resolve_layout: bool,
     ) -> Result<ObjectRead, IndexerError> {
         let oref = self.get_object_ref()?;
         let object: sui_types::object::Object = self.try_into()?;
-        let layout = object.get_layout(ObjectFormatOptions::default(), module_cache)?;
+        let layout = if resolve_layout {
+            object
+                .get_layout(ObjectFormatOptions::default(), module_cache)
+                .unwrap_or_else(|e| {
+                    warn!(object_id = %oref.0, "Failed to resolve object layout: {e}");
+                    None
+                })
+        } else {
+            None
+        };
         Ok(ObjectRead::Exists(oref, object, layout))
     }
 
@@ -299,7 +320,12 @@ impl From<CoinBalance> for Balance {
 
 #[cfg(test)]
 mod tests {
-    use move_core_types::{account_address::AccountAddress, language_storage::StructTag};
+    use move_binary_format::CompiledModule;
+    use move_core_types::{
+        account_address::AccountAddress,
+        language_storage::{ModuleId, StructTag},
+    };
+    use sui_json_rpc_types::{SuiData, SuiObjectData, SuiObjectDataOptions};
     use sui_types::{
         coin::Coin,
         digests::TransactionDigest,
@@ -310,6 +336,51 @@ mod tests {
 
     use super::*;
 
+    /// Module cache that counts lookups without resolving anything.
+    #[derive(Default)]
+    struct CountingModuleCache(std::cell::Cell<usize>);
+
+    impl GetModule for CountingModuleCache {
+        type Error = IndexerError;
+        type Item = CompiledModule;
+
+        fn get_module_by_id(&self, _id: &ModuleId) -> Result<Option<CompiledModule>, IndexerError> {
+            self.0.set(self.0.get() + 1);
+            Ok(None)
+        }
+    }
+
+    #[test]
+    fn test_object_read_without_layout_skips_module_lookups() {
+        let object = Object::new_gas_for_testing();
+        let stored_obj = StoredObject::from(IndexedObject::from_object(1, object.clone(), None));
+        let module_cache = CountingModuleCache::default();
+        let options = SuiObjectDataOptions::bcs_lossless();
+
+        let object_read = stored_obj
+            .clone()
+            .try_into_object_read_with_layout(&module_cache, options.requires_layout())
+            .unwrap();
+        assert_eq!(module_cache.0.get(), 0);
+
+        let ObjectRead::Exists(oref, read_object, layout) = object_read else {
+            panic!("object should exist");
+        };
+        assert!(layout.is_none());
+        let data = SuiObjectData::try_from((oref, read_object, layout, options)).unwrap();
+        let bcs = data.bcs.unwrap();
+        assert_eq!(
+            bcs.try_as_move().unwrap().bcs_bytes,
+            object.data.try_as_move().unwrap().contents()
+        );
+
+        // Resolving the layout goes through the module cache, and an object whose modules are
+        // missing is still read, without a layout.
+        let object_read = stored_obj.try_into_object_read(&module_cache).unwrap();
+        assert!(module_cache.0.get() > 0);
+        assert!(matches!(object_read, ObjectRead::Exists(_, _, None)));
+    }
+
     #[test]
     fn test_canonical_string_of_object_type_for_coin() {
         let test_obj = Object::new_gas_for_testing();
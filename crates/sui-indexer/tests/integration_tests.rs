@@ -4,45 +4,61 @@
 // integration test with standalone postgresql database
 #[cfg(feature = "pg_integration")]
 pub mod pg_integration_test {
-    use diesel::RunQueryDsl;
+    use diesel::{ExpressionMethods, QueryDsl, RunQueryDsl};
     use futures::future::join_all;
     use jsonrpsee::http_client::{HttpClient, HttpClientBuilder};
     use move_core_types::ident_str;
     use move_core_types::identifier::Identifier;
-    use move_core_types::language_storage::StructTag;
+    use move_core_types::language_storage::{StructTag, TypeTag};
     use move_core_types::parser::parse_struct_tag;
+    use move_core_types::value::{MoveStructLayout, MoveTypeLayout};
     use ntest::timeout;
+    use std::collections::BTreeMap;
     use std::env;
     use std::str::FromStr;
+    use std::sync::{Arc, Mutex};
     use sui_test_transaction_builder::{
         create_devnet_nft, delete_devnet_nft, publish_nfts_package,
     };
     use tokio::task::JoinHandle;
+    use tracing_subscriber::layer::SubscriberExt;
 
+    use sui_indexer::apis::ReadApiV2;
     use sui_indexer::errors::IndexerError;
+    use sui_indexer::indexer_reader::IndexerReader;
+    use sui_indexer::metrics::ReadApiV2Metrics;
     use sui_indexer::models::objects::{
         compose_object_bulk_insert_query, compose_object_bulk_insert_update_query,
         filter_latest_objects, NamedBcsBytes, Object, ObjectStatus,
     };
     use sui_indexer::models::owners::OwnerType;
+    use sui_indexer::models_v2::checkpoints::StoredCheckpoint;
     use sui_indexer::schema::objects;
+    use sui_indexer::schema_v2::checkpoints;
     use sui_indexer::store::{IndexerStore, PgIndexerStore};
     use sui_indexer::test_utils::{start_test_indexer, SuiTransactionBlockResponseBuilder};
+    use sui_indexer::utils::reset_database;
     use sui_indexer::{get_pg_pool_connection, new_pg_connection_pool, IndexerConfig};
     use sui_json_rpc::api::ExtendedApiClient;
     use sui_json_rpc::api::IndexerApiClient;
-    use sui_json_rpc::api::{ReadApiClient, TransactionBuilderClient, WriteApiClient};
+    use sui_json_rpc::api::{
+        ReadApiClient, ReadApiServer, TransactionBuilderClient, WriteApiClient,
+    };
     use sui_json_rpc_types::{
         CheckpointId, EventFilter, SuiMoveObject, SuiObjectData, SuiObjectDataFilter,
         SuiObjectDataOptions, SuiObjectResponse, SuiObjectResponseQuery, SuiParsedMoveObject,
         SuiTransactionBlockResponse, SuiTransactionBlockResponseOptions,
         SuiTransactionBlockResponseQuery, TransactionBlockBytes, TransactionFilter,
     };
-    use sui_types::base_types::{ObjectID, SuiAddress};
+    use sui_types::base_types::{ObjectID, SequenceNumber, SuiAddress};
+    use sui_types::coin::Coin;
+    use sui_types::collection_types::{Entry, VecMap};
     use sui_types::digests::{ObjectDigest, TransactionDigest};
+    use sui_types::display::DisplayVersionUpdatedEvent;
     use sui_types::error::SuiObjectResponseError;
     use sui_types::gas_coin::GasCoin;
-    use sui_types::object::ObjectFormatOptions;
+    use sui_types::id::ID;
+    use sui_types::object::{MoveObject, ObjectFormatOptions, Owner};
     use sui_types::quorum_driver_types::ExecuteTransactionRequestType;
     use sui_types::transaction::TEST_ONLY_GAS_UNIT_FOR_TRANSFER;
     use test_cluster::{TestCluster, TestClusterBuilder};
@@ -1236,6 +1252,537 @@ pub mod pg_integration_test {
         Ok(())
     }
 
+    #[tokio::test]
+    #[timeout(60000)]
+    async fn test_available_checkpoint_range_after_pruning() {
+        let db_url = reset_v2_database();
+        let pg_connection_pool = new_pg_connection_pool(&db_url).unwrap();
+        let mut pg_pool_conn = get_pg_pool_connection(&pg_connection_pool).unwrap();
+
+        let stored_checkpoints = (0..10)
+            .map(|seq| StoredCheckpoint {
+                sequence_number: seq,
+                checkpoint_digest: vec![seq as u8; 32],
+                ..Default::default()
+            })
+            .collect::<Vec<_>>();
+        diesel::insert_into(checkpoints::table)
+            .values(&stored_checkpoints)
+            .execute(&mut pg_pool_conn)
+            .unwrap();
+
+        // Prune everything below checkpoint 4
+        diesel::delete(checkpoints::table.filter(checkpoints::sequence_number.lt(4)))
+            .execute(&mut pg_pool_conn)
+            .unwrap();
+
+        let read_api = ReadApiV2::new(IndexerReader::new(db_url).unwrap());
+        assert_eq!(
+            read_api.get_available_checkpoint_range().await.unwrap(),
+            (4, 9)
+        );
+    }
+
+    #[tokio::test]
+    #[timeout(60000)]
+    async fn test_get_checkpoints_bounded_by_tx_digests() {
+        use sui_types::crypto::AggregateAuthoritySignature;
+        use sui_types::messages_checkpoint::CheckpointCommitment;
+
+        let db_url = reset_v2_database();
+        let pg_connection_pool = new_pg_connection_pool(&db_url).unwrap();
+        let mut pg_pool_conn = get_pg_pool_connection(&pg_connection_pool).unwrap();
+
+        let digest = |seq: u8, i: u8| vec![seq * 10 + i; 32];
+        let stored_checkpoints = [2u8, 3, 1]
+            .into_iter()
+            .enumerate()
+            .map(|(seq, tx_count)| StoredCheckpoint {
+                sequence_number: seq as i64,
+                checkpoint_digest: vec![seq as u8; 32],
+                tx_digests: (0..tx_count).map(|i| Some(digest(seq as u8, i))).collect(),
+                checkpoint_commitments: bcs::to_bytes(&Vec::<CheckpointCommitment>::new()).unwrap(),
+                validator_signature: bcs::to_bytes(&AggregateAuthoritySignature::default())
+                    .unwrap(),
+                ..Default::default()
+            })
+            .collect::<Vec<_>>();
+        diesel::insert_into(checkpoints::table)
+            .values(&stored_checkpoints)
+            .execute(&mut pg_pool_conn)
+            .unwrap();
+
+        let read_api =
+            ReadApiV2::new(IndexerReader::new(db_url).unwrap()).with_max_checkpoint_tx_digests(5);
+
+        // The page ends before checkpoint 2, which would take it over the bound, and says so.
+        let page = ReadApiServer::get_checkpoints(&read_api, None, Some(10), false)
+            .await
+            .unwrap();
+        assert_eq!(page.data.len(), 2);
+        assert!(page.has_next_page);
+        assert_eq!(page.next_cursor, Some(1u64.into()));
+        for checkpoint in &page.data {
+            let seq = checkpoint.sequence_number as u8;
+            let expected: Vec<_> = stored_checkpoints[seq as usize]
+                .tx_digests
+                .iter()
+                .map(|d| TransactionDigest::try_from(d.as_deref().unwrap()).unwrap())
+                .collect();
+            assert_eq!(checkpoint.transactions, expected);
+        }
+
+        // Nothing is dropped: the next page picks up from where the last one ended early.
+        let page = ReadApiServer::get_checkpoints(&read_api, page.next_cursor, Some(10), false)
+            .await
+            .unwrap();
+        assert_eq!(page.data.len(), 1);
+        assert_eq!(page.data[0].sequence_number, 2);
+        assert!(!page.has_next_page);
+    }
+
+    #[tokio::test]
+    #[timeout(60000)]
+    async fn test_display_fields_rendered_from_seeded_table() {
+        let reader = IndexerReader::new(reset_v2_database()).unwrap();
+        reader
+            .insert_display_for_testing(&GasCoin::type_(), gas_coin_display())
+            .await
+            .unwrap();
+
+        let read_api = ReadApiV2::new(reader);
+        let coin = sui_types::object::Object::new_gas_with_balance_and_owner_for_testing(
+            1000,
+            SuiAddress::ZERO,
+        );
+        let rendered = read_api
+            .get_display_fields(&coin, &Some(GasCoin::layout()))
+            .await
+            .unwrap();
+        assert_eq!(
+            rendered.data,
+            Some(BTreeMap::from([(
+                "balance".to_string(),
+                "1000 MIST".to_string()
+            )]))
+        );
+        assert!(rendered.error.is_none());
+    }
+
+    #[tokio::test]
+    #[timeout(60000)]
+    async fn test_display_fields_missing_display() {
+        let read_api = ReadApiV2::new(IndexerReader::new(reset_v2_database()).unwrap());
+        let coin = sui_types::object::Object::new_gas_with_balance_and_owner_for_testing(
+            1000,
+            SuiAddress::ZERO,
+        );
+        let rendered = read_api
+            .get_display_fields(&coin, &Some(GasCoin::layout()))
+            .await
+            .unwrap();
+        assert_eq!(rendered.data, None);
+        assert!(rendered.error.is_none());
+    }
+
+    #[tokio::test]
+    #[timeout(60000)]
+    async fn test_display_fields_layout_mismatch() {
+        let reader = IndexerReader::new(reset_v2_database()).unwrap();
+        reader
+            .insert_display_for_testing(&GasCoin::type_(), gas_coin_display())
+            .await
+            .unwrap();
+
+        let read_api = ReadApiV2::new(reader);
+        let coin = sui_types::object::Object::new_gas_with_balance_and_owner_for_testing(
+            1000,
+            SuiAddress::ZERO,
+        );
+        let layout = MoveStructLayout::Runtime(vec![MoveTypeLayout::Bool]);
+        assert!(read_api
+            .get_display_fields(&coin, &Some(layout))
+            .await
+            .is_err());
+    }
+
+    #[tokio::test]
+    #[timeout(60000)]
+    async fn test_display_fields_disabled() {
+        let reader = IndexerReader::new(reset_v2_database()).unwrap();
+        reader
+            .insert_display_for_testing(&GasCoin::type_(), gas_coin_display())
+            .await
+            .unwrap();
+
+        let read_api = ReadApiV2::new(reader).with_display_disabled();
+        let coin = sui_types::object::Object::new_gas_with_balance_and_owner_for_testing(
+            1000,
+            SuiAddress::ZERO,
+        );
+        let rendered = read_api
+            .get_display_fields(&coin, &Some(GasCoin::layout()))
+            .await
+            .unwrap();
+        assert_eq!(rendered.data, None);
+        assert!(matches!(
+            rendered.error,
+            Some(SuiObjectResponseError::DisplayError { error }) if error.contains("disabled")
+        ));
+    }
+
+    #[tokio::test]
+    #[timeout(60000)]
+    async fn test_get_object_read_with_type_filter() {
+        use sui_indexer::indexer_reader::TypedObjectRead;
+        use sui_types::object::ObjectRead;
+
+        let reader = IndexerReader::new(reset_v2_database()).unwrap();
+        let coin = sui_types::object::Object::new_gas_for_testing();
+        let id = coin.id();
+        reader.insert_object_for_testing(coin).await.unwrap();
+
+        let read = reader
+            .get_object_read_with_type_in_blocking_task(id, Some(GasCoin::type_()))
+            .await
+            .unwrap();
+        assert!(matches!(
+            read,
+            TypedObjectRead::Read(ObjectRead::Exists((object_id, _, _), _, _)) if object_id == id
+        ));
+
+        let read = reader
+            .get_object_read_with_type_in_blocking_task(id, Some(Coin::type_(TypeTag::Bool)))
+            .await
+            .unwrap();
+        let TypedObjectRead::TypeMismatch {
+            object_id,
+            expected,
+            actual,
+        } = read
+        else {
+            panic!("Expected a type mismatch, got {read:?}");
+        };
+        assert_eq!(object_id, id);
+        assert_eq!(
+            expected,
+            Coin::type_(TypeTag::Bool).to_canonical_string(/* with_prefix */ true)
+        );
+        assert_eq!(
+            actual,
+            Some(GasCoin::type_().to_canonical_string(/* with_prefix */ true))
+        );
+
+        let missing = ObjectID::random();
+        let read = reader
+            .get_object_read_with_type_in_blocking_task(missing, Some(GasCoin::type_()))
+            .await
+            .unwrap();
+        assert!(matches!(
+            read,
+            TypedObjectRead::Read(ObjectRead::NotExists(object_id)) if object_id == missing
+        ));
+    }
+
+    #[tokio::test]
+    #[timeout(60000)]
+    async fn test_display_fields_cached_per_object_version() {
+        let reader = IndexerReader::new(reset_v2_database()).unwrap();
+        reader
+            .insert_display_for_testing(&GasCoin::type_(), gas_coin_display())
+            .await
+            .unwrap();
+
+        let read_api = ReadApiV2::new(reader.clone()).with_display_cache(10);
+        let layout = Some(GasCoin::layout());
+        let id = ObjectID::random();
+        let coin = sui_types::object::Object::with_id_owner_version_for_testing(
+            id,
+            SequenceNumber::from_u64(1),
+            SuiAddress::ZERO,
+        );
+        let first = read_api.get_display_fields(&coin, &layout).await.unwrap();
+
+        // Replace the Display, so rendering the coin again would give a different result.
+        let mut updated = gas_coin_display();
+        updated.version = 2;
+        updated.fields.contents[0].value = "a coin".to_string();
+        reader
+            .insert_display_for_testing(&GasCoin::type_(), updated)
+            .await
+            .unwrap();
+
+        let second = read_api.get_display_fields(&coin, &layout).await.unwrap();
+        assert_eq!(first, second);
+
+        // A new version of the coin is rendered again, and picks up the new Display.
+        let coin = sui_types::object::Object::with_id_owner_version_for_testing(
+            id,
+            SequenceNumber::from_u64(2),
+            SuiAddress::ZERO,
+        );
+        let third = read_api.get_display_fields(&coin, &layout).await.unwrap();
+        assert_eq!(
+            third.data,
+            Some(BTreeMap::from([(
+                "balance".to_string(),
+                "a coin".to_string()
+            )]))
+        );
+    }
+
+    #[tokio::test]
+    #[timeout(60000)]
+    async fn test_failed_display_render_not_cached() {
+        let reader = IndexerReader::new(reset_v2_database()).unwrap();
+        let mut broken = gas_coin_display();
+        broken.fields.contents[0].value = "{no_such_field}".to_string();
+        reader
+            .insert_display_for_testing(&GasCoin::type_(), broken)
+            .await
+            .unwrap();
+
+        let read_api = ReadApiV2::new(reader.clone()).with_display_cache(10);
+        let layout = Some(GasCoin::layout());
+        let coin = sui_types::object::Object::with_id_owner_version_for_testing(
+            ObjectID::random(),
+            SequenceNumber::from_u64(1),
+            SuiAddress::ZERO,
+        );
+        let first = read_api.get_display_fields(&coin, &layout).await.unwrap();
+        assert!(first.error.is_some());
+
+        // Fixing the Display is picked up at the same version of the coin, because the failed
+        // render was not remembered.
+        let mut fixed = gas_coin_display();
+        fixed.version = 2;
+        reader
+            .insert_display_for_testing(&GasCoin::type_(), fixed)
+            .await
+            .unwrap();
+
+        let second = read_api.get_display_fields(&coin, &layout).await.unwrap();
+        assert_eq!(second.error, None);
+        assert!(second.data.unwrap().contains_key("balance"));
+    }
+
+    #[tokio::test]
+    #[timeout(60000)]
+    async fn test_batch_resolve_display_mixed_types() {
+        let reader = IndexerReader::new(reset_v2_database()).unwrap();
+        reader
+            .insert_display_for_testing(&GasCoin::type_(), gas_coin_display())
+            .await
+            .unwrap();
+
+        let gas_coin = |version| {
+            let object = sui_types::object::Object::with_id_owner_version_for_testing(
+                ObjectID::random(),
+                SequenceNumber::from_u64(version),
+                SuiAddress::ZERO,
+            );
+            (object, Some(GasCoin::layout()))
+        };
+        // A coin of a type with no Display registered.
+        let bool_coin = sui_types::object::Object::new_move(
+            MoveObject::new_coin(
+                Coin::type_(TypeTag::Bool).into(),
+                SequenceNumber::from_u64(1),
+                ObjectID::random(),
+                5,
+            ),
+            Owner::AddressOwner(SuiAddress::ZERO),
+            TransactionDigest::genesis_marker(),
+        );
+
+        let displays = reader
+            .batch_resolve_display(vec![
+                gas_coin(1),
+                (bool_coin, Some(Coin::layout(TypeTag::Bool))),
+                gas_coin(2),
+            ])
+            .await
+            .unwrap();
+
+        assert_eq!(displays.len(), 3);
+        assert!(displays[0].data.as_ref().unwrap().contains_key("balance"));
+        assert_eq!(displays[1].data, None);
+        assert_eq!(displays[1].error, None);
+        assert_eq!(displays[0], displays[2]);
+    }
+
+    /// Records the name and fields of every span created while it is the default subscriber.
+    #[derive(Clone, Default)]
+    struct SpanRecorder(Arc<Mutex<BTreeMap<u64, (String, BTreeMap<String, String>)>>>);
+
+    struct FieldVisitor<'a>(&'a mut BTreeMap<String, String>);
+
+    impl tracing::field::Visit for FieldVisitor<'_> {
+        fn record_debug(&mut self, field: &tracing::field::Field, value: &dyn std::fmt::Debug) {
+            self.0
+                .insert(field.name().to_string(), format!("{value:?}"));
+        }
+    }
+
+    impl<S: tracing::Subscriber> tracing_subscriber::Layer<S> for SpanRecorder {
+        fn on_new_span(
+            &self,
+            attrs: &tracing::span::Attributes<'_>,
+            id: &tracing::span::Id,
+            _ctx: tracing_subscriber::layer::Context<'_, S>,
+        ) {
+            let mut fields = BTreeMap::new();
+            attrs.record(&mut FieldVisitor(&mut fields));
+            let name = attrs.metadata().name().to_string();
+            self.0.lock().unwrap().insert(id.into_u64(), (name, fields));
+        }
+
+        fn on_record(
+            &self,
+            id: &tracing::span::Id,
+            values: &tracing::span::Record<'_>,
+            _ctx: tracing_subscriber::layer::Context<'_, S>,
+        ) {
+            if let Some((_, fields)) = self.0.lock().unwrap().get_mut(&id.into_u64()) {
+                values.record(&mut FieldVisitor(fields));
+            }
+        }
+    }
+
+    #[tokio::test]
+    #[timeout(60000)]
+    async fn test_read_api_v2_emits_spans_and_metrics() {
+        let recorder = SpanRecorder::default();
+        let _guard =
+            tracing::subscriber::set_default(tracing_subscriber::registry().with(recorder.clone()));
+        let metrics = ReadApiV2Metrics::new(&prometheus::Registry::new());
+        let read_api = ReadApiV2::new(IndexerReader::new(reset_v2_database()).unwrap())
+            .with_metrics(metrics.clone());
+
+        let responses = ReadApiServer::multi_get_objects(
+            &read_api,
+            vec![ObjectID::random(), ObjectID::random()],
+            None,
+        )
+        .await
+        .unwrap();
+        assert_eq!(responses.len(), 2);
+
+        let spans: Vec<_> = recorder.0.lock().unwrap().values().cloned().collect();
+        let (_, fields) = spans
+            .iter()
+            .find(|(name, _)| name == "multi_get_objects")
+            .expect("multi_get_objects should emit a span");
+        assert_eq!(fields.get("ids").map(String::as_str), Some("2"));
+        // Without `show_display`, each object is read through `get_object`, which times its read.
+        let object_spans: Vec<_> = spans
+            .iter()
+            .filter(|(name, _)| name == "get_object")
+            .collect();
+        assert_eq!(object_spans.len(), 2);
+        assert!(object_spans
+            .iter()
+            .all(|(_, fields)| fields.contains_key("db_ms")));
+
+        let requests = |method: &str| {
+            metrics
+                .request_latency
+                .with_label_values(&[method])
+                .get_sample_count()
+        };
+        assert_eq!(requests("multi_get_objects"), 1);
+        assert_eq!(requests("get_object"), 2);
+        assert_eq!(
+            metrics
+                .stage_latency
+                .with_label_values(&["get_object", "db"])
+                .get_sample_count(),
+            2
+        );
+    }
+
+    #[tokio::test]
+    #[timeout(60000)]
+    async fn test_failed_batch_display_render_not_cached() {
+        let reader = IndexerReader::new(reset_v2_database()).unwrap();
+        let mut broken = gas_coin_display();
+        broken.fields.contents[0].value = "{no_such_field}".to_string();
+        reader
+            .insert_display_for_testing(&GasCoin::type_(), broken)
+            .await
+            .unwrap();
+
+        let read_api = ReadApiV2::new(reader.clone()).with_display_cache(10);
+        let coin = sui_types::object::Object::with_id_owner_version_for_testing(
+            ObjectID::random(),
+            SequenceNumber::from_u64(1),
+            SuiAddress::ZERO,
+        );
+        let objects = vec![(coin, Some(GasCoin::layout()))];
+        let first = read_api
+            .batch_display_fields(objects.clone())
+            .await
+            .unwrap();
+        assert!(first[0].error.is_some());
+
+        let mut fixed = gas_coin_display();
+        fixed.version = 2;
+        reader
+            .insert_display_for_testing(&GasCoin::type_(), fixed)
+            .await
+            .unwrap();
+
+        // The failed render was not remembered, so the same version is rendered again with the
+        // fixed Display.
+        let second = read_api
+            .batch_display_fields(objects.clone())
+            .await
+            .unwrap();
+        assert_eq!(second[0].error, None);
+        assert!(second[0].data.as_ref().unwrap().contains_key("balance"));
+
+        // The successful render is remembered, so a later change to the Display is not seen.
+        let mut updated = gas_coin_display();
+        updated.version = 3;
+        updated.fields.contents[0].value = "a coin".to_string();
+        reader
+            .insert_display_for_testing(&GasCoin::type_(), updated)
+            .await
+            .unwrap();
+        let third = read_api.batch_display_fields(objects).await.unwrap();
+        assert_eq!(second, third);
+    }
+
+    /// Drop and recreate the v2 tables, returning the URL of the database.
+    fn reset_v2_database() -> String {
+        let pg_host = env::var("POSTGRES_HOST").unwrap_or_else(|_| "localhost".into());
+        let pg_port = env::var("POSTGRES_PORT").unwrap_or_else(|_| "32770".into());
+        let pw = env::var("POSTGRES_PASSWORD").unwrap_or_else(|_| "postgrespw".into());
+        let db_url = format!("postgres://postgres:{pw}@{pg_host}:{pg_port}");
+        let pg_connection_pool = new_pg_connection_pool(&db_url).unwrap();
+        let mut pg_pool_conn = get_pg_pool_connection(&pg_connection_pool).unwrap();
+        reset_database(
+            &mut pg_pool_conn,
+            /* drop_all */ true,
+            /* use_v2 */ true,
+        )
+        .unwrap();
+        db_url
+    }
+
+    fn gas_coin_display() -> DisplayVersionUpdatedEvent {
+        DisplayVersionUpdatedEvent {
+            id: ID::new(ObjectID::ZERO),
+            version: 1,
+            fields: VecMap {
+                contents: vec![Entry {
+                    key: "balance".to_string(),
+                    value: "{balance} MIST".to_string(),
+                }],
+            },
+        }
+    }
+
     async fn start_test_cluster(
         epoch_duration_ms: Option<u64>,
     ) -> (
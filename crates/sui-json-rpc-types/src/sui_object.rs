@@ -405,6 +405,12 @@ impl SuiObjectDataOptions {
     pub fn is_not_in_object_info(&self) -> bool {
         self.show_bcs || self.show_content || self.show_display || self.show_storage_rebate
     }
+
+    /// Whether the Move layout of the object is needed to honor these options. Raw BCS data does
+    /// not need it, only parsed content and Display rendering do.
+    pub fn requires_layout(&self) -> bool {
+        self.show_content || self.show_display
+    }
 }
 
 impl TryFrom<(ObjectRead, SuiObjectDataOptions)> for SuiObjectResponse {
@@ -498,12 +504,8 @@ impl
 
         let bcs: Option<SuiRawData> = if show_bcs {
             let data = match o.data.clone() {
-                Data::Move(m) => {
-                    let layout = layout.clone().ok_or_else(|| {
-                        anyhow!("Layout is required to convert Move object to json")
-                    })?;
-                    SuiRawData::try_from_object(m, layout)?
-                }
+                // The raw representation does not depend on the layout, so it is not required.
+                Data::Move(m) => SuiRawData::MoveObject(m.into()),
                 Data::Package(p) => SuiRawData::try_from_package(p)
                     .map_err(|e| anyhow!("Error getting raw data from package: {e:#?}"))?,
             };
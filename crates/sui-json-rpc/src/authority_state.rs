@@ -349,7 +349,7 @@ impl StateRead for AuthorityState {
         gas_price: Option<u64>,
     ) -> StateReadResult<DevInspectResults> {
         Ok(self
-            .dev_inspect_transaction_block(sender, transaction_kind, gas_price)
+            .dev_inspect_transaction_block(sender, transaction_kind, gas_price, None)
             .await?)
     }
 
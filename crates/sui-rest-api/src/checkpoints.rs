@@ -38,6 +38,15 @@ pub async fn get_full_checkpoint(
         return Err(AppError(anyhow::anyhow!("invalid accept type")));
     }
 
+    Ok(Bcs(get_checkpoint_data(state.as_ref(), checkpoint_id)?))
+}
+
+/// Collect the summary, contents, and full transactions of checkpoint `checkpoint_id`, including
+/// the objects each transaction read and wrote.
+pub fn get_checkpoint_data<S: NodeStateGetter + ?Sized>(
+    state: &S,
+    checkpoint_id: CheckpointSequenceNumber,
+) -> Result<CheckpointData> {
     let verified_summary = state.get_verified_checkpoint_by_sequence_number(checkpoint_id)?;
     let checkpoint_contents = state.get_checkpoint_contents(verified_summary.content_digest)?;
 
@@ -156,11 +165,11 @@ pub async fn get_full_checkpoint(
         full_transactions.push(full_transaction);
     }
 
-    Ok(Bcs(CheckpointData {
+    Ok(CheckpointData {
         checkpoint_summary: verified_summary.into(),
         checkpoint_contents,
         transactions: full_transactions,
-    }))
+    })
 }
 
 #[derive(Clone, Debug, Serialize, Deserialize)]
@@ -9,7 +9,7 @@ pub mod headers;
 pub mod node_state_getter;
 mod objects;
 
-pub use checkpoints::{CheckpointData, CheckpointTransaction};
+pub use checkpoints::{get_checkpoint_data, CheckpointData, CheckpointTransaction};
 pub use client::Client;
 use node_state_getter::NodeStateGetter;
 
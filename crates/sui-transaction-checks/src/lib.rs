@@ -172,7 +172,8 @@ mod checked {
             .into());
         }
         check_input_objects(&input_objects, config)?;
-        let mut used_objects: HashSet<SuiAddress> = HashSet::new();
+        // the gas coin cannot also be used as an input
+        let mut used_objects: HashSet<SuiAddress> = HashSet::from([gas_object_ref.0.into()]);
         for input_object in input_objects.iter() {
             let Some(object) = input_object.as_object() else {
                 // object was deleted
@@ -1,21 +1,31 @@
 // Copyright (c) Mysten Labs, Inc.
 // SPDX-License-Identifier: Apache-2.0
 
-use anyhow::{bail, ensure};
+use anyhow::{anyhow, bail, ensure};
 use clap;
+use fastcrypto::encoding::{Base64, Encoding, Hex};
+use move_command_line_common::address::ParsedAddress;
 use move_command_line_common::parser::{parse_u256, parse_u64};
+use move_command_line_common::types::ParsedStructType;
 use move_command_line_common::values::{ParsableValue, ParsedValue};
 use move_command_line_common::{parser::Parser as MoveCLParser, values::ValueToken};
+use move_core_types::identifier::Identifier;
+use move_core_types::language_storage::StructTag;
 use move_core_types::u256::U256;
-use move_core_types::value::{MoveStruct, MoveValue};
+use move_core_types::value::{MoveStruct, MoveStructLayout, MoveTypeLayout, MoveValue};
 use move_symbol_pool::Symbol;
 use move_transactional_test_runner::tasks::SyntaxChoice;
+use std::path::PathBuf;
 use sui_types::base_types::{SequenceNumber, SuiAddress};
+use sui_types::crypto::{
+    AccountKeyPair, AccountPrivateKey, SignatureScheme, SigningKey, ToFromBytes,
+};
 use sui_types::move_package::UpgradePolicy;
-use sui_types::object::{Object, Owner};
+use sui_types::object::{MoveObject, Object, ObjectFormatOptions, Owner};
 use sui_types::programmable_transaction_builder::ProgrammableTransactionBuilder;
 use sui_types::transaction::{Argument, CallArg, ObjectArg};
 
+use crate::programmable_transaction_test_parser::parser::ParsedMoveCall;
 use crate::test_adapter::{FakeID, SuiTestAdapter};
 
 pub const SUI_ARGS_LONG: &str = "sui-args";
@@ -28,6 +38,9 @@ pub struct SuiRunArgs {
     pub gas_price: Option<u64>,
     #[clap(long = "summarize")]
     pub summarize: bool,
+    /// Also list the objects the transaction took as input, and whether it modified them.
+    #[clap(long = "show-inputs")]
+    pub show_inputs: bool,
 }
 
 #[derive(Debug, clap::Parser)]
@@ -44,28 +57,199 @@ pub struct SuiPublishArgs {
 pub struct SuiInitArgs {
     #[clap(long = "accounts", num_args(1..))]
     pub accounts: Option<Vec<String>>,
+    #[clap(long = "default-sender")]
+    pub default_sender: Option<String>,
     #[clap(long = "protocol-version")]
     pub protocol_version: Option<u64>,
     #[clap(long = "max-gas")]
     pub max_gas: Option<u64>,
     #[clap(long = "shared-object-deletion")]
     pub shared_object_deletion: Option<bool>,
+    #[clap(long = "shared-object-retries")]
+    pub shared_object_retries: Option<u32>,
+    /// Multisig accounts, as `NAME:THRESHOLD:MEMBER[:WEIGHT],...`. Members must be declared with
+    /// `--accounts`, and have weight 1 unless specified.
+    #[clap(
+        long = "multisig",
+        value_parser = parse_multisig,
+        action = clap::ArgAction::Append,
+    )]
+    pub multisigs: Vec<MultisigArg>,
+    /// A zkLogin identity named `NAME`, which authenticates with a fixture ephemeral key and
+    /// proof. The proof expires after epoch 10. Only supported in simulator mode.
+    #[clap(long = "zklogin")]
+    pub zklogin: Option<String>,
+    /// Do not accept the JWKs the zkLogin fixture proof was issued against until they are added
+    /// to the authenticator state with `authenticator-state-update`.
+    #[clap(long = "zklogin-jwks-on-chain", requires = "zklogin")]
+    pub zklogin_jwks_on_chain: bool,
+    /// Accounts that sign with a known Ed25519 private key, as `NAME:KEY`, where `KEY` is the
+    /// 32 byte key, or the key prefixed with its scheme flag as in a Sui keystore, encoded in
+    /// base64 or `0x` prefixed hex.
+    #[clap(
+        long = "imported-account",
+        value_parser = parse_imported_account,
+        action = clap::ArgAction::Append,
+    )]
+    pub imported_accounts: Vec<ImportedAccountArg>,
+    /// How many gas coins each account declared with `--accounts` starts with. The first is the
+    /// one the account pays for gas with. Defaults to 1.
+    #[clap(long = "gas-coins-per-account")]
+    pub gas_coins_per_account: Option<usize>,
+    /// A fixture file of objects to add to the genesis state, in the format written by
+    /// `dump-store`. Relative paths are resolved against the working directory of the test run.
+    #[clap(long = "objects")]
+    pub objects: Option<PathBuf>,
+    /// Where `dump-store` writes dumps given as a relative path. Relative paths are resolved
+    /// against the working directory of the test run.
+    #[clap(long = "dump-dir")]
+    pub dump_dir: Option<PathBuf>,
     #[clap(long = "simulator")]
     pub simulator: bool,
 }
 
+#[derive(Clone, Debug)]
+pub struct MultisigArg {
+    pub name: String,
+    pub threshold: u16,
+    pub members: Vec<(String, u8)>,
+}
+
+#[derive(Clone, Debug)]
+pub struct ImportedAccountArg {
+    pub name: String,
+    private_key: Vec<u8>,
+}
+
+impl ImportedAccountArg {
+    pub fn key_pair(&self) -> AccountKeyPair {
+        // The key is checked to be valid when it is parsed
+        AccountPrivateKey::from_bytes(&self.private_key)
+            .unwrap()
+            .into()
+    }
+}
+
 #[derive(Debug, clap::Parser)]
 pub struct ViewObjectCommand {
     #[clap(value_parser = parse_fake_id)]
     pub id: FakeID,
+    /// Also print the object's size as used for storage cost and rebate calculations.
+    #[clap(long = "show-size")]
+    pub show_size: bool,
+}
+
+#[derive(Debug, clap::Parser)]
+pub struct ViewObjectAtCheckpointCommand {
+    #[clap(value_parser = parse_fake_id)]
+    pub id: FakeID,
+    /// The sequence number of the checkpoint to view the object as of.
+    #[clap(long = "checkpoint")]
+    pub checkpoint: u64,
+}
+
+#[derive(Debug, clap::Parser)]
+pub struct ViewStorageRebateCommand {
+    #[clap(value_parser = parse_fake_id)]
+    pub id: FakeID,
+}
+
+#[derive(Debug, clap::Parser)]
+pub struct ViewDynamicFieldCommand {
+    #[clap(value_parser = parse_fake_id)]
+    pub parent: FakeID,
+    #[clap(long = "name", value_parser = parse_input_value)]
+    pub name: ParsedValue<SuiExtraValueArgs>,
+}
+
+#[derive(Debug, clap::Parser)]
+pub struct ViewOwnerChainCommand {
+    #[clap(value_parser = parse_fake_id)]
+    pub id: FakeID,
+}
+
+#[derive(Debug, clap::Parser)]
+pub struct ViewDynamicFieldChangesCommand {
+    /// The number of the task that built the transaction.
+    pub task: u64,
+    #[clap(value_parser = parse_fake_id)]
+    pub parent: FakeID,
+}
+
+#[derive(Debug, clap::Parser)]
+pub struct AssertPackageDigestCommand {
+    #[clap(value_parser = parse_fake_id)]
+    pub id: FakeID,
+    /// Hex encoded digest of the package's modules and dependencies.
+    pub expected: String,
+}
+
+#[derive(Debug, clap::Parser)]
+pub struct AssertObjectTypeCommand {
+    #[clap(value_parser = parse_fake_id)]
+    pub id: FakeID,
+    /// The object's full type, including any type arguments.
+    #[clap(value_parser = ParsedStructType::parse)]
+    pub expected: ParsedStructType,
+}
+
+#[derive(Debug, clap::Parser)]
+pub struct DiffObjectCommand {
+    #[clap(value_parser = parse_fake_id)]
+    pub id: FakeID,
+    #[clap(long = "from")]
+    pub from: u64,
+    #[clap(long = "to")]
+    pub to: u64,
+}
+
+#[derive(Debug, clap::Parser)]
+pub struct ViewPackageIdsCommand {
+    #[clap(value_parser = parse_fake_id)]
+    pub id: FakeID,
+}
+
+#[derive(Debug, clap::Parser)]
+pub struct ViewLinkageCommand {
+    #[clap(value_parser = parse_fake_id)]
+    pub id: FakeID,
 }
 
 #[derive(Debug, clap::Parser)]
 pub struct TransferObjectCommand {
     #[clap(value_parser = parse_fake_id)]
     pub id: FakeID,
-    #[clap(long = "recipient")]
-    pub recipient: String,
+    /// An account, a named address, or a literal address, e.g. `0x42`.
+    #[clap(long = "recipient", value_parser = ParsedAddress::parse)]
+    pub recipient: ParsedAddress,
+    #[clap(long = "sender")]
+    pub sender: Option<String>,
+    #[clap(long = "gas-budget")]
+    pub gas_budget: Option<u64>,
+}
+
+#[derive(Debug, clap::Parser)]
+pub struct MintCoinCommand {
+    /// The `TreasuryCap<T>` to mint a `Coin<T>` with.
+    #[clap(long = "treasury", value_parser = parse_fake_id)]
+    pub treasury: FakeID,
+    #[clap(long = "amount")]
+    pub amount: u64,
+    /// An account, a named address, or a literal address, e.g. `0x42`.
+    #[clap(long = "recipient", value_parser = ParsedAddress::parse)]
+    pub recipient: ParsedAddress,
+    #[clap(long = "sender")]
+    pub sender: Option<String>,
+    #[clap(long = "gas-budget")]
+    pub gas_budget: Option<u64>,
+}
+
+#[derive(Debug, clap::Parser)]
+pub struct TransferToObjectCommand {
+    #[clap(long = "child", value_parser = parse_fake_id)]
+    pub child: FakeID,
+    #[clap(long = "parent", value_parser = parse_fake_id)]
+    pub parent: FakeID,
     #[clap(long = "sender")]
     pub sender: Option<String>,
     #[clap(long = "gas-budget")]
@@ -78,6 +262,14 @@ pub struct ConsensusCommitPrologueCommand {
     pub timestamp_ms: u64,
 }
 
+#[derive(Debug, clap::Parser)]
+pub struct RandomnessStateUpdateCommand {
+    #[clap(long = "round")]
+    pub round: u64,
+    #[clap(long = "random-bytes")]
+    pub random_bytes: String,
+}
+
 #[derive(Debug, clap::Parser)]
 pub struct ProgrammableTransactionCommand {
     #[clap(long = "sender")]
@@ -88,9 +280,37 @@ pub struct ProgrammableTransactionCommand {
     pub gas_price: Option<u64>,
     #[clap(long = "dev-inspect")]
     pub dev_inspect: bool,
+    /// Pay for gas with this gas coin of the sender's when dev-inspecting, instead of a fresh one.
+    #[clap(long = "dev-inspect-gas", value_parser = parse_fake_id, requires = "dev_inspect")]
+    pub dev_inspect_gas: Option<FakeID>,
+    /// Dry run the transaction first, and use the gas it was charged, plus a margin, as the
+    /// budget for the real execution.
+    #[clap(
+        long = "auto-gas-budget",
+        conflicts_with_all = ["gas_budget", "dev_inspect"]
+    )]
+    pub auto_gas_budget: bool,
+    /// Build and sign the transaction, then print its digest instead of executing it.
+    #[clap(long = "compute-digest-only", conflicts_with = "dev_inspect")]
+    pub compute_digest_only: bool,
+    /// Also list the objects the transaction took as input, and whether it modified them.
+    #[clap(long = "show-inputs")]
+    pub show_inputs: bool,
+    /// List the events the transaction emitted one per line, with their sequence numbers, in the
+    /// same format as `query-events-by-type --show-seq`.
+    #[clap(long = "show-events")]
+    pub show_events: bool,
+    /// Members signing for a multisig `--sender`. Defaults to members in declaration order until
+    /// the threshold is met.
+    #[clap(long = "multisig-signers", num_args(1..))]
+    pub multisig_signers: Option<Vec<String>>,
+    /// Add an unused pure input that pads the signed transaction to exactly this many serialized
+    /// bytes, to test the transaction size limit.
+    #[clap(long = "pad-to", conflicts_with = "dev_inspect")]
+    pub pad_to: Option<usize>,
     #[clap(
         long = "inputs",
-        value_parser = ParsedValue::<SuiExtraValueArgs>::parse,
+        value_parser = parse_input_value,
         num_args(1..),
         action = clap::ArgAction::Append,
     )]
@@ -126,10 +346,338 @@ pub struct StagePackageCommand {
 #[derive(Debug, clap::Parser)]
 pub struct SetAddressCommand {
     pub address: String,
-    #[clap(value_parser = ParsedValue::<SuiExtraValueArgs>::parse)]
+    #[clap(value_parser = parse_input_value)]
     pub input: ParsedValue<SuiExtraValueArgs>,
 }
 
+#[derive(Debug, clap::Parser)]
+pub struct QueryEventsByTypeCommand {
+    #[clap(value_parser = ParsedStructType::parse)]
+    pub event_type: ParsedStructType,
+    #[clap(long = "limit")]
+    pub limit: Option<usize>,
+    /// Prefix each event with the task that emitted it and its sequence number within that
+    /// task's transaction, which together make up its cursor.
+    #[clap(long = "show-seq")]
+    pub show_seq: bool,
+}
+
+#[derive(Debug, clap::Parser)]
+pub struct ListObjectsCommand {
+    #[clap(long = "owner")]
+    pub owner: String,
+    /// Start after this object, as printed in the previous page's next cursor.
+    #[clap(long = "cursor", value_parser = parse_fake_id)]
+    pub cursor: Option<FakeID>,
+    #[clap(long = "limit")]
+    pub limit: Option<usize>,
+}
+
+#[derive(Debug, clap::Parser)]
+pub struct CountObjectsCommand {
+    /// Only count objects owned by this account.
+    #[clap(long = "owner")]
+    pub owner: Option<String>,
+    /// Only count Move objects of exactly this type.
+    #[clap(long = "type", value_parser = ParsedStructType::parse)]
+    pub type_: Option<ParsedStructType>,
+}
+
+#[derive(Debug, clap::Parser)]
+pub struct ViewGasPriceCommand {
+    /// The number of the task that built the transaction.
+    pub task: u64,
+}
+
+#[derive(Debug, clap::Parser)]
+pub struct ViewProtocolConfigCommand {
+    /// The name of a single config value or feature flag to print, instead of the whole config.
+    #[clap(long = "key")]
+    pub key: Option<String>,
+}
+
+#[derive(Debug, clap::Parser)]
+pub struct ReplayTransactionCommand {
+    /// The digest of the transaction to replay.
+    pub digest: String,
+    /// The JSON-RPC URL of the fullnode to fetch the transaction and its inputs from.
+    #[clap(long = "rpc")]
+    pub rpc: String,
+}
+
+#[derive(Debug, clap::Parser)]
+pub struct ViewTxCommand {
+    /// The number of the task that built the transaction.
+    pub task: u64,
+}
+
+#[derive(Debug, clap::Parser)]
+pub struct ViewTxDependenciesCommand {
+    /// The number of the task that built the transaction.
+    pub task: u64,
+}
+
+#[derive(Debug, clap::Parser)]
+pub struct ViewValidatorCommand {
+    /// The validator's address, either as a literal or a named address such as `validator_0`.
+    #[clap(value_parser = ParsedAddress::parse)]
+    pub address: ParsedAddress,
+}
+
+#[derive(Debug, clap::Parser)]
+pub struct SetGasPriceVoteCommand {
+    /// The validator's address, either as a literal or a named address such as `validator_0`.
+    #[clap(value_parser = ParsedAddress::parse)]
+    pub validator: ParsedAddress,
+    /// The gas price the validator votes for the reference gas price to be from the next epoch.
+    pub price: u64,
+}
+
+/// Creates an empty `sui::bag::Bag`, shared from the start.
+#[derive(Debug, clap::Parser)]
+pub struct CreateSharedObjectCommand {
+    /// The version the object is created at, and so the version it was first shared at.
+    #[clap(long = "initial-shared-version")]
+    pub initial_shared_version: u64,
+}
+
+#[derive(Debug, clap::Parser)]
+pub struct ExecuteSignedCommand {
+    /// Base64 encoded BCS bytes of the signed transaction's `SenderSignedData`
+    #[clap(long = "tx-bytes")]
+    pub tx_bytes: String,
+}
+
+#[derive(Debug, clap::Parser)]
+pub struct AdvanceEpochCommand {
+    #[clap(long = "count", conflicts_with = "to")]
+    pub count: Option<u64>,
+    #[clap(long = "to")]
+    pub to: Option<u64>,
+}
+
+#[derive(Debug, clap::Parser)]
+pub struct UpgradeProtocolCommand {
+    /// The protocol version to run the next epoch under.
+    #[clap(long = "to")]
+    pub to: u64,
+}
+
+#[derive(Debug, clap::Parser)]
+pub struct CreateCheckpointsCommand {
+    /// How many checkpoints to create, one after the other. All but the first are empty.
+    #[clap(long = "count")]
+    pub count: u64,
+}
+
+#[derive(Debug, clap::Parser)]
+pub struct AssertCheckpointTxCountCommand {
+    /// The number of transactions the latest checkpoint should contain.
+    pub expected: usize,
+    /// Count the transactions executed since the latest checkpoint, which the next
+    /// `create-checkpoint` will include, instead. Only supported in simulator mode.
+    #[clap(long = "pending")]
+    pub pending: bool,
+}
+
+#[derive(Debug, clap::Parser)]
+pub struct AssertTotalSupplyCommand {
+    /// The type `T` of the `Coin<T>`s to sum.
+    #[clap(value_parser = ParsedStructType::parse)]
+    pub coin_type: ParsedStructType,
+    /// The expected sum of the balances of all live coins of that type.
+    pub expected: u128,
+}
+
+#[derive(Debug, clap::Parser)]
+pub struct AssertCreatedCountCommand {
+    /// The number of the task that built the transaction.
+    pub task: u64,
+    /// The number of objects the transaction should have created.
+    pub expected: usize,
+    /// Only require that the transaction created no more than `expected` objects.
+    #[clap(long = "at-most")]
+    pub at_most: bool,
+}
+
+#[derive(Debug, clap::Parser)]
+pub struct AssertBalanceChangeCommand {
+    /// The number of the task that built the transaction.
+    pub task: u64,
+    /// The account whose balance is checked.
+    pub account: String,
+    /// The type `T` of the `Coin<T>`s to sum.
+    #[clap(value_parser = ParsedStructType::parse)]
+    pub coin_type: ParsedStructType,
+    /// The magnitude of the expected change, which is an increase unless `--decrease` is set.
+    pub amount: u64,
+    /// The balance is expected to decrease by `amount`.
+    #[clap(long = "decrease")]
+    pub decrease: bool,
+}
+
+#[derive(Debug, clap::Parser)]
+pub struct AssertObjectUnchangedCommand {
+    #[clap(value_parser = parse_fake_id)]
+    pub id: FakeID,
+    /// The number of the task that built the transaction, defaulting to the latest one.
+    #[clap(long = "task")]
+    pub task: Option<u64>,
+}
+
+#[derive(Debug, clap::Parser)]
+pub struct AssertValidatorCountCommand {
+    /// The number of validators expected to be in the active set.
+    pub expected: usize,
+}
+
+#[derive(Debug, clap::Parser)]
+pub struct RemoveAccountCommand {
+    /// The name of the account to forget. Its objects stay where they are, but the name no longer
+    /// resolves, either as a sender or as an address.
+    pub name: String,
+}
+
+#[derive(Debug, clap::Parser)]
+pub struct AssertDevInspectReturnCommand {
+    /// The function to call, as `address::module::function`, followed by any type arguments.
+    #[clap(value_parser = ParsedMoveCall::parse_function)]
+    pub function: ParsedMoveCall,
+    #[clap(
+        long = "args",
+        value_parser = parse_input_value,
+        num_args(1..),
+    )]
+    pub args: Vec<ParsedValue<SuiExtraValueArgs>>,
+    #[clap(long = "sender")]
+    pub sender: Option<String>,
+    /// The single value the function is expected to return.
+    #[clap(long = "expected", value_parser = parse_input_value)]
+    pub expected: ParsedValue<SuiExtraValueArgs>,
+}
+
+#[derive(Debug, clap::Parser)]
+pub struct ViewCheckpointEpochInfoCommand {
+    /// The sequence number of the checkpoint to inspect. Defaults to the latest checkpoint.
+    #[clap(long = "checkpoint")]
+    pub checkpoint: Option<u64>,
+    /// If set, the checkpoint must end its epoch, and the next epoch must run at this protocol
+    /// version.
+    #[clap(long = "expect-protocol-version")]
+    pub expect_protocol_version: Option<u64>,
+}
+
+#[derive(Debug, clap::Parser)]
+pub struct AssertTxEpochCommand {
+    /// The number of the task that built the transaction.
+    pub task: u64,
+    /// The epoch the transaction is expected to have been executed in.
+    pub epoch: u64,
+}
+
+#[derive(Debug, clap::Parser)]
+pub struct ExpectUseDeletedCommand {
+    #[clap(value_parser = parse_fake_id)]
+    pub id: FakeID,
+    #[clap(long = "sender")]
+    pub sender: Option<String>,
+}
+
+#[derive(Debug, clap::Parser)]
+pub struct ViewStorageAccountingCommand {
+    /// The number of the task that built the transaction.
+    pub task: u64,
+}
+
+#[derive(Debug, clap::Parser)]
+pub struct AssertSharedVersionCommand {
+    #[clap(value_parser = parse_fake_id)]
+    pub id: FakeID,
+    /// The version the shared object is expected to be at.
+    pub version: u64,
+}
+
+#[derive(Debug, clap::Parser)]
+pub struct AssertSameTypeOriginCommand {
+    #[clap(value_parser = parse_fake_id)]
+    pub first: FakeID,
+    #[clap(value_parser = parse_fake_id)]
+    pub second: FakeID,
+}
+
+#[derive(Debug, clap::Parser)]
+pub struct AssertUpgradeableCommand {
+    #[clap(value_parser = parse_fake_id)]
+    pub package: FakeID,
+    /// The package should have an `UpgradeCap`.
+    #[clap(long = "yes", conflicts_with = "no", required_unless_present = "no")]
+    pub yes: bool,
+    /// The package should have no `UpgradeCap`.
+    #[clap(long = "no")]
+    pub no: bool,
+}
+
+#[derive(Debug, clap::Parser)]
+pub struct AuthenticatorStateUpdateCommand {
+    /// The OIDC provider that published the JWK set in the body of the command, e.g. `Twitch`.
+    #[clap(long = "provider")]
+    pub provider: String,
+}
+
+#[derive(Debug, clap::Parser)]
+pub struct ExecuteBatchCommand {
+    /// The numbers of the tasks that built the transactions, with `--compute-digest-only`. They
+    /// must only use owned objects, and not any of the same ones.
+    #[clap(required = true)]
+    pub tasks: Vec<u64>,
+}
+
+#[derive(Debug, clap::Parser)]
+pub struct AssertEventFieldCommand {
+    /// The number of the task that built the transaction.
+    pub task: u64,
+    /// The position of the event among those the transaction emitted, starting from 0.
+    pub index: usize,
+    /// Dot separated field names, or vector indices, leading from the event to the value.
+    pub path: String,
+    #[clap(value_parser = parse_input_value)]
+    pub expected: ParsedValue<SuiExtraValueArgs>,
+}
+
+#[derive(Debug, clap::Parser)]
+pub struct AssertEventTypesCommand {
+    /// The number of the task that built the transaction.
+    pub task: u64,
+    /// The distinct types of the events the transaction should have emitted, in any order.
+    #[clap(value_parser = ParsedStructType::parse)]
+    pub event_types: Vec<ParsedStructType>,
+}
+
+#[derive(Debug, clap::Parser)]
+pub struct DumpStoreCommand {
+    /// Where to write the objects. Relative paths are resolved against the `--dump-dir` given in
+    /// init, and are rejected without one.
+    pub path: PathBuf,
+}
+
+#[derive(Debug, clap::Parser)]
+pub struct WriteCheckpointCommand {
+    /// Where to write the checkpoint. Relative paths are resolved against a temporary directory
+    /// that belongs to the test, so that tests do not write into the source tree or into each
+    /// other's files.
+    pub path: PathBuf,
+    /// The checkpoint to write. Defaults to the latest one.
+    #[clap(long = "sequence-number")]
+    pub sequence_number: Option<u64>,
+}
+
+#[derive(Debug, clap::Parser)]
+pub struct LoadCheckpointCommand {
+    /// A checkpoint written by `write-checkpoint`, resolved like its path. The store is rewound
+    /// to how it was before the checkpoint, and the checkpoint's transactions are executed again.
+    pub path: PathBuf,
+}
+
 #[derive(Debug, clap::Parser)]
 pub struct AdvanceClockCommand {
     #[clap(long = "duration-ns")]
@@ -140,10 +688,24 @@ pub struct AdvanceClockCommand {
 pub enum SuiSubcommand {
     #[clap(name = "view-object")]
     ViewObject(ViewObjectCommand),
+    #[clap(name = "view-linkage")]
+    ViewLinkage(ViewLinkageCommand),
+    #[clap(name = "view-storage-rebate")]
+    ViewStorageRebate(ViewStorageRebateCommand),
+    #[clap(name = "assert-package-digest")]
+    AssertPackageDigest(AssertPackageDigestCommand),
+    #[clap(name = "diff-object")]
+    DiffObject(DiffObjectCommand),
+    #[clap(name = "view-dynamic-field")]
+    ViewDynamicField(ViewDynamicFieldCommand),
     #[clap(name = "transfer-object")]
     TransferObject(TransferObjectCommand),
+    #[clap(name = "transfer-to-object")]
+    TransferToObject(TransferToObjectCommand),
     #[clap(name = "consensus-commit-prologue")]
     ConsensusCommitPrologue(ConsensusCommitPrologueCommand),
+    #[clap(name = "randomness-state-update")]
+    RandomnessStateUpdate(RandomnessStateUpdateCommand),
     #[clap(name = "programmable")]
     ProgrammableTransaction(ProgrammableTransactionCommand),
     #[clap(name = "upgrade")]
@@ -154,12 +716,100 @@ pub enum SuiSubcommand {
     SetAddress(SetAddressCommand),
     #[clap(name = "create-checkpoint")]
     CreateCheckpoint,
+    #[clap(name = "create-checkpoints")]
+    CreateCheckpoints(CreateCheckpointsCommand),
     #[clap(name = "advance-epoch")]
-    AdvanceEpoch,
+    AdvanceEpoch(AdvanceEpochCommand),
     #[clap(name = "advance-clock")]
     AdvanceClock(AdvanceClockCommand),
     #[clap(name = "view-checkpoint")]
     ViewCheckpoint,
+    #[clap(name = "assert-checkpoint-tx-count")]
+    AssertCheckpointTxCount(AssertCheckpointTxCountCommand),
+    #[clap(name = "query-events-by-type")]
+    QueryEventsByType(QueryEventsByTypeCommand),
+    #[clap(name = "execute-signed")]
+    ExecuteSigned(ExecuteSignedCommand),
+    #[clap(name = "list-objects")]
+    ListObjects(ListObjectsCommand),
+    #[clap(name = "create-shared-object")]
+    CreateSharedObject(CreateSharedObjectCommand),
+    #[clap(name = "view-validator")]
+    ViewValidator(ViewValidatorCommand),
+    #[clap(name = "set-gas-price-vote")]
+    SetGasPriceVote(SetGasPriceVoteCommand),
+    #[clap(name = "view-system-state")]
+    ViewSystemState,
+    #[clap(name = "view-tx")]
+    ViewTx(ViewTxCommand),
+    #[clap(name = "view-gas-price")]
+    ViewGasPrice(ViewGasPriceCommand),
+    #[clap(name = "view-protocol-config")]
+    ViewProtocolConfig(ViewProtocolConfigCommand),
+    #[clap(name = "replay-transaction")]
+    ReplayTransaction(ReplayTransactionCommand),
+    #[clap(name = "dump-store")]
+    DumpStore(DumpStoreCommand),
+    #[clap(name = "assert-total-supply")]
+    AssertTotalSupply(AssertTotalSupplyCommand),
+    #[clap(name = "assert-event-types")]
+    AssertEventTypes(AssertEventTypesCommand),
+    #[clap(name = "assert-created-count")]
+    AssertCreatedCount(AssertCreatedCountCommand),
+    #[clap(name = "assert-object-type")]
+    AssertObjectType(AssertObjectTypeCommand),
+    #[clap(name = "assert-upgradeable")]
+    AssertUpgradeable(AssertUpgradeableCommand),
+    #[clap(name = "execute-batch")]
+    ExecuteBatch(ExecuteBatchCommand),
+    #[clap(name = "authenticator-state-update")]
+    AuthenticatorStateUpdate(AuthenticatorStateUpdateCommand),
+    #[clap(name = "view-next-fake-id")]
+    ViewNextFakeId,
+    #[clap(name = "assert-event-field")]
+    AssertEventField(AssertEventFieldCommand),
+    #[clap(name = "write-checkpoint")]
+    WriteCheckpoint(WriteCheckpointCommand),
+    #[clap(name = "load-checkpoint")]
+    LoadCheckpoint(LoadCheckpointCommand),
+    #[clap(name = "assert-balance-change")]
+    AssertBalanceChange(AssertBalanceChangeCommand),
+    #[clap(name = "mint-coin")]
+    MintCoin(MintCoinCommand),
+    #[clap(name = "view-dynamic-field-changes")]
+    ViewDynamicFieldChanges(ViewDynamicFieldChangesCommand),
+    #[clap(name = "view-tx-dependencies")]
+    ViewTxDependencies(ViewTxDependenciesCommand),
+    #[clap(name = "assert-same-type-origin")]
+    AssertSameTypeOrigin(AssertSameTypeOriginCommand),
+    #[clap(name = "view-owner-chain")]
+    ViewOwnerChain(ViewOwnerChainCommand),
+    #[clap(name = "view-object-at-checkpoint")]
+    ViewObjectAtCheckpoint(ViewObjectAtCheckpointCommand),
+    #[clap(name = "assert-object-unchanged")]
+    AssertObjectUnchanged(AssertObjectUnchangedCommand),
+    #[clap(name = "assert-tx-epoch")]
+    AssertTxEpoch(AssertTxEpochCommand),
+    #[clap(name = "count-objects")]
+    CountObjects(CountObjectsCommand),
+    #[clap(name = "view-package-ids")]
+    ViewPackageIds(ViewPackageIdsCommand),
+    #[clap(name = "upgrade-protocol")]
+    UpgradeProtocol(UpgradeProtocolCommand),
+    #[clap(name = "expect-use-deleted")]
+    ExpectUseDeleted(ExpectUseDeletedCommand),
+    #[clap(name = "view-storage-accounting")]
+    ViewStorageAccounting(ViewStorageAccountingCommand),
+    #[clap(name = "assert-shared-version")]
+    AssertSharedVersion(AssertSharedVersionCommand),
+    #[clap(name = "assert-validator-count")]
+    AssertValidatorCount(AssertValidatorCountCommand),
+    #[clap(name = "remove-account")]
+    RemoveAccount(RemoveAccountCommand),
+    #[clap(name = "assert-dev-inspect-return")]
+    AssertDevInspectReturn(AssertDevInspectReturnCommand),
+    #[clap(name = "view-checkpoint-epoch-info")]
+    ViewCheckpointEpochInfo(ViewCheckpointEpochInfoCommand),
 }
 
 #[derive(Clone, Debug)]
@@ -167,6 +817,13 @@ pub enum SuiExtraValueArgs {
     Object(FakeID, Option<SequenceNumber>),
     Digest(String),
     Receiving(FakeID, Option<SequenceNumber>),
+    ObjectId(FakeID),
+    AddressId(ParsedAddress),
+    AddressBytes(ParsedAddress),
+    NamedStruct(
+        ParsedStructType,
+        Vec<(Identifier, ParsedValue<SuiExtraValueArgs>)>,
+    ),
 }
 
 pub enum SuiValue {
@@ -175,9 +832,23 @@ pub enum SuiValue {
     ObjVec(Vec<(FakeID, Option<SequenceNumber>)>),
     Digest(String),
     Receiving(FakeID, Option<SequenceNumber>),
+    ObjectId(FakeID),
+    NamedStruct(StructTag, Vec<(Identifier, SuiValue)>),
 }
 
 impl SuiExtraValueArgs {
+    /// Keywords understood by the inputs parser, either here or by the underlying Move value
+    /// parser.
+    const KEYWORDS: &[&str] = &[
+        "object",
+        "digest",
+        "receiving",
+        "id",
+        "address_bytes",
+        "vector",
+        "struct",
+    ];
+
     fn parse_object_value<'a, I: Iterator<Item = (ValueToken, &'a str)>>(
         parser: &mut MoveCLParser<'a, ValueToken, I>,
     ) -> anyhow::Result<Self> {
@@ -203,16 +874,85 @@ impl SuiExtraValueArgs {
         Ok(SuiExtraValueArgs::Digest(package.to_owned()))
     }
 
-    fn parse_receiving_or_object_value<'a, I: Iterator<Item = (ValueToken, &'a str)>>(
+    /// `id(i,j)` or `id(0x..)` is the `ID` of an existing object, `id(@addr)` wraps an arbitrary
+    /// address.
+    fn parse_id_value<'a, I: Iterator<Item = (ValueToken, &'a str)>>(
         parser: &mut MoveCLParser<'a, ValueToken, I>,
-        ident_name: &str,
-    ) -> anyhow::Result<(FakeID, Option<SequenceNumber>)> {
+    ) -> anyhow::Result<Self> {
         let contents = parser.advance(ValueToken::Ident)?;
-        ensure!(contents == ident_name);
+        ensure!(contents == "id");
+        parser.advance(ValueToken::LParen)?;
+        let value = if let Some(ValueToken::AtSign) = parser.peek_tok() {
+            parser.advance(ValueToken::AtSign)?;
+            SuiExtraValueArgs::AddressId(parser.parse_address()?)
+        } else {
+            SuiExtraValueArgs::ObjectId(Self::parse_fake_id_value(parser)?)
+        };
+        parser.advance(ValueToken::RParen)?;
+        Ok(value)
+    }
+
+    /// `address_bytes(0x..)` or `address_bytes(@addr)` is the 32-byte `vector<u8>` encoding of an
+    /// address, for functions that take raw address or digest bytes.
+    fn parse_address_bytes_value<'a, I: Iterator<Item = (ValueToken, &'a str)>>(
+        parser: &mut MoveCLParser<'a, ValueToken, I>,
+    ) -> anyhow::Result<Self> {
+        let contents = parser.advance(ValueToken::Ident)?;
+        ensure!(contents == "address_bytes");
         parser.advance(ValueToken::LParen)?;
+        if let Some(ValueToken::AtSign) = parser.peek_tok() {
+            parser.advance(ValueToken::AtSign)?;
+        }
+        let address = parser.parse_address()?;
+        parser.advance(ValueToken::RParen)?;
+        Ok(SuiExtraValueArgs::AddressBytes(address))
+    }
+
+    /// `addr::module::Name { field: value, ... }` is a struct given by field name. The fields and
+    /// their values are checked against the struct's declaration once its package can be looked
+    /// up, when the value becomes a transaction input.
+    ///
+    /// `addr` must be a named address: the Move value parser reads a leading number as a number.
+    /// Generic structs are not supported either, as there is no syntax for type arguments in
+    /// values. Both can still be given positionally, with `struct(...)`.
+    fn parse_named_struct_value<'a, I: Iterator<Item = (ValueToken, &'a str)>>(
+        parser: &mut MoveCLParser<'a, ValueToken, I>,
+    ) -> anyhow::Result<Self> {
+        let address = parser.advance(ValueToken::Ident)?;
+        if !matches!(parser.peek_tok(), Some(ValueToken::ColonColon)) {
+            return Err(Self::unknown_keyword(address));
+        }
+        parser.advance(ValueToken::ColonColon)?;
+        let module = parser.advance(ValueToken::Ident)?;
+        parser.advance(ValueToken::ColonColon)?;
+        let name = parser.advance(ValueToken::Ident)?;
+        parser.advance(ValueToken::LBrace)?;
+        let fields = parser.parse_list(
+            |parser| {
+                let field = Identifier::new(parser.advance(ValueToken::Ident)?)?;
+                parser.advance(ValueToken::Colon)?;
+                Ok((field, parser.parse_value::<SuiExtraValueArgs>()?))
+            },
+            ValueToken::Comma,
+            ValueToken::RBrace,
+            /* allow_trailing_delim */ true,
+        )?;
+        parser.advance(ValueToken::RBrace)?;
+        let ty = ParsedStructType {
+            address: ParsedAddress::Named(address.to_owned()),
+            module: module.to_owned(),
+            name: name.to_owned(),
+            type_args: vec![],
+        };
+        Ok(SuiExtraValueArgs::NamedStruct(ty, fields))
+    }
+
+    fn parse_fake_id_value<'a, I: Iterator<Item = (ValueToken, &'a str)>>(
+        parser: &mut MoveCLParser<'a, ValueToken, I>,
+    ) -> anyhow::Result<FakeID> {
         let i_str = parser.advance(ValueToken::Number)?;
         let (i, _) = parse_u256(i_str)?;
-        let fake_id = if let Some(ValueToken::Comma) = parser.peek_tok() {
+        Ok(if let Some(ValueToken::Comma) = parser.peek_tok() {
             parser.advance(ValueToken::Comma)?;
             let j_str = parser.advance(ValueToken::Number)?;
             let (j, _) = parse_u64(j_str)?;
@@ -225,7 +965,17 @@ impl SuiExtraValueArgs {
             u256_bytes.reverse();
             let address: SuiAddress = SuiAddress::from_bytes(&u256_bytes).unwrap();
             FakeID::Known(address.into())
-        };
+        })
+    }
+
+    fn parse_receiving_or_object_value<'a, I: Iterator<Item = (ValueToken, &'a str)>>(
+        parser: &mut MoveCLParser<'a, ValueToken, I>,
+        ident_name: &str,
+    ) -> anyhow::Result<(FakeID, Option<SequenceNumber>)> {
+        let contents = parser.advance(ValueToken::Ident)?;
+        ensure!(contents == ident_name);
+        parser.advance(ValueToken::LParen)?;
+        let fake_id = Self::parse_fake_id_value(parser)?;
         parser.advance(ValueToken::RParen)?;
         let version = if let Some(ValueToken::AtSign) = parser.peek_tok() {
             parser.advance(ValueToken::AtSign)?;
@@ -237,27 +987,205 @@ impl SuiExtraValueArgs {
         };
         Ok((fake_id, version))
     }
+
+    /// Identifiers are never valid values on their own, so an identifier that is neither a known
+    /// keyword nor the start of a struct type is a typo. Give a better error for it than the
+    /// generic token-level failure from the Move value parser, suggesting the closest keyword when
+    /// there is one.
+    fn unknown_keyword(ident: &str) -> anyhow::Error {
+        let suggestion = Self::KEYWORDS
+            .iter()
+            .map(|kw| (edit_distance(ident, kw), *kw))
+            .filter(|(d, kw)| *d <= kw.len() / 3 + 1)
+            .min();
+        match suggestion {
+            Some((_, kw)) => anyhow!("unknown input keyword '{ident}', did you mean '{kw}'?"),
+            None => anyhow!(
+                "unknown input keyword '{ident}'. Expected one of: {}",
+                Self::KEYWORDS.join(", ")
+            ),
+        }
+    }
+}
+
+/// How the type described by `layout` is written in Move
+fn layout_type_name(layout: &MoveTypeLayout) -> String {
+    match layout {
+        MoveTypeLayout::Vector(inner) => format!("vector<{}>", layout_type_name(inner)),
+        MoveTypeLayout::Struct(MoveStructLayout::WithTypes { type_, .. }) => type_.to_string(),
+        layout => layout.to_string(),
+    }
+}
+
+/// Whether `value` is a value of the type described by `layout`
+fn value_matches_layout(value: &MoveValue, layout: &MoveTypeLayout) -> bool {
+    match (value, layout) {
+        (MoveValue::Bool(_), MoveTypeLayout::Bool)
+        | (MoveValue::U8(_), MoveTypeLayout::U8)
+        | (MoveValue::U16(_), MoveTypeLayout::U16)
+        | (MoveValue::U32(_), MoveTypeLayout::U32)
+        | (MoveValue::U64(_), MoveTypeLayout::U64)
+        | (MoveValue::U128(_), MoveTypeLayout::U128)
+        | (MoveValue::U256(_), MoveTypeLayout::U256)
+        | (MoveValue::Address(_), MoveTypeLayout::Address)
+        | (MoveValue::Signer(_), MoveTypeLayout::Signer) => true,
+        (MoveValue::Vector(values), MoveTypeLayout::Vector(inner)) => {
+            values.iter().all(|v| value_matches_layout(v, inner))
+        }
+        (MoveValue::Struct(s), MoveTypeLayout::Struct(l)) => {
+            let values: Vec<_> = match s {
+                MoveStruct::Runtime(values) => values.iter().collect(),
+                MoveStruct::WithFields(fields) | MoveStruct::WithTypes { fields, .. } => {
+                    fields.iter().map(|(_, v)| v).collect()
+                }
+            };
+            let layouts: Vec<_> = match l {
+                MoveStructLayout::Runtime(layouts) => layouts.iter().collect(),
+                MoveStructLayout::WithFields(fields)
+                | MoveStructLayout::WithTypes { fields, .. } => {
+                    fields.iter().map(|f| &f.layout).collect()
+                }
+            };
+            values.len() == layouts.len()
+                && values
+                    .into_iter()
+                    .zip(layouts)
+                    .all(|(v, l)| value_matches_layout(v, l))
+        }
+        _ => false,
+    }
+}
+
+/// Levenshtein distance between two ASCII identifiers
+fn edit_distance(a: &str, b: &str) -> usize {
+    let b = b.as_bytes();
+    let mut prev: Vec<usize> = (0..=b.len()).collect();
+    for (i, ca) in a.bytes().enumerate() {
+        let mut cur = vec![i + 1; b.len() + 1];
+        for (j, cb) in b.iter().enumerate() {
+            let subst = prev[j] + usize::from(ca != *cb);
+            cur[j + 1] = subst.min(prev[j + 1] + 1).min(cur[j] + 1);
+        }
+        prev = cur;
+    }
+    prev[b.len()]
 }
 
 impl SuiValue {
-    fn assert_move_value(self) -> MoveValue {
-        match self {
+    fn nested_move_value(self) -> anyhow::Result<MoveValue> {
+        Ok(match self {
             SuiValue::MoveValue(v) => v,
-            SuiValue::Object(_, _) => panic!("unexpected nested Sui object in args"),
-            SuiValue::ObjVec(_) => panic!("unexpected nested Sui object vector in args"),
-            SuiValue::Digest(_) => panic!("unexpected nested Sui package digest in args"),
-            SuiValue::Receiving(_, _) => panic!("unexpected nested Sui receiving object in args"),
+            SuiValue::Object(_, _) => bail!("unexpected nested Sui object in args"),
+            SuiValue::ObjVec(_) => bail!(
+                "unexpected nested Sui object vector in args. \
+                Vectors of object vectors are not supported"
+            ),
+            SuiValue::Digest(_) => bail!("unexpected nested Sui package digest in args"),
+            SuiValue::Receiving(_, _) => bail!("unexpected nested Sui receiving object in args"),
+            SuiValue::ObjectId(_) => bail!("unexpected nested Sui object ID in args"),
+            SuiValue::NamedStruct(tag, _) => bail!(
+                "unexpected nested struct {tag} in args. \
+                Structs given by field name can only be nested in other such structs"
+            ),
+        })
+    }
+
+    /// The value of struct `tag` given by `fields`, which must name every field of the struct,
+    /// in declaration order, with a value of that field's type.
+    fn named_struct_value(
+        tag: StructTag,
+        fields: Vec<(Identifier, SuiValue)>,
+        test_adapter: &SuiTestAdapter,
+    ) -> anyhow::Result<MoveValue> {
+        let name = test_adapter.stabilize_str(tag.to_string());
+        let layout = MoveObject::get_layout_from_struct_tag(
+            tag,
+            ObjectFormatOptions::default(),
+            &test_adapter,
+        )
+        .map_err(|e| anyhow!("Unable to resolve struct {name}: {e}"))?;
+        let declared = match layout {
+            MoveStructLayout::WithTypes { fields, .. } | MoveStructLayout::WithFields(fields) => {
+                fields
+            }
+            MoveStructLayout::Runtime(_) => unreachable!("layouts are built with field names"),
+        };
+        let is_declared = |field: &Identifier| declared.iter().any(|d| &d.name == field);
+        for (i, expected) in declared.iter().map(|d| &d.name).enumerate() {
+            let Some((given, _)) = fields.get(i) else {
+                bail!("Missing field '{expected}' for struct {name}");
+            };
+            if given == expected {
+                continue;
+            }
+            if !is_declared(given) {
+                bail!("Struct {name} has no field '{given}'");
+            }
+            if !fields.iter().any(|(f, _)| f == expected) {
+                bail!("Missing field '{expected}' for struct {name}");
+            }
+            bail!("Field '{given}' of struct {name} is out of order, expected field '{expected}'");
+        }
+        if let Some((extra, _)) = fields.get(declared.len()) {
+            if is_declared(extra) {
+                bail!("Field '{extra}' of struct {name} is given more than once");
+            }
+            bail!("Struct {name} has no field '{extra}'");
+        }
+
+        let mut values = vec![];
+        for ((field, value), declared) in fields.into_iter().zip(&declared) {
+            let expected = test_adapter.stabilize_str(layout_type_name(&declared.layout));
+            let value = match value {
+                SuiValue::NamedStruct(tag, fields) => {
+                    if !matches!(
+                        &declared.layout,
+                        MoveTypeLayout::Struct(MoveStructLayout::WithTypes { type_, .. })
+                            if *type_ == tag
+                    ) {
+                        let given = test_adapter.stabilize_str(tag.to_string());
+                        bail!(
+                            "Field '{field}' of struct {name} has type {expected}, \
+                            but was given a {given}"
+                        );
+                    }
+                    Self::named_struct_value(tag, fields, test_adapter)?
+                }
+                value => {
+                    let value = value.nested_move_value()?;
+                    if !value_matches_layout(&value, &declared.layout) {
+                        let given = test_adapter.stabilize_str(value.to_string());
+                        bail!(
+                            "Field '{field}' of struct {name} has type {expected}, \
+                            but was given {given}"
+                        );
+                    }
+                    value
+                }
+            };
+            values.push(value);
         }
+        Ok(MoveValue::Struct(MoveStruct::Runtime(values)))
     }
 
-    fn assert_object(self) -> (FakeID, Option<SequenceNumber>) {
-        match self {
-            SuiValue::MoveValue(_) => panic!("unexpected nested non-object value in args"),
+    fn nested_object(self) -> anyhow::Result<(FakeID, Option<SequenceNumber>)> {
+        Ok(match self {
+            SuiValue::MoveValue(_) => {
+                bail!("unexpected nested non-object value in args. Cannot mix objects and values in a vector")
+            }
             SuiValue::Object(id, version) => (id, version),
-            SuiValue::ObjVec(_) => panic!("unexpected nested Sui object vector in args"),
-            SuiValue::Digest(_) => panic!("unexpected nested Sui package digest in args"),
-            SuiValue::Receiving(_, _) => panic!("unexpected nested Sui receiving object in args"),
-        }
+            SuiValue::ObjVec(_) => bail!(
+                "unexpected nested Sui object vector in args. \
+                Vectors of object vectors are not supported"
+            ),
+            SuiValue::Digest(_) => bail!("unexpected nested Sui package digest in args"),
+            SuiValue::Receiving(_, _) => bail!("unexpected nested Sui receiving object in args"),
+            SuiValue::ObjectId(_) => bail!("unexpected nested Sui object ID in args"),
+            SuiValue::NamedStruct(tag, _) => bail!(
+                "unexpected nested struct {tag} in args. \
+                Cannot mix objects and values in a vector"
+            ),
+        })
     }
 
     fn resolve_object(
@@ -269,6 +1197,9 @@ impl SuiValue {
             Some(id) => id,
             None => bail!("INVALID TEST. Unknown object, object({})", fake_id),
         };
+        if let (None, Some(task)) = (version, test_adapter.removed_by(&id)) {
+            bail!("object({fake_id}) was deleted or wrapped by task {task}");
+        }
         let obj_res = if let Some(v) = version {
             sui_types::storage::ObjectStore::get_object_by_key(&*test_adapter.executor, &id, v)
         } else {
@@ -286,7 +1217,37 @@ impl SuiValue {
         version: Option<SequenceNumber>,
         test_adapter: &SuiTestAdapter,
     ) -> anyhow::Result<ObjectArg> {
-        let obj = Self::resolve_object(fake_id, version, test_adapter)?;
+        let Some(version) = version else {
+            let Some(obj_ref) = test_adapter.get_object_ref(fake_id)? else {
+                bail!("INVALID TEST. Could not load object argument object({fake_id})");
+            };
+            return Ok(ObjectArg::Receiving(obj_ref));
+        };
+        let latest = Self::resolve_object(fake_id, None, test_adapter)?;
+
+        // A pinned version must be one that the object was sent to its parent at, so that the
+        // transaction fails (if at all) because of how the parent is used, not the test's setup.
+        let Ok(Some(obj)) = sui_types::storage::ObjectStore::get_object_by_key(
+            &*test_adapter.executor,
+            &latest.id(),
+            version,
+        ) else {
+            bail!(
+                "Receiving object version mismatch: object({fake_id}) has no version {version}, \
+                 its latest version is {}",
+                latest.version(),
+            );
+        };
+        let parent = match obj.owner {
+            Owner::AddressOwner(owner) => test_adapter.real_to_fake_object_id(&owner.into()),
+            _ => None,
+        };
+        if parent.is_none() {
+            bail!(
+                "Receiving object version mismatch: object({fake_id}) was not owned by an object \
+                 at version {version}"
+            );
+        }
         Ok(ObjectArg::Receiving(obj.compute_object_reference()))
     }
 
@@ -321,6 +1282,10 @@ impl SuiValue {
             SuiValue::Receiving(fake_id, version) => {
                 CallArg::Object(Self::receiving_arg(fake_id, version, test_adapter)?)
             }
+            SuiValue::ObjectId(fake_id) => {
+                let obj = Self::resolve_object(fake_id, None, test_adapter)?;
+                CallArg::Pure(bcs::to_bytes(&obj.id()).unwrap())
+            }
             SuiValue::ObjVec(_) => bail!("obj vec is not supported as an input"),
             SuiValue::Digest(pkg) => {
                 let pkg = Symbol::from(pkg);
@@ -329,6 +1294,11 @@ impl SuiValue {
                 };
                 CallArg::Pure(bcs::to_bytes(&staged.digest).unwrap())
             }
+            SuiValue::NamedStruct(tag, fields) => CallArg::Pure(
+                Self::named_struct_value(tag, fields, test_adapter)?
+                    .simple_serialize()
+                    .unwrap(),
+            ),
         })
     }
 
@@ -361,6 +1331,11 @@ impl ParsableValue for SuiExtraValueArgs {
             (ValueToken::Ident, "object") => Some(Self::parse_object_value(parser)),
             (ValueToken::Ident, "digest") => Some(Self::parse_digest_value(parser)),
             (ValueToken::Ident, "receiving") => Some(Self::parse_receiving_value(parser)),
+            (ValueToken::Ident, "id") => Some(Self::parse_id_value(parser)),
+            (ValueToken::Ident, "address_bytes") => Some(Self::parse_address_bytes_value(parser)),
+            (ValueToken::Ident, ident) if !Self::KEYWORDS.contains(&ident) => {
+                Some(Self::parse_named_struct_value(parser))
+            }
             _ => None,
         }
     }
@@ -372,29 +1347,61 @@ impl ParsableValue for SuiExtraValueArgs {
     fn concrete_vector(elems: Vec<Self::ConcreteValue>) -> anyhow::Result<Self::ConcreteValue> {
         if !elems.is_empty() && matches!(elems[0], SuiValue::Object(_, _)) {
             Ok(SuiValue::ObjVec(
-                elems.into_iter().map(SuiValue::assert_object).collect(),
+                elems
+                    .into_iter()
+                    .map(SuiValue::nested_object)
+                    .collect::<anyhow::Result<_>>()?,
             ))
         } else {
+            // elements may themselves be vectors, e.g. `vector[vector[1u8], vector[]]`, in which
+            // case they have already been concretized into `MoveValue::Vector`s
             Ok(SuiValue::MoveValue(MoveValue::Vector(
-                elems.into_iter().map(SuiValue::assert_move_value).collect(),
+                elems
+                    .into_iter()
+                    .map(SuiValue::nested_move_value)
+                    .collect::<anyhow::Result<_>>()?,
             )))
         }
     }
 
     fn concrete_struct(values: Vec<Self::ConcreteValue>) -> anyhow::Result<Self::ConcreteValue> {
         Ok(SuiValue::MoveValue(MoveValue::Struct(MoveStruct::Runtime(
-            values.into_iter().map(|v| v.assert_move_value()).collect(),
+            values
+                .into_iter()
+                .map(SuiValue::nested_move_value)
+                .collect::<anyhow::Result<_>>()?,
         ))))
     }
 
     fn into_concrete_value(
         self,
-        _mapping: &impl Fn(&str) -> Option<move_core_types::account_address::AccountAddress>,
+        mapping: &impl Fn(&str) -> Option<move_core_types::account_address::AccountAddress>,
     ) -> anyhow::Result<Self::ConcreteValue> {
         match self {
             SuiExtraValueArgs::Object(id, version) => Ok(SuiValue::Object(id, version)),
             SuiExtraValueArgs::Digest(pkg) => Ok(SuiValue::Digest(pkg)),
             SuiExtraValueArgs::Receiving(id, version) => Ok(SuiValue::Receiving(id, version)),
+            SuiExtraValueArgs::ObjectId(id) => Ok(SuiValue::ObjectId(id)),
+            SuiExtraValueArgs::AddressId(addr) => {
+                // `ID` is a struct wrapping a single address
+                let addr = addr.into_account_address(mapping)?;
+                Ok(SuiValue::MoveValue(MoveValue::Struct(MoveStruct::Runtime(
+                    vec![MoveValue::Address(addr)],
+                ))))
+            }
+            SuiExtraValueArgs::AddressBytes(addr) => {
+                let addr = addr.into_account_address(mapping)?;
+                Ok(SuiValue::MoveValue(MoveValue::Vector(
+                    addr.into_bytes().into_iter().map(MoveValue::U8).collect(),
+                )))
+            }
+            SuiExtraValueArgs::NamedStruct(ty, fields) => Ok(SuiValue::NamedStruct(
+                ty.into_struct_tag(mapping)?,
+                fields
+                    .into_iter()
+                    .map(|(field, value)| Ok((field, value.into_concrete_value(mapping)?)))
+                    .collect::<anyhow::Result<_>>()?,
+            )),
         }
     }
 }
@@ -413,6 +1420,87 @@ fn parse_fake_id(s: &str) -> anyhow::Result<FakeID> {
     })
 }
 
+/// Parse a value, like `ParsedValue::parse`, but first reject negative number literals: Move has no
+/// signed integers, and the Move value parser only reports them as an unrecognized token.
+fn parse_input_value(s: &str) -> anyhow::Result<ParsedValue<SuiExtraValueArgs>> {
+    let mut in_string = false;
+    let mut prev = None;
+    for (i, c) in s.char_indices() {
+        match c {
+            '"' => in_string = !in_string,
+            '-' if !in_string
+                && !matches!(prev, Some(p) if char::is_ascii_alphanumeric(&p) || p == '_')
+                && matches!(s[i + 1..].chars().next(), Some(d) if d.is_ascii_digit()) =>
+            {
+                // Report the whole literal, suffix included, rather than the rest of the input
+                let len = 1 + s[i + 1..]
+                    .chars()
+                    .take_while(|c| c.is_ascii_alphanumeric() || *c == '_')
+                    .count();
+                bail!(
+                    "Move has no signed integers; use the unsigned representation. Got: {}",
+                    &s[i..i + len]
+                )
+            }
+            _ => (),
+        }
+        prev = Some(c);
+    }
+    ParsedValue::<SuiExtraValueArgs>::parse(s)
+}
+
+fn parse_multisig(s: &str) -> anyhow::Result<MultisigArg> {
+    let mut parts = s.splitn(3, ':');
+    let (Some(name), Some(threshold), Some(members)) = (parts.next(), parts.next(), parts.next())
+    else {
+        bail!("Invalid multisig account '{s}'. Expected NAME:THRESHOLD:MEMBER[:WEIGHT],...")
+    };
+    let threshold = threshold
+        .parse()
+        .map_err(|e| anyhow!("Invalid threshold for multisig account '{name}': {e}"))?;
+    let members = members
+        .split(',')
+        .map(|member| match member.split_once(':') {
+            Some((member, weight)) => {
+                let weight = weight
+                    .parse()
+                    .map_err(|e| anyhow!("Invalid weight for member '{member}': {e}"))?;
+                Ok((member.to_owned(), weight))
+            }
+            None => Ok((member.to_owned(), 1)),
+        })
+        .collect::<anyhow::Result<_>>()?;
+    Ok(MultisigArg {
+        name: name.to_owned(),
+        threshold,
+        members,
+    })
+}
+
+fn parse_imported_account(s: &str) -> anyhow::Result<ImportedAccountArg> {
+    let Some((name, key)) = s.split_once(':') else {
+        bail!("Invalid imported account '{s}'. Expected NAME:KEY")
+    };
+    let mut bytes = match key.strip_prefix("0x") {
+        Some(hex) => Hex::decode(hex),
+        None => Base64::decode(key),
+    }
+    .map_err(|e| anyhow!("Invalid private key for imported account '{name}': {e}"))?;
+    if bytes.len() == AccountPrivateKey::LENGTH + 1 {
+        ensure!(
+            bytes[0] == SignatureScheme::ED25519.flag(),
+            "Imported account '{name}' must have an Ed25519 key"
+        );
+        bytes.remove(0);
+    }
+    AccountPrivateKey::from_bytes(&bytes)
+        .map_err(|e| anyhow!("Invalid private key for imported account '{name}': {e}"))?;
+    Ok(ImportedAccountArg {
+        name: name.to_owned(),
+        private_key: bytes,
+    })
+}
+
 fn parse_policy(x: &str) -> anyhow::Result<u8> {
     Ok(match x {
             "compatible" => UpgradePolicy::COMPATIBLE,
@@ -421,3 +1509,250 @@ fn parse_policy(x: &str) -> anyhow::Result<u8> {
         _ => bail!("Invalid upgrade policy {x}. Policy must be one of 'compatible', 'additive', or 'dep_only'")
     })
 }
+
+#[cfg(test)]
+mod tests {
+    use std::str::FromStr;
+
+    use shared_crypto::intent::{Intent, IntentMessage, PersonalMessage};
+    use sui_types::crypto::{KeypairTraits, Signature};
+
+    use super::*;
+
+    fn parse_input(s: &str) -> anyhow::Result<ParsedValue<SuiExtraValueArgs>> {
+        parse_input_value(s)
+    }
+
+    #[test]
+    fn near_miss_keyword_suggests_fix() {
+        let err = parse_input("objct(1)").unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "unknown input keyword 'objct', did you mean 'object'?"
+        );
+        let err = parse_input("recieving(1,0)").unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "unknown input keyword 'recieving', did you mean 'receiving'?"
+        );
+        let err = parse_input("vectr[1, 2]").unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "unknown input keyword 'vectr', did you mean 'vector'?"
+        );
+    }
+
+    #[test]
+    fn negative_numbers_are_rejected() {
+        let err = parse_input("-1").unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Move has no signed integers; use the unsigned representation. Got: -1"
+        );
+        let err = parse_input("vector[1, -20u64]").unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Move has no signed integers; use the unsigned representation. Got: -20u64"
+        );
+        // A minus inside a string is not a number
+        assert!(parse_input("b\"a-1\"").is_ok());
+    }
+
+    #[test]
+    fn unrelated_identifier_is_rejected() {
+        let err = parse_input("banana(1)").unwrap_err();
+        assert!(err
+            .to_string()
+            .starts_with("unknown input keyword 'banana'. Expected one of:"));
+    }
+
+    #[test]
+    fn known_keywords_still_parse() {
+        assert!(matches!(
+            parse_input("object(1,2)").unwrap(),
+            ParsedValue::Custom(SuiExtraValueArgs::Object(FakeID::Enumerated(1, 2), None))
+        ));
+        assert!(matches!(
+            parse_input("vector[1u8]").unwrap(),
+            ParsedValue::Vector(_)
+        ));
+        assert!(matches!(
+            parse_input("true").unwrap(),
+            ParsedValue::Bool(true)
+        ));
+    }
+
+    #[test]
+    fn named_struct_parses_fields_in_order() {
+        let ParsedValue::Custom(SuiExtraValueArgs::NamedStruct(ty, fields)) =
+            parse_input("test::m::S { a: 1u64, b: test::m::T { c: true }, }").unwrap()
+        else {
+            panic!("expected a named struct");
+        };
+        assert_eq!(ty.address, ParsedAddress::Named("test".to_owned()));
+        assert_eq!((ty.module.as_str(), ty.name.as_str()), ("m", "S"));
+        let names: Vec<_> = fields.iter().map(|(f, _)| f.as_str()).collect();
+        assert_eq!(names, ["a", "b"]);
+        assert!(matches!(
+            &fields[1].1,
+            ParsedValue::Custom(SuiExtraValueArgs::NamedStruct(..))
+        ));
+        // a struct needs its module and name
+        let err = parse_input("banana { a: 1 }").unwrap_err();
+        assert!(err
+            .to_string()
+            .starts_with("unknown input keyword 'banana'. Expected one of:"));
+        assert!(parse_input("test::m::S { a 1u64 }").is_err());
+    }
+
+    #[test]
+    fn dev_inspect_function_parses_type_arguments() {
+        let call = ParsedMoveCall::parse_function("test::m::id<vector<u8>>").unwrap();
+        assert_eq!((call.package.as_str(), call.module.as_str()), ("test", "m"));
+        assert_eq!(call.function.as_str(), "id");
+        assert_eq!(call.type_arguments.len(), 1);
+        assert!(call.arguments.is_empty());
+        // Only the function is named, it is not called
+        assert!(ParsedMoveCall::parse_function("test::m::id(Input(0))").is_err());
+        assert!(ParsedMoveCall::parse_function("test::m").is_err());
+    }
+
+    fn pure_bytes(s: &str) -> anyhow::Result<Vec<u8>> {
+        match parse_input(s)?.into_concrete_value(&|_| None)? {
+            SuiValue::MoveValue(v) => Ok(v.simple_serialize().unwrap()),
+            _ => panic!("expected a pure value for {s}"),
+        }
+    }
+
+    #[test]
+    fn wide_integer_literals_use_suffix_width() {
+        assert_eq!(
+            pure_bytes("340u128").unwrap(),
+            bcs::to_bytes(&340u128).unwrap()
+        );
+        assert_eq!(
+            pure_bytes("340282366920938463463374607431768211455u128").unwrap(),
+            bcs::to_bytes(&u128::MAX).unwrap()
+        );
+        assert_eq!(
+            pure_bytes("5u256").unwrap(),
+            bcs::to_bytes(&U256::from(5u64)).unwrap()
+        );
+        assert_eq!(pure_bytes("5u256").unwrap().len(), 32);
+    }
+
+    #[test]
+    fn wide_integer_literals_reject_overflow() {
+        assert!(parse_input("340282366920938463463374607431768211456u128").is_err());
+        assert!(parse_input(
+            "115792089237316195423570985008687907853269984665640564039457584007913129639936u256"
+        )
+        .is_err());
+    }
+
+    #[test]
+    fn multisig_accounts_parse_weights_and_threshold() {
+        let multisig = parse_multisig("M:3:A:2,B,C").unwrap();
+        assert_eq!(multisig.name, "M");
+        assert_eq!(multisig.threshold, 3);
+        assert_eq!(
+            multisig.members,
+            vec![
+                ("A".to_owned(), 2),
+                ("B".to_owned(), 1),
+                ("C".to_owned(), 1)
+            ]
+        );
+        assert!(parse_multisig("M:2").is_err());
+        assert!(parse_multisig("M:two:A,B").is_err());
+        assert!(parse_multisig("M:2:A:heavy,B").is_err());
+    }
+
+    #[test]
+    fn imported_account_matches_fixture() {
+        // Fixture generated with an independent Ed25519 implementation.
+        const KEY_HEX: &str = "0x9f7a1c2e4b6d8a0f3e5c7b9d1a2c4e6f8b0d2a4c6e8f0b1d3a5c7e9f1b3d5a7c";
+        const KEY_BASE64: &str = "n3ocLkttig8+XHudGixOb4sNKkxujwsdOlx+nxs9Wnw=";
+        const KEYSTORE_BASE64: &str = "AJ96HC5LbYoPPlx7nRosTm+LDSpMbo8LHTpcfp8bPVp8";
+        const ADDRESS: &str = "0x8a7c94b5aa3db1110ace45f7a12c702f1e9c23df392ae1be173f2467efb78534";
+        const SIGNATURE: &str = "ACk7ZimwDIxV4wODyQBeScJ6YCN9SOi5qQ2/Xr4zBS/nPgKmLuwwzXd3Ih6SHDE0IHcIR5QgXJEE0ZuHPQqdRgNf8/N7DC2r+Cc8zUkB0IMmCiz5tLiLn/m0IPfeM+CKRg==";
+
+        for key in [KEY_HEX, KEY_BASE64, KEYSTORE_BASE64] {
+            let imported = parse_imported_account(&format!("K:{key}")).unwrap();
+            assert_eq!(imported.name, "K");
+            let key_pair = imported.key_pair();
+            assert_eq!(
+                SuiAddress::from(key_pair.public()),
+                SuiAddress::from_str(ADDRESS).unwrap()
+            );
+
+            let message = IntentMessage::new(
+                Intent::personal_message(),
+                PersonalMessage {
+                    message: b"fixture".to_vec(),
+                },
+            );
+            let signature = Signature::new_secure(&message, &key_pair);
+            assert_eq!(Base64::encode(signature.as_ref()), SIGNATURE);
+        }
+
+        assert!(parse_imported_account("K").is_err());
+        assert!(parse_imported_account("K:0x9f7a").is_err());
+        // Only Ed25519 keys are supported
+        assert!(parse_imported_account("K:AZ96HC5LbYoPPlx7nRosTm+LDSpMbo8LHTpcfp8bPVp8").is_err());
+    }
+
+    #[test]
+    fn id_keyword_parses_objects_and_addresses() {
+        assert!(matches!(
+            parse_input("id(1,2)").unwrap(),
+            ParsedValue::Custom(SuiExtraValueArgs::ObjectId(FakeID::Enumerated(1, 2)))
+        ));
+        let mut expected = vec![0u8; 32];
+        expected[31] = 2;
+        assert_eq!(pure_bytes("id(@0x2)").unwrap(), expected);
+        assert!(parse_input("id(@0x2").is_err());
+    }
+
+    #[test]
+    fn address_bytes_keyword_encodes_32_byte_vector() {
+        let mut expected = vec![0u8; 32];
+        expected[30] = 0xab;
+        expected[31] = 0xcd;
+        let expected = bcs::to_bytes(&expected).unwrap();
+        assert_eq!(pure_bytes("address_bytes(0xabcd)").unwrap(), expected);
+        assert_eq!(pure_bytes("address_bytes(@0xabcd)").unwrap(), expected);
+        // one byte too many
+        assert!(parse_input(&format!("address_bytes(0x{})", "ff".repeat(33))).is_err());
+        assert!(parse_input("address_bytes(0x2").is_err());
+    }
+
+    #[test]
+    fn nested_vectors_encode_as_nested_bcs() {
+        assert_eq!(
+            pure_bytes("vector[vector[1u8, 2u8], vector[], vector[3u8]]").unwrap(),
+            bcs::to_bytes(&vec![vec![1u8, 2], vec![], vec![3]]).unwrap()
+        );
+        assert_eq!(
+            pure_bytes("vector[vector[vector[7u64]], vector[]]").unwrap(),
+            bcs::to_bytes(&vec![vec![vec![7u64]], vec![]]).unwrap()
+        );
+        assert_eq!(
+            pure_bytes("vector[x\"0102\", vector[3u8]]").unwrap(),
+            bcs::to_bytes(&vec![vec![1u8, 2], vec![3]]).unwrap()
+        );
+    }
+
+    #[test]
+    fn nested_object_vectors_are_rejected() {
+        let concretize = |s: &str| parse_input(s).unwrap().into_concrete_value(&|_| None);
+        let err = concretize("vector[vector[object(1,0)], vector[object(1,1)]]").unwrap_err();
+        assert!(err
+            .to_string()
+            .contains("Vectors of object vectors are not supported"));
+        let err = concretize("vector[object(1,0), 1u8]").unwrap_err();
+        assert!(err
+            .to_string()
+            .contains("Cannot mix objects and values in a vector"));
+    }
+}
@@ -7,15 +7,20 @@ pub mod args;
 pub mod programmable_transaction_test_parser;
 pub mod test_adapter;
 
+use fastcrypto_zkp::bn254::zk_login::{JwkId, JWK};
+use move_core_types::language_storage::StructTag;
 use move_transactional_test_runner::framework::run_test_impl;
 use rand::rngs::StdRng;
 use simulacrum::Simulacrum;
+use std::future::Future;
 use std::path::Path;
+use sui_protocol_config::ProtocolVersion;
 use sui_rest_api::node_state_getter::NodeStateGetter;
+use sui_types::authenticator_state::ActiveJwk;
 use sui_types::digests::TransactionDigest;
 use sui_types::digests::TransactionEventsDigest;
 use sui_types::effects::TransactionEvents;
-use sui_types::event::Event;
+use sui_types::event::{Event, EventID};
 use sui_types::messages_checkpoint::CheckpointContentsDigest;
 use sui_types::storage::ObjectKey;
 use sui_types::storage::ObjectStore;
@@ -30,13 +35,18 @@ use sui_storage::key_value_store::TransactionKeyValueStore;
 use sui_types::base_types::ObjectID;
 use sui_types::base_types::SuiAddress;
 use sui_types::base_types::VersionNumber;
+use sui_types::crypto::SuiKeyPair;
 use sui_types::effects::TransactionEffects;
 use sui_types::error::ExecutionError;
 use sui_types::error::SuiError;
 use sui_types::error::SuiResult;
+use sui_types::error::UserInputError;
 use sui_types::messages_checkpoint::VerifiedCheckpoint;
 use sui_types::object::Object;
+use sui_types::sui_system_state::get_sui_system_state;
+use sui_types::sui_system_state::SuiSystemState;
 use sui_types::transaction::Transaction;
+use sui_types::transaction::TransactionData;
 use sui_types::transaction::TransactionDataAPI;
 use sui_types::transaction::TransactionKind;
 
@@ -51,6 +61,9 @@ pub struct ValidatorWithFullnode {
     pub validator: Arc<AuthorityState>,
     pub fullnode: Arc<AuthorityState>,
     pub kv_store: Arc<TransactionKeyValueStore>,
+    /// How many times a shared object transaction is resubmitted after a transient failure to
+    /// sequence it. Deterministic failures are never retried.
+    pub shared_object_retries: u32,
 }
 
 #[allow(unused_variables)]
@@ -62,8 +75,19 @@ pub trait TransactionalAdapter: Send + Sync + ObjectStore + NodeStateGetter {
         transaction: Transaction,
     ) -> anyhow::Result<(TransactionEffects, Option<ExecutionError>)>;
 
+    /// Submit `transactions`, which must only use owned objects and be independent of one another,
+    /// all at once, and return their effects in the same order. Each is certified and executed
+    /// without going through consensus.
+    async fn execute_owned_txns_concurrently(
+        &mut self,
+        transactions: Vec<Transaction>,
+    ) -> anyhow::Result<Vec<(TransactionEffects, Option<ExecutionError>)>>;
+
     async fn create_checkpoint(&mut self) -> anyhow::Result<VerifiedCheckpoint>;
 
+    /// The number of transactions executed since the latest checkpoint was created.
+    fn pending_checkpoint_tx_count(&self) -> usize;
+
     async fn advance_clock(
         &mut self,
         duration: std::time::Duration,
@@ -71,24 +95,100 @@ pub trait TransactionalAdapter: Send + Sync + ObjectStore + NodeStateGetter {
 
     async fn advance_epoch(&mut self) -> anyhow::Result<()>;
 
+    /// Advance to the next epoch, which runs under protocol version `version`.
+    async fn upgrade_protocol_version(&mut self, version: ProtocolVersion) -> anyhow::Result<()>;
+
     async fn request_gas(
         &mut self,
         address: SuiAddress,
         amount: u64,
     ) -> anyhow::Result<TransactionEffects>;
 
+    /// Write `object` straight into the store, without executing a transaction.
+    async fn insert_object(&mut self, object: Object) -> anyhow::Result<()>;
+
+    /// Remove the object with id `id` from the live objects, without executing a transaction.
+    async fn remove_object(&mut self, id: ObjectID) -> anyhow::Result<()>;
+
+    /// The current state of the Sui system object.
+    fn get_system_state(&self) -> SuiResult<SuiSystemState>;
+
+    /// Accept zkLogin signatures whose proofs were issued against `jwk`.
+    fn insert_jwk(&mut self, jwk_id: JwkId, jwk: JWK);
+
+    /// Replace the JWKs in the authenticator state with a system transaction.
+    async fn update_authenticator_state(
+        &mut self,
+        jwks: Vec<ActiveJwk>,
+    ) -> anyhow::Result<TransactionEffects>;
+
+    /// Write every object in the store to `path`, returning the number of objects written.
+    fn dump_objects(&self, path: &Path) -> anyhow::Result<usize>;
+
+    /// The latest version of every object in the store that has not been deleted or wrapped.
+    fn live_objects(&self) -> Vec<Object>;
+
+    /// The key of the account belonging to the validator at `address`, if it is known.
+    fn validator_account_key(&self, address: &SuiAddress) -> Option<SuiKeyPair>;
+
+    /// Inspect `transaction_kind` without committing it, paying for gas with `gas_object` if one
+    /// is given, and with a fresh coin otherwise.
     async fn dev_inspect_transaction_block(
         &self,
         sender: SuiAddress,
         transaction_kind: TransactionKind,
         gas_price: Option<u64>,
+        gas_object: Option<ObjectID>,
     ) -> SuiResult<DevInspectResults>;
 
+    /// Execute `transaction` without committing its effects, to learn what it would cost.
+    async fn dry_run_transaction_block(
+        &self,
+        transaction: TransactionData,
+    ) -> SuiResult<TransactionEffects>;
+
     async fn query_tx_events_asc(
         &self,
         tx_digest: &TransactionDigest,
         limit: usize,
     ) -> SuiResult<Vec<Event>>;
+
+    /// Events of type `event_type` emitted by any transaction, oldest first, along with the
+    /// transaction that emitted each and its sequence number within that transaction.
+    async fn query_events_by_type_asc(
+        &self,
+        event_type: &StructTag,
+        limit: usize,
+    ) -> SuiResult<Vec<(EventID, Event)>>;
+
+    /// Up to `limit` objects owned by `owner`, ordered by object ID, starting after `cursor`.
+    async fn query_objects_by_owner(
+        &self,
+        owner: SuiAddress,
+        cursor: Option<ObjectID>,
+        limit: usize,
+    ) -> SuiResult<Vec<Object>>;
+}
+
+/// Whether `error` is a transient failure to get a transaction sequenced (e.g. consensus being
+/// overloaded), which may succeed if resubmitted, as opposed to a deterministic failure.
+fn is_transient_conflict(error: &SuiError) -> bool {
+    error.is_overload() || matches!(error, SuiError::ValidatorHaltedAtEpochEnd)
+}
+
+/// Run `f`, running it again up to `max_retries` times while it fails transiently.
+async fn retry_transient<T, F, Fut>(max_retries: u32, mut f: F) -> SuiResult<T>
+where
+    F: FnMut() -> Fut,
+    Fut: Future<Output = SuiResult<T>>,
+{
+    let mut attempt = 0;
+    loop {
+        match f().await {
+            Err(e) if attempt < max_retries && is_transient_conflict(&e) => attempt += 1,
+            result => return result,
+        }
+    }
 }
 
 #[async_trait::async_trait]
@@ -97,32 +197,80 @@ impl TransactionalAdapter for ValidatorWithFullnode {
         &mut self,
         transaction: Transaction,
     ) -> anyhow::Result<(TransactionEffects, Option<ExecutionError>)> {
+        // Certifying a transaction assumes its signatures are valid, so reject transactions that
+        // were signed incorrectly up front rather than panicking.
+        self.validator.verify_transaction(transaction.clone())?;
         let with_shared = transaction
             .data()
             .intent_message()
             .value
             .contains_shared_object();
-        let (_, effects, execution_error) = send_and_confirm_transaction_with_execution_error(
-            &self.validator,
-            Some(&self.fullnode),
-            transaction,
-            with_shared,
-        )
+        let retries = if with_shared {
+            self.shared_object_retries
+        } else {
+            0
+        };
+        let (_, effects, execution_error) = retry_transient(retries, || {
+            send_and_confirm_transaction_with_execution_error(
+                &self.validator,
+                Some(&self.fullnode),
+                transaction.clone(),
+                with_shared,
+            )
+        })
         .await?;
         Ok((effects.into_data(), execution_error))
     }
 
+    async fn execute_owned_txns_concurrently(
+        &mut self,
+        transactions: Vec<Transaction>,
+    ) -> anyhow::Result<Vec<(TransactionEffects, Option<ExecutionError>)>> {
+        for transaction in &transactions {
+            self.validator.verify_transaction(transaction.clone())?;
+        }
+        let results = futures::future::join_all(transactions.into_iter().map(|transaction| {
+            send_and_confirm_transaction_with_execution_error(
+                &self.validator,
+                Some(&self.fullnode),
+                transaction,
+                /* with_shared */ false,
+            )
+        }))
+        .await;
+        results
+            .into_iter()
+            .map(|result| {
+                let (_, effects, execution_error) = result?;
+                Ok((effects.into_data(), execution_error))
+            })
+            .collect()
+    }
+
     async fn dev_inspect_transaction_block(
         &self,
         sender: SuiAddress,
         transaction_kind: TransactionKind,
         gas_price: Option<u64>,
+        gas_object: Option<ObjectID>,
     ) -> SuiResult<DevInspectResults> {
         self.fullnode
-            .dev_inspect_transaction_block(sender, transaction_kind, gas_price)
+            .dev_inspect_transaction_block(sender, transaction_kind, gas_price, gas_object)
             .await
     }
 
+    async fn dry_run_transaction_block(
+        &self,
+        transaction: TransactionData,
+    ) -> SuiResult<TransactionEffects> {
+        let digest = transaction.digest();
+        let (_, _, effects, _) = self
+            .fullnode
+            .dry_exec_transaction(transaction, digest)
+            .await?;
+        Ok(effects)
+    }
+
     async fn query_tx_events_asc(
         &self,
         tx_digest: &TransactionDigest,
@@ -144,10 +292,58 @@ impl TransactionalAdapter for ValidatorWithFullnode {
             .collect())
     }
 
+    async fn query_events_by_type_asc(
+        &self,
+        event_type: &StructTag,
+        limit: usize,
+    ) -> SuiResult<Vec<(EventID, Event)>> {
+        Ok(self
+            .validator
+            .query_events(
+                &self.kv_store,
+                EventFilter::MoveEventType(event_type.clone()),
+                None,
+                limit,
+                false,
+            )
+            .await?
+            .into_iter()
+            .take(limit)
+            .map(|sui_event| (sui_event.id.clone(), sui_event.into()))
+            .collect())
+    }
+
+    async fn query_objects_by_owner(
+        &self,
+        owner: SuiAddress,
+        cursor: Option<ObjectID>,
+        limit: usize,
+    ) -> SuiResult<Vec<Object>> {
+        self.validator
+            .get_owner_objects(owner, cursor, limit, None)?
+            .into_iter()
+            .map(|info| {
+                self.validator
+                    .get_object_by_key(&info.object_id, info.version)?
+                    .ok_or_else(|| {
+                        UserInputError::ObjectNotFound {
+                            object_id: info.object_id,
+                            version: Some(info.version),
+                        }
+                        .into()
+                    })
+            })
+            .collect()
+    }
+
     async fn create_checkpoint(&mut self) -> anyhow::Result<VerifiedCheckpoint> {
         unimplemented!("create_checkpoint not supported")
     }
 
+    fn pending_checkpoint_tx_count(&self) -> usize {
+        unimplemented!("pending_checkpoint_tx_count not supported")
+    }
+
     async fn advance_clock(
         &mut self,
         _duration: std::time::Duration,
@@ -159,6 +355,10 @@ impl TransactionalAdapter for ValidatorWithFullnode {
         unimplemented!("advance_epoch not supported")
     }
 
+    async fn upgrade_protocol_version(&mut self, _version: ProtocolVersion) -> anyhow::Result<()> {
+        unimplemented!("upgrade_protocol_version not supported")
+    }
+
     async fn request_gas(
         &mut self,
         _address: SuiAddress,
@@ -166,6 +366,41 @@ impl TransactionalAdapter for ValidatorWithFullnode {
     ) -> anyhow::Result<TransactionEffects> {
         unimplemented!("request_gas not supported")
     }
+
+    async fn insert_object(&mut self, _object: Object) -> anyhow::Result<()> {
+        unimplemented!("insert_object not supported")
+    }
+
+    async fn remove_object(&mut self, _id: ObjectID) -> anyhow::Result<()> {
+        unimplemented!("remove_object not supported")
+    }
+
+    fn get_system_state(&self) -> SuiResult<SuiSystemState> {
+        get_sui_system_state(self)
+    }
+
+    fn insert_jwk(&mut self, _jwk_id: JwkId, _jwk: JWK) {
+        unimplemented!("insert_jwk not supported")
+    }
+
+    async fn update_authenticator_state(
+        &mut self,
+        _jwks: Vec<ActiveJwk>,
+    ) -> anyhow::Result<TransactionEffects> {
+        unimplemented!("update_authenticator_state not supported")
+    }
+
+    fn dump_objects(&self, _path: &Path) -> anyhow::Result<usize> {
+        unimplemented!("dump_objects not supported")
+    }
+
+    fn live_objects(&self) -> Vec<Object> {
+        unimplemented!("live_objects not supported")
+    }
+
+    fn validator_account_key(&self, _address: &SuiAddress) -> Option<SuiKeyPair> {
+        unimplemented!("validator_account_key not supported")
+    }
 }
 
 #[async_trait::async_trait]
@@ -255,15 +490,30 @@ impl TransactionalAdapter for Simulacrum<StdRng> {
         Ok(self.execute_transaction(transaction)?)
     }
 
+    async fn execute_owned_txns_concurrently(
+        &mut self,
+        _transactions: Vec<Transaction>,
+    ) -> anyhow::Result<Vec<(TransactionEffects, Option<ExecutionError>)>> {
+        unimplemented!("execute_owned_txns_concurrently not supported in simulator mode")
+    }
+
     async fn dev_inspect_transaction_block(
         &self,
         _sender: SuiAddress,
         _transaction_kind: TransactionKind,
         _gas_price: Option<u64>,
+        _gas_object: Option<ObjectID>,
     ) -> SuiResult<DevInspectResults> {
         unimplemented!("dev_inspect_transaction_block not supported in simulator mode")
     }
 
+    async fn dry_run_transaction_block(
+        &self,
+        _transaction: TransactionData,
+    ) -> SuiResult<TransactionEffects> {
+        unimplemented!("dry_run_transaction_block not supported in simulator mode")
+    }
+
     async fn query_tx_events_asc(
         &self,
         tx_digest: &TransactionDigest,
@@ -276,10 +526,78 @@ impl TransactionalAdapter for Simulacrum<StdRng> {
             .unwrap_or_default())
     }
 
+    /// Only transactions that have been included in a checkpoint are scanned, as that is what
+    /// orders them.
+    async fn query_events_by_type_asc(
+        &self,
+        event_type: &StructTag,
+        limit: usize,
+    ) -> SuiResult<Vec<(EventID, Event)>> {
+        let store = self.store();
+        let Some(highest) = store.get_highest_checkpint() else {
+            return Ok(vec![]);
+        };
+        let mut events = vec![];
+        for seq in 0..=*highest.sequence_number() {
+            let Some(contents) = store
+                .get_checkpoint_by_sequence_number(seq)
+                .and_then(|chk| store.get_checkpoint_contents(&chk.content_digest))
+            else {
+                continue;
+            };
+            for digests in contents.iter() {
+                let Some(tx_events) =
+                    store.get_transaction_events_by_tx_digest(&digests.transaction)
+                else {
+                    continue;
+                };
+                events.extend(
+                    tx_events
+                        .data
+                        .iter()
+                        .enumerate()
+                        .filter(|(_, event)| &event.type_ == event_type)
+                        .map(|(seq, event)| {
+                            (
+                                EventID::from((digests.transaction, seq as u64)),
+                                event.clone(),
+                            )
+                        }),
+                );
+                if events.len() >= limit {
+                    events.truncate(limit);
+                    return Ok(events);
+                }
+            }
+        }
+        Ok(events)
+    }
+
+    async fn query_objects_by_owner(
+        &self,
+        owner: SuiAddress,
+        cursor: Option<ObjectID>,
+        limit: usize,
+    ) -> SuiResult<Vec<Object>> {
+        let mut objects: Vec<_> = self
+            .store()
+            .owned_objects(owner)
+            .filter(|object| cursor.map_or(true, |cursor| object.id() > cursor))
+            .cloned()
+            .collect();
+        objects.sort_by_key(|object| object.id());
+        objects.truncate(limit);
+        Ok(objects)
+    }
+
     async fn create_checkpoint(&mut self) -> anyhow::Result<VerifiedCheckpoint> {
         Ok(self.create_checkpoint())
     }
 
+    fn pending_checkpoint_tx_count(&self) -> usize {
+        self.pending_checkpoint_tx_count()
+    }
+
     async fn advance_clock(
         &mut self,
         duration: std::time::Duration,
@@ -292,6 +610,11 @@ impl TransactionalAdapter for Simulacrum<StdRng> {
         Ok(())
     }
 
+    async fn upgrade_protocol_version(&mut self, version: ProtocolVersion) -> anyhow::Result<()> {
+        self.advance_epoch_with_protocol_version(version);
+        Ok(())
+    }
+
     async fn request_gas(
         &mut self,
         address: SuiAddress,
@@ -299,4 +622,155 @@ impl TransactionalAdapter for Simulacrum<StdRng> {
     ) -> anyhow::Result<TransactionEffects> {
         self.request_gas(address, amount)
     }
+
+    async fn insert_object(&mut self, object: Object) -> anyhow::Result<()> {
+        self.insert_object(object);
+        Ok(())
+    }
+
+    async fn remove_object(&mut self, id: ObjectID) -> anyhow::Result<()> {
+        self.remove_object(id);
+        Ok(())
+    }
+
+    fn get_system_state(&self) -> SuiResult<SuiSystemState> {
+        get_sui_system_state(self)
+    }
+
+    fn insert_jwk(&mut self, jwk_id: JwkId, jwk: JWK) {
+        self.insert_jwk(jwk_id, jwk)
+    }
+
+    async fn update_authenticator_state(
+        &mut self,
+        jwks: Vec<ActiveJwk>,
+    ) -> anyhow::Result<TransactionEffects> {
+        self.update_authenticator_state(jwks)
+    }
+
+    fn dump_objects(&self, path: &Path) -> anyhow::Result<usize> {
+        self.store().dump_objects(path)
+    }
+
+    fn live_objects(&self) -> Vec<Object> {
+        self.store().live_objects().cloned().collect()
+    }
+
+    fn validator_account_key(&self, address: &SuiAddress) -> Option<SuiKeyPair> {
+        self.keystore().validator_account(address).cloned()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::cell::Cell;
+
+    #[tokio::test]
+    async fn transient_conflict_is_retried() {
+        let calls = Cell::new(0);
+        let result = retry_transient(2, || {
+            calls.set(calls.get() + 1);
+            let attempt = calls.get();
+            async move {
+                if attempt == 1 {
+                    Err(SuiError::TooManyTransactionsPendingConsensus)
+                } else {
+                    Ok(attempt)
+                }
+            }
+        })
+        .await;
+        assert_eq!(result.unwrap(), 2);
+        assert_eq!(calls.get(), 2);
+    }
+
+    #[tokio::test]
+    async fn transient_submission_failure_is_retried_to_effects() {
+        use sui_core::authority::authority_test_utils::{
+            init_state_with_ids, init_transfer_transaction,
+        };
+        use sui_types::crypto::{get_key_pair, AccountKeyPair};
+        use sui_types::effects::TransactionEffectsAPI;
+        use sui_types::transaction::TEST_ONLY_GAS_UNIT_FOR_TRANSFER;
+
+        let (sender, sender_key): (_, AccountKeyPair) = get_key_pair();
+        let recipient = SuiAddress::random_for_testing_only();
+        let object_id = ObjectID::random();
+        let gas_object_id = ObjectID::random();
+        let validator =
+            init_state_with_ids(vec![(sender, object_id), (sender, gas_object_id)]).await;
+        let rgp = validator.reference_gas_price_for_testing().unwrap();
+        let object = validator.get_object(&object_id).await.unwrap().unwrap();
+        let gas_object = validator.get_object(&gas_object_id).await.unwrap().unwrap();
+        let transaction = init_transfer_transaction(
+            &validator,
+            sender,
+            &sender_key,
+            recipient,
+            object.compute_object_reference(),
+            gas_object.compute_object_reference(),
+            rgp * TEST_ONLY_GAS_UNIT_FOR_TRANSFER,
+            rgp,
+        )
+        .into_inner();
+
+        // The first submission is rejected as if consensus were overloaded, before the
+        // transaction reaches the validator; the retry submits it for real.
+        let calls = Cell::new(0);
+        let (_, effects, execution_error) = retry_transient(2, || {
+            calls.set(calls.get() + 1);
+            let attempt = calls.get();
+            let validator = &validator;
+            let transaction = transaction.clone();
+            async move {
+                if attempt == 1 {
+                    return Err(SuiError::TooManyTransactionsPendingConsensus);
+                }
+                send_and_confirm_transaction_with_execution_error(
+                    validator,
+                    None,
+                    transaction,
+                    /* with_shared */ false,
+                )
+                .await
+            }
+        })
+        .await
+        .unwrap();
+
+        assert_eq!(calls.get(), 2);
+        assert!(execution_error.is_none());
+        assert!(effects.status().is_ok());
+        assert_eq!(effects.transaction_digest(), transaction.digest());
+        let transferred = validator.get_object(&object_id).await.unwrap().unwrap();
+        assert_eq!(
+            transferred.owner,
+            sui_types::object::Owner::AddressOwner(recipient)
+        );
+    }
+
+    #[tokio::test]
+    async fn deterministic_failure_is_not_retried() {
+        let calls = Cell::new(0);
+        let result: SuiResult<()> = retry_transient(2, || {
+            calls.set(calls.get() + 1);
+            async { Err(SuiError::ExecutionError("boom".to_string())) }
+        })
+        .await;
+        assert!(result.is_err());
+        assert_eq!(calls.get(), 1);
+    }
+
+    #[tokio::test]
+    async fn retries_are_bounded() {
+        let calls = Cell::new(0);
+        let result: SuiResult<()> = retry_transient(2, || {
+            calls.set(calls.get() + 1);
+            async { Err(SuiError::TooManyTransactionsPendingConsensus) }
+        })
+        .await;
+        assert!(result.is_err());
+        assert_eq!(calls.get(), 3);
+    }
 }
@@ -14,8 +14,8 @@ use sui_types::{
 };
 
 use crate::programmable_transaction_test_parser::token::{
-    GAS_COIN, INPUT, MAKE_MOVE_VEC, MERGE_COINS, NESTED_RESULT, PUBLISH, RESULT, SPLIT_COINS,
-    TRANSFER_OBJECTS, UPGRADE,
+    GAS_COIN, INPUT, MAKE_MOVE_VEC, MERGE_COINS, NESTED_RESULT, PACKAGE, PUBLISH, RESULT,
+    SPLIT_COINS, TRANSFER_OBJECTS, UPGRADE,
 };
 
 use super::token::CommandToken;
@@ -192,20 +192,8 @@ where
                 )
             }
             (Tok::Ident, contents) => {
-                let package = Identifier::new(contents)?;
-                self.inner().advance(Tok::ColonColon)?;
-                let module = Identifier::new(self.inner().advance(Tok::Ident)?)?;
-                self.inner().advance(Tok::ColonColon)?;
-                let function = Identifier::new(self.inner().advance(Tok::Ident)?)?;
-                let type_arguments = self.parse_type_args_opt()?.unwrap_or_default();
-                let arguments = self.parse_command_args(Tok::LParen, Tok::RParen)?;
-                let call = ParsedMoveCall {
-                    package,
-                    module,
-                    function,
-                    type_arguments,
-                    arguments,
-                };
+                let mut call = self.parse_move_call_function(contents)?;
+                call.arguments = self.parse_command_args(Tok::LParen, Tok::RParen)?;
                 ParsedCommand::MoveCall(Box::new(call))
             }
 
@@ -213,6 +201,25 @@ where
         })
     }
 
+    /// Parses the rest of `package::module::function`, followed by any type arguments, once
+    /// `package` has been read. The call is returned without arguments.
+    pub fn parse_move_call_function(&mut self, package: &str) -> Result<ParsedMoveCall> {
+        use super::token::CommandToken as Tok;
+        let package = Identifier::new(package)?;
+        self.inner().advance(Tok::ColonColon)?;
+        let module = Identifier::new(self.inner().advance(Tok::Ident)?)?;
+        self.inner().advance(Tok::ColonColon)?;
+        let function = Identifier::new(self.inner().advance(Tok::Ident)?)?;
+        let type_arguments = self.parse_type_args_opt()?.unwrap_or_default();
+        Ok(ParsedMoveCall {
+            package,
+            module,
+            function,
+            type_arguments,
+            arguments: vec![],
+        })
+    }
+
     pub fn maybe_trailing_comma(&mut self) -> Result<()> {
         if let Some(CommandToken::Comma) = self.inner().peek_tok() {
             self.inner().advance(CommandToken::Comma)?;
@@ -288,7 +295,7 @@ where
             return Ok(None);
         }
         let contents = self.inner().advance(CommandToken::TypeArgString)?;
-        let type_tokens: Vec<_> = TypeToken::tokenize(contents)?
+        let type_tokens: Vec<_> = tokenize_type_args(contents)?
             .into_iter()
             .filter(|(tok, _)| !tok.is_whitespace())
             .collect();
@@ -307,6 +314,34 @@ where
     }
 }
 
+/// Tokenizes a type argument string. A package reference, `package(N)`, becomes a single address
+/// identifier, to be resolved to the package published by task `N` (see `parse_package_ref`).
+fn tokenize_type_args(contents: &str) -> Result<Vec<(TypeToken, &str)>> {
+    let mut tokens = vec![];
+    let package_ref = format!("{PACKAGE}(");
+    let mut rest = contents;
+    while let Some(start) = rest.find(&package_ref) {
+        let Some(len) = rest[start..].find(')') else {
+            bail!("Unterminated package reference in {contents}");
+        };
+        let end = start + len + 1;
+        tokens.extend(TypeToken::tokenize(&rest[..start])?);
+        tokens.push((TypeToken::Ident, &rest[start..end]));
+        rest = &rest[end..];
+    }
+    tokens.extend(TypeToken::tokenize(rest)?);
+    Ok(tokens)
+}
+
+/// The task number referred to by an address of the form `package(N)`, if `name` is one.
+pub fn parse_package_ref(name: &str) -> Option<u64> {
+    let task = name
+        .strip_prefix(PACKAGE)?
+        .strip_prefix('(')?
+        .strip_suffix(')')?;
+    u64::from_str(task.trim()).ok()
+}
+
 impl ParsedCommand {
     pub fn parse_vec(s: &str) -> Result<Vec<Self>> {
         let tokens: Vec<_> = CommandToken::tokenize(s)?
@@ -376,6 +411,22 @@ impl ParsedCommand {
 }
 
 impl ParsedMoveCall {
+    /// Parses a function to call, `package::module::function`, followed by any type arguments,
+    /// as in a Move call command but without its arguments.
+    pub fn parse_function(s: &str) -> Result<Self> {
+        let tokens: Vec<_> = CommandToken::tokenize(s)?
+            .into_iter()
+            .filter(|(tok, _)| !tok.is_whitespace())
+            .collect();
+        let mut parser = CommandParser::new(tokens);
+        let package = parser.inner().advance(CommandToken::Ident)?;
+        let call = parser.parse_move_call_function(package)?;
+        if let Ok((_, contents)) = parser.inner().advance_any() {
+            bail!("Expected end of token stream. Got: {}", contents)
+        }
+        Ok(call)
+    }
+
     pub fn into_move_call(
         self,
         address_mapping: &impl Fn(&str) -> Option<AccountAddress>,
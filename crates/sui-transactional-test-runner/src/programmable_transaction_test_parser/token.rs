@@ -52,6 +52,7 @@ pub const GAS_COIN: &str = "Gas";
 pub const INPUT: &str = "Input";
 pub const RESULT: &str = "Result";
 pub const NESTED_RESULT: &str = "NestedResult";
+pub const PACKAGE: &str = "package";
 
 impl Display for CommandToken {
     fn fmt(&self, formatter: &mut fmt::Formatter<'_>) -> Result<(), fmt::Error> {
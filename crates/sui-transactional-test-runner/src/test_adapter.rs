@@ -3,15 +3,21 @@
 
 //! This module contains the transactional test runner instantiation for the Sui adapter
 
-use crate::{args::*, programmable_transaction_test_parser::parser::ParsedCommand};
+use crate::{
+    args::*,
+    programmable_transaction_test_parser::parser::{parse_package_ref, ParsedCommand},
+};
 use crate::{TransactionalAdapter, ValidatorWithFullnode};
-use anyhow::{anyhow, bail};
+use anyhow::{anyhow, bail, ensure};
 use async_trait::async_trait;
 use bimap::btree::BiBTreeMap;
+use fastcrypto::encoding::{Base64, Encoding, Hex};
+use fastcrypto_zkp::bn254::zk_login::{parse_jwks, OIDCProvider};
+use jsonrpsee::http_client::HttpClientBuilder;
 use move_binary_format::{file_format::CompiledScript, CompiledModule};
-use move_bytecode_utils::module_cache::GetModule;
+use move_bytecode_utils::{layout::TypeLayoutBuilder, module_cache::GetModule};
 use move_command_line_common::{
-    address::ParsedAddress, files::verify_and_create_named_address_mapping,
+    address::ParsedAddress, files::verify_and_create_named_address_mapping, values::ParsedValue,
 };
 use move_compiler::{
     shared::{NumberFormat, NumericalAddress, PackagePaths},
@@ -20,9 +26,9 @@ use move_compiler::{
 use move_core_types::ident_str;
 use move_core_types::{
     account_address::AccountAddress,
-    identifier::IdentStr,
-    language_storage::{ModuleId, TypeTag},
-    value::MoveStruct,
+    identifier::{IdentStr, Identifier},
+    language_storage::{ModuleId, StructTag, TypeTag},
+    value::{MoveStruct, MoveValue},
 };
 use move_symbol_pool::Symbol;
 use move_transactional_test_runner::{
@@ -32,28 +38,43 @@ use move_transactional_test_runner::{
 use move_vm_runtime::session::SerializedReturnValues;
 use once_cell::sync::Lazy;
 use rand::{rngs::StdRng, Rng, SeedableRng};
-use simulacrum::Simulacrum;
+use serde::de::DeserializeOwned;
+use shared_crypto::intent::{Intent, IntentMessage};
+use simulacrum::{InMemoryStore, Simulacrum};
 use std::fmt::{self, Write};
+use std::str::FromStr;
 use std::time::Duration;
 use std::{
     collections::{BTreeMap, BTreeSet},
-    path::Path,
+    path::{Path, PathBuf},
     sync::Arc,
 };
 use sui_core::authority::test_authority_builder::TestAuthorityBuilder;
 use sui_core::authority::AuthorityState;
 use sui_framework::DEFAULT_FRAMEWORK_PATH;
-use sui_json_rpc::api::QUERY_MAX_RESULT_LIMIT;
-use sui_json_rpc_types::{DevInspectResults, SuiExecutionStatus, SuiTransactionBlockEffectsAPI};
-use sui_protocol_config::{Chain, ProtocolConfig};
+use sui_json_rpc::api::{ReadApiClient, QUERY_MAX_RESULT_LIMIT};
+use sui_json_rpc_types::{
+    DevInspectResults, OwnedObjectRef, SuiExecutionStatus, SuiObjectDataOptions, SuiObjectRef,
+    SuiPastObjectResponse, SuiTransactionBlockEffectsAPI, SuiTransactionBlockResponseOptions,
+};
+use sui_protocol_config::{Chain, ProtocolConfig, ProtocolVersion};
+use sui_rest_api::{get_checkpoint_data, CheckpointData};
 use sui_storage::{
     key_value_store::TransactionKeyValueStore, key_value_store_metrics::KeyValueStoreMetrics,
 };
 use sui_swarm_config::genesis_config::AccountConfig;
+use sui_types::authenticator_state::ActiveJwk;
 use sui_types::base_types::SequenceNumber;
+use sui_types::committee::EpochId;
 use sui_types::crypto::get_authority_key_pair;
-use sui_types::effects::TransactionEffectsAPI;
+use sui_types::effects::{TransactionEffects, TransactionEffectsAPI};
+use sui_types::error::{ExecutionError, SuiError, SuiResult, UserInputError};
+use sui_types::message_envelope::Message;
+use sui_types::multisig::{MultiSig, MultiSigPublicKey};
+use sui_types::signature::GenericSignature;
+use sui_types::sui_system_state::SuiSystemStateTrait;
 use sui_types::transaction::Command;
+use sui_types::transaction::InputObjectKind;
 use sui_types::transaction::ProgrammableTransaction;
 use sui_types::DEEPBOOK_ADDRESS;
 use sui_types::DEEPBOOK_PACKAGE_ID;
@@ -61,23 +82,31 @@ use sui_types::MOVE_STDLIB_PACKAGE_ID;
 use sui_types::SUI_SYSTEM_ADDRESS;
 use sui_types::{
     base_types::{ObjectID, ObjectRef, SuiAddress, SUI_ADDRESS_LENGTH},
-    crypto::{get_key_pair_from_rng, AccountKeyPair},
+    crypto::{get_key_pair_from_rng, AccountKeyPair, KeypairTraits, PublicKey, Signature},
+    digests::TransactionDigest,
+    dynamic_field::derive_dynamic_field_id,
     event::Event,
-    object::{self, Object, ObjectFormatOptions},
-    transaction::{Transaction, TransactionData, TransactionDataAPI, VerifiedTransaction},
+    object::{self, MoveObject, Object, ObjectFormatOptions, Owner, OBJECT_START_VERSION},
+    transaction::{
+        SenderSignedData, Transaction, TransactionData, TransactionDataAPI, VerifiedTransaction,
+    },
     MOVE_STDLIB_ADDRESS, SUI_CLOCK_OBJECT_ID, SUI_FRAMEWORK_ADDRESS, SUI_SYSTEM_STATE_OBJECT_ID,
 };
 use sui_types::{execution_status::ExecutionStatus, transaction::TransactionKind};
 use sui_types::{gas::GasCostSummary, object::GAS_VALUE_FOR_TESTING};
 use sui_types::{
-    move_package::MovePackage,
-    transaction::{Argument, CallArg},
+    move_package::{MovePackage, UpgradeCap},
+    transaction::{Argument, CallArg, ObjectArg},
 };
 use sui_types::{
     programmable_transaction_builder::ProgrammableTransactionBuilder, SUI_FRAMEWORK_PACKAGE_ID,
 };
 use sui_types::{utils::to_sender_signed_transaction, SUI_SYSTEM_PACKAGE_ID};
-use tempfile::NamedTempFile;
+use sui_types::{
+    utils::{get_zklogin_user_address, sign_zklogin_tx},
+    zk_login_util::DEFAULT_JWK_BYTES,
+};
+use tempfile::{NamedTempFile, TempDir};
 
 #[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Debug)]
 pub enum FakeID {
@@ -99,7 +128,14 @@ const RNG_SEED: [u8; 32] = [
     179, 179, 65, 9, 31, 249, 221, 123, 225, 112, 199, 247,
 ];
 
+/// Sender name that dev-inspects a transaction as the system address (0x0), to exercise entry
+/// functions gated on it.
+const SYSTEM_SENDER: &str = "system";
+
 const DEFAULT_GAS_BUDGET: u64 = 5_000_000_000;
+/// Extra gas, as a percentage of what a dry run was charged, added to budgets chosen by
+/// `--auto-gas-budget`.
+const AUTO_GAS_BUDGET_MARGIN_PERCENT: u64 = 10;
 const GAS_FOR_TESTING: u64 = GAS_VALUE_FOR_TESTING;
 
 const DEFAULT_CHAIN_START_TIMESTAMP: u64 = 0;
@@ -110,12 +146,31 @@ pub struct SuiTestAdapter<'a> {
     package_upgrade_mapping: BTreeMap<Symbol, Symbol>,
     accounts: BTreeMap<String, TestAccount>,
     default_account: TestAccount,
+    /// Named account used by commands that do not specify a `--sender`.
+    default_sender: Option<String>,
+    multisig_accounts: BTreeMap<String, MultisigAccount>,
+    /// The name and address of the zkLogin identity, if one was declared.
+    zklogin_account: Option<(String, SuiAddress)>,
     default_syntax: SyntaxChoice,
     object_enumeration: BiBTreeMap<ObjectID, FakeID>,
     next_fake: (u64, u64),
     gas_price: u64,
+    /// The protocol config the network is running under, which `upgrade-protocol` can change.
+    protocol_config: ProtocolConfig,
     pub(crate) staged_modules: BTreeMap<Symbol, StagedPackage>,
+    /// The transaction built by each task that built one, by task number.
+    task_transactions: BTreeMap<u64, Transaction>,
+    /// Objects that were deleted or wrapped, and have not been unwrapped since, along with the
+    /// task that removed them.
+    removed_objects: BTreeMap<ObjectID, u64>,
+    /// Accounts forgotten by `remove-account`, whose names no longer resolve.
+    removed_accounts: BTreeSet<String>,
     is_simulator: bool,
+    /// Where commands that read and write files resolve relative paths, so that tests running at
+    /// the same time do not overwrite each other's files.
+    scratch_dir: TempDir,
+    /// Where `dump-store` resolves relative paths, if set with `--dump-dir`.
+    dump_dir: Option<PathBuf>,
     pub(crate) executor: Box<dyn TransactionalAdapter>,
 }
 
@@ -132,6 +187,14 @@ struct TestAccount {
     gas: ObjectID,
 }
 
+/// An account controlled by a weighted set of named `accounts`, which has no gas of its own.
+struct MultisigAccount {
+    address: SuiAddress,
+    public_key: MultiSigPublicKey,
+    /// Member account names, in the same order as their keys in `public_key`.
+    members: Vec<String>,
+}
+
 #[derive(Debug)]
 struct TxnSummary {
     created: Vec<ObjectID>,
@@ -140,6 +203,9 @@ struct TxnSummary {
     deleted: Vec<ObjectID>,
     unwrapped_then_deleted: Vec<ObjectID>,
     wrapped: Vec<ObjectID>,
+    /// Objects the transaction took as input, and whether it modified them. Not tracked for
+    /// dev-inspect.
+    inputs: Vec<(ObjectID, bool)>,
     events: Vec<Event>,
     gas_summary: GasCostSummary,
 }
@@ -177,61 +243,234 @@ impl<'a> MoveTestAdapter<'a> for SuiTestAdapter<'a> {
         );
 
         // Unpack the init arguments
-        let (additional_mapping, account_names, protocol_config, is_simulator) =
-            match task_opt.map(|t| t.command) {
-                Some((
-                    InitCommand { named_addresses },
-                    SuiInitArgs {
-                        accounts,
-                        protocol_version,
-                        max_gas,
-                        shared_object_deletion,
-                        simulator,
-                    },
-                )) => {
-                    let map = verify_and_create_named_address_mapping(named_addresses).unwrap();
-                    let accounts = accounts
-                        .map(|v| v.into_iter().collect::<BTreeSet<_>>())
-                        .unwrap_or_default();
-
-                    let mut protocol_config = if let Some(protocol_version) = protocol_version {
-                        ProtocolConfig::get_for_version(protocol_version.into(), Chain::Unknown)
-                    } else {
-                        ProtocolConfig::get_for_max_version_UNSAFE()
-                    };
-                    if let Some(enable) = shared_object_deletion {
-                        protocol_config.set_shared_object_deletion(enable);
+        let (
+            additional_mapping,
+            account_names,
+            imported_accounts,
+            default_sender,
+            multisigs,
+            zklogin,
+            fixture_jwks,
+            genesis_objects,
+            gas_coins_per_account,
+            protocol_config,
+            shared_object_retries,
+            dump_dir,
+            is_simulator,
+        ) = match task_opt.map(|t| t.command) {
+            Some((
+                InitCommand { named_addresses },
+                SuiInitArgs {
+                    accounts,
+                    default_sender,
+                    protocol_version,
+                    max_gas,
+                    shared_object_deletion,
+                    shared_object_retries,
+                    multisigs,
+                    zklogin,
+                    zklogin_jwks_on_chain,
+                    imported_accounts,
+                    objects,
+                    gas_coins_per_account,
+                    dump_dir,
+                    simulator,
+                },
+            )) => {
+                let map = verify_and_create_named_address_mapping(named_addresses).unwrap();
+                let accounts = accounts
+                    .map(|v| v.into_iter().collect::<BTreeSet<_>>())
+                    .unwrap_or_default();
+                let mut declared = accounts.clone();
+                let imported_accounts: Vec<_> = imported_accounts
+                    .into_iter()
+                    .map(|imported| {
+                        if !declared.insert(imported.name.clone()) {
+                            panic!("Account {} is declared more than once", imported.name);
+                        }
+                        (imported.name.clone(), imported.key_pair())
+                    })
+                    .collect();
+                if declared.contains(SYSTEM_SENDER) {
+                    panic!(
+                        "Invalid init. The account name '{}' is reserved for the system address",
+                        SYSTEM_SENDER
+                    )
+                }
+                if let Some(sender) = &default_sender {
+                    if !declared.contains(sender) {
+                        panic!("Default sender {} is not a declared account", sender);
                     }
-                    if let Some(mx_tx_gas_override) = max_gas {
-                        if simulator {
-                            panic!("Cannot set max gas in simulator mode");
+                }
+                for multisig in &multisigs {
+                    for (member, _) in &multisig.members {
+                        if !declared.contains(member) {
+                            panic!(
+                                "Member {} of multisig account {} is not a declared account",
+                                member, multisig.name
+                            );
                         }
-                        protocol_config.set_max_tx_gas_for_testing(mx_tx_gas_override)
                     }
-                    (map, accounts, protocol_config, simulator)
                 }
-                None => {
-                    let protocol_config = ProtocolConfig::get_for_max_version_UNSAFE();
-                    (BTreeMap::new(), BTreeSet::new(), protocol_config, false)
+
+                let mut protocol_config = if let Some(protocol_version) = protocol_version {
+                    ProtocolConfig::get_for_version(protocol_version.into(), Chain::Unknown)
+                } else {
+                    ProtocolConfig::get_for_max_version_UNSAFE()
+                };
+                if let Some(enable) = shared_object_deletion {
+                    protocol_config.set_shared_object_deletion(enable);
                 }
-            };
+                if let Some(mx_tx_gas_override) = max_gas {
+                    if simulator {
+                        panic!("Cannot set max gas in simulator mode");
+                    }
+                    protocol_config.set_max_tx_gas_for_testing(mx_tx_gas_override)
+                }
+                if shared_object_retries.is_some() && simulator {
+                    panic!("Cannot retry shared object transactions in simulator mode");
+                }
+                if zklogin.is_some() && !simulator {
+                    panic!("zkLogin senders are only supported in simulator mode");
+                }
+                let genesis_objects = match objects {
+                    Some(path) => InMemoryStore::load_object_dump(&path).unwrap_or_else(|e| {
+                        panic!("Invalid genesis objects fixture {}: {e}", path.display())
+                    }),
+                    None => vec![],
+                };
+                let gas_coins_per_account = gas_coins_per_account.unwrap_or(1);
+                if gas_coins_per_account == 0 {
+                    panic!("Accounts need at least one gas coin");
+                }
+                (
+                    map,
+                    accounts,
+                    imported_accounts,
+                    default_sender,
+                    multisigs,
+                    zklogin,
+                    !zklogin_jwks_on_chain,
+                    genesis_objects,
+                    gas_coins_per_account,
+                    protocol_config,
+                    shared_object_retries.unwrap_or(0),
+                    dump_dir,
+                    simulator,
+                )
+            }
+            None => {
+                let protocol_config = ProtocolConfig::get_for_max_version_UNSAFE();
+                (
+                    BTreeMap::new(),
+                    BTreeSet::new(),
+                    vec![],
+                    None,
+                    vec![],
+                    None,
+                    false,
+                    vec![],
+                    1,
+                    protocol_config,
+                    0,
+                    None,
+                    false,
+                )
+            }
+        };
 
         let (
-            executor,
+            mut executor,
             AccountSetup {
                 default_account,
                 accounts,
-                named_address_mapping,
+                mut named_address_mapping,
                 objects,
                 account_objects,
             },
         ) = if is_simulator {
-            init_sim_executor(rng, account_names, additional_mapping, &protocol_config)
+            init_sim_executor(
+                rng,
+                account_names,
+                imported_accounts,
+                gas_coins_per_account,
+                additional_mapping,
+                &protocol_config,
+                genesis_objects,
+            )
         } else {
-            init_val_fullnode_executor(rng, account_names, additional_mapping, &protocol_config)
-                .await
+            init_val_fullnode_executor(
+                rng,
+                account_names,
+                imported_accounts,
+                gas_coins_per_account,
+                additional_mapping,
+                &protocol_config,
+                shared_object_retries,
+                genesis_objects,
+            )
+            .await
         };
 
+        let mut multisig_accounts = BTreeMap::new();
+        for MultisigArg {
+            name,
+            threshold,
+            members,
+        } in multisigs
+        {
+            let (members, weights): (Vec<_>, Vec<_>) = members.into_iter().unzip();
+            let pks = members
+                .iter()
+                .map(|member| PublicKey::Ed25519(accounts[member].key_pair.public().into()))
+                .collect();
+            let public_key = MultiSigPublicKey::new(pks, weights, threshold)
+                .unwrap_or_else(|e| panic!("Invalid multisig account {}: {}", name, e));
+            let address = SuiAddress::from(&public_key);
+            let addr = NumericalAddress::new(address.to_inner(), NumberFormat::Hex);
+            if named_address_mapping.insert(name.clone(), addr).is_some() {
+                panic!("Invalid init. The named address '{}' is reserved", name)
+            }
+            let account = MultisigAccount {
+                address,
+                public_key,
+                members,
+            };
+            multisig_accounts.insert(name, account);
+        }
+
+        let zklogin_account = zklogin.map(|name| {
+            let address = get_zklogin_user_address();
+            let addr = NumericalAddress::new(address.to_inner(), NumberFormat::Hex);
+            if named_address_mapping.insert(name.clone(), addr).is_some() {
+                panic!("Invalid init. The named address '{}' is reserved", name)
+            }
+            (name, address)
+        });
+        if zklogin_account.is_some() && fixture_jwks {
+            // The fixture proof was issued against these keys
+            let jwks = parse_jwks(DEFAULT_JWK_BYTES, &OIDCProvider::Twitch)
+                .expect("Fixture JWKs must parse");
+            for (jwk_id, jwk) in jwks {
+                executor.insert_jwk(jwk_id, jwk);
+            }
+        }
+
+        // Name the genesis validators `validator_0`, `validator_1`, ... so tests can stake with
+        // them and inspect them without hard-coding their addresses. A test that declares one of
+        // these names itself keeps its own address for it.
+        let validators = executor
+            .get_system_state()
+            .expect("Genesis must have a system state")
+            .into_sui_system_state_summary()
+            .active_validators;
+        for (i, validator) in validators.into_iter().enumerate() {
+            let addr = NumericalAddress::new(validator.sui_address.to_inner(), NumberFormat::Hex);
+            named_address_mapping
+                .entry(format!("validator_{i}"))
+                .or_insert(addr);
+        }
+
         let object_ids = objects.iter().map(|obj| obj.id()).collect::<Vec<_>>();
 
         let mut test_adapter = Self {
@@ -249,12 +488,21 @@ impl<'a> MoveTestAdapter<'a> for SuiTestAdapter<'a> {
             package_upgrade_mapping: BTreeMap::new(),
             accounts,
             default_account,
+            default_sender,
+            multisig_accounts,
+            zklogin_account,
             default_syntax,
             object_enumeration: BiBTreeMap::new(),
             next_fake: (0, 0),
             // TODO: make this configurable
             gas_price: 1000,
+            protocol_config,
             staged_modules: BTreeMap::new(),
+            task_transactions: BTreeMap::new(),
+            removed_objects: BTreeMap::new(),
+            removed_accounts: BTreeSet::new(),
+            scratch_dir: tempfile::tempdir().unwrap(),
+            dump_dir,
         };
 
         for well_known in WELL_KNOWN_OBJECTS.iter().copied() {
@@ -263,12 +511,15 @@ impl<'a> MoveTestAdapter<'a> for SuiTestAdapter<'a> {
                 .insert(well_known, FakeID::Known(well_known));
         }
         let mut output = String::new();
-        for (account, obj_id) in account_objects {
-            let fake = test_adapter.enumerate_fake(obj_id);
+        for (account, obj_ids) in account_objects {
+            let fakes = obj_ids
+                .into_iter()
+                .map(|obj_id| format!("object({})", test_adapter.enumerate_fake(obj_id)))
+                .collect::<Vec<_>>();
             if !output.is_empty() {
                 output.push_str(", ")
             }
-            write!(output, "{}: object({})", account, fake).unwrap()
+            write!(output, "{}: {}", account, fakes.join(", ")).unwrap()
         }
         for object_id in object_ids {
             test_adapter.enumerate_fake(object_id);
@@ -330,7 +581,7 @@ impl<'a> MoveTestAdapter<'a> for SuiTestAdapter<'a> {
             let pt = builder.finish();
             TransactionData::new_programmable(sender, vec![gas], pt, gas_budget, gas_price)
         };
-        let transaction = self.sign_txn(sender, data);
+        let transaction = self.sign_txn(sender, data)?;
         let summary = self.execute_txn(transaction).await?;
         let created_package = summary
             .created
@@ -364,7 +615,9 @@ impl<'a> MoveTestAdapter<'a> for SuiTestAdapter<'a> {
                 _ => (),
             }
         }
-        let output = self.object_summary_output(&summary, /* summarize */ false);
+        let output = self.object_summary_output(
+            &summary, /* summarize */ false, /* show_inputs */ false,
+        );
         let published_modules = self
             .get_object(&created_package, None)
             .unwrap()
@@ -399,6 +652,7 @@ impl<'a> MoveTestAdapter<'a> for SuiTestAdapter<'a> {
             sender,
             gas_price,
             summarize,
+            show_inputs,
         } = extra;
         let mut builder = ProgrammableTransactionBuilder::new();
         let arguments = args
@@ -420,9 +674,9 @@ impl<'a> MoveTestAdapter<'a> for SuiTestAdapter<'a> {
             let pt = builder.finish();
             TransactionData::new_programmable(sender, vec![gas], pt, gas_budget, gas_price)
         };
-        let transaction = self.sign_txn(sender, data);
+        let transaction = self.sign_txn(sender, data)?;
         let summary = self.execute_txn(transaction).await?;
-        let output = self.object_summary_output(&summary, summarize);
+        let output = self.object_summary_output(&summary, summarize, show_inputs);
         let empty = SerializedReturnValues {
             mutable_reference_outputs: vec![],
             return_values: vec![],
@@ -485,215 +739,1897 @@ impl<'a> MoveTestAdapter<'a> for SuiTestAdapter<'a> {
                     .get_verified_checkpoint_by_sequence_number(latest_chk)?;
                 Ok(Some(format!("{}", chk.data())))
             }
+            SuiSubcommand::ViewCheckpointEpochInfo(ViewCheckpointEpochInfoCommand {
+                checkpoint,
+                expect_protocol_version,
+            }) => {
+                let latest_chk = self.executor.get_latest_checkpoint_sequence_number()?;
+                let checkpoint = checkpoint.unwrap_or(latest_chk);
+                ensure!(
+                    checkpoint <= latest_chk,
+                    "Checkpoint {checkpoint} has not been created, the latest is {latest_chk}"
+                );
+                let chk = self
+                    .executor
+                    .get_verified_checkpoint_by_sequence_number(checkpoint)?;
+                let summary = chk.data();
+                let epoch = summary.epoch;
+
+                let Some(end_of_epoch) = &summary.end_of_epoch_data else {
+                    ensure!(
+                        expect_protocol_version.is_none(),
+                        "Checkpoint {checkpoint} does not end epoch {epoch}"
+                    );
+                    return Ok(Some(format!(
+                        "Checkpoint {checkpoint} is in the middle of epoch {epoch}"
+                    )));
+                };
+
+                let next_version = end_of_epoch.next_epoch_protocol_version.as_u64();
+                if let Some(expected) = expect_protocol_version {
+                    ensure!(
+                        next_version == expected,
+                        "Epoch {} starts at protocol version {next_version}, expected {expected}",
+                        epoch + 1,
+                    );
+                }
+
+                // Validator keys are not stable across test runs, so the committee is summarised
+                // by its size and stake rather than listed member by member.
+                let committee = &end_of_epoch.next_epoch_committee;
+                let total_stake: u64 = committee.iter().map(|(_, stake)| *stake).sum();
+                Ok(Some(format!(
+                    "Checkpoint {checkpoint} ends epoch {epoch}\n\
+                     Next epoch protocol version: {next_version}\n\
+                     Next epoch committee: {} validators, total stake {total_stake}",
+                    committee.len(),
+                )))
+            }
+            SuiSubcommand::AssertCheckpointTxCount(AssertCheckpointTxCountCommand {
+                expected,
+                pending: true,
+            }) => {
+                if !self.is_simulator() {
+                    bail!("Pending checkpoint transactions are only tracked in simulator mode");
+                }
+                let pending = self.executor.pending_checkpoint_tx_count();
+                ensure!(
+                    pending == expected,
+                    "{pending} transactions are pending checkpoint, expected {expected}"
+                );
+                Ok(None)
+            }
+            SuiSubcommand::AssertCheckpointTxCount(AssertCheckpointTxCountCommand {
+                expected,
+                pending: false,
+            }) => {
+                let latest_chk = self.executor.get_latest_checkpoint_sequence_number()?;
+                let chk = self
+                    .executor
+                    .get_verified_checkpoint_by_sequence_number(latest_chk)?;
+                let contents = self
+                    .executor
+                    .get_checkpoint_contents(chk.data().content_digest)?;
+                ensure!(
+                    contents.size() == expected,
+                    "Checkpoint {latest_chk} has {} transactions, expected {expected}",
+                    contents.size()
+                );
+                Ok(None)
+            }
             SuiSubcommand::CreateCheckpoint => {
                 self.executor.create_checkpoint().await?;
                 let latest_chk = self.executor.get_latest_checkpoint_sequence_number()?;
                 Ok(Some(format!("Checkpoint created: {}", latest_chk)))
             }
-            SuiSubcommand::AdvanceEpoch => {
-                self.executor.advance_epoch().await?;
+            SuiSubcommand::CreateCheckpoints(CreateCheckpointsCommand { count }) => {
+                if !self.is_simulator() {
+                    bail!("Creating checkpoints is only supported in simulator mode");
+                }
+                ensure!(count > 0, "Checkpoint count must be positive");
+                for _ in 0..count {
+                    self.executor.create_checkpoint().await?;
+                }
+                let latest_chk = self.executor.get_latest_checkpoint_sequence_number()?;
+                Ok(Some(format!(
+                    "Checkpoints created: {count}, latest: {latest_chk}"
+                )))
+            }
+            SuiSubcommand::AdvanceEpoch(AdvanceEpochCommand { count, to }) => {
+                let count = match (count, to) {
+                    (_, Some(to)) => {
+                        let current = self.current_epoch()?;
+                        ensure!(
+                            to > current,
+                            "Cannot advance to epoch {to}, already at epoch {current}"
+                        );
+                        to - current
+                    }
+                    (Some(count), None) => {
+                        ensure!(count > 0, "Epoch count must be positive");
+                        count
+                    }
+                    (None, None) => 1,
+                };
+                for _ in 0..count {
+                    self.executor.advance_epoch().await?;
+                }
                 let latest_chk = self.executor.get_latest_checkpoint_sequence_number()?;
                 let chk = self
                     .executor
                     .get_verified_checkpoint_by_sequence_number(latest_chk)?;
                 Ok(Some(format!("Epoch advanced: {}", chk.data().epoch)))
             }
-            SuiSubcommand::AdvanceClock(AdvanceClockCommand { duration_ns }) => {
-                self.executor
-                    .advance_clock(Duration::from_nanos(duration_ns))
-                    .await?;
-                Ok(None)
+            SuiSubcommand::UpgradeProtocol(UpgradeProtocolCommand { to }) => {
+                if !self.is_simulator() {
+                    bail!("Upgrading the protocol is only supported in simulator mode");
+                }
+                let current = self.protocol_config.version.as_u64();
+                ensure!(
+                    to > current,
+                    "Cannot upgrade to protocol version {to}, already at protocol version {current}"
+                );
+                ensure!(
+                    to <= ProtocolVersion::MAX.as_u64(),
+                    "Protocol version {to} is not supported, the latest is {}",
+                    ProtocolVersion::MAX.as_u64()
+                );
+                // The new version takes effect from the next epoch, and the system packages are
+                // not upgraded along with it.
+                let version = ProtocolVersion::new(to);
+                self.executor.upgrade_protocol_version(version).await?;
+                self.protocol_config = ProtocolConfig::get_for_version(version, Chain::Unknown);
+                Ok(Some(format!(
+                    "Protocol upgraded: {to}, epoch: {}",
+                    self.current_epoch()?
+                )))
             }
-
-            SuiSubcommand::ViewObject(ViewObjectCommand { id: fake_id }) => {
-                let obj = get_obj!(fake_id);
-                Ok(Some(match &obj.data {
-                    object::Data::Move(move_obj) => {
-                        let layout = move_obj
-                            .get_layout(ObjectFormatOptions::default(), &&*self)
-                            .unwrap();
-                        let move_struct =
-                            MoveStruct::simple_deserialize(move_obj.contents(), &layout).unwrap();
-                        self.stabilize_str(format!(
-                            "Owner: {}\nVersion: {}\nContents: {}",
-                            &obj.owner,
-                            obj.version().value(),
-                            move_struct
-                        ))
-                    }
-                    object::Data::Package(package) => {
-                        let num_modules = package.serialized_module_map().len();
-                        let modules = package
-                            .serialized_module_map()
-                            .keys()
-                            .cloned()
-                            .collect::<Vec<_>>()
-                            .join(", ");
-                        assert!(!modules.is_empty());
-                        if num_modules > 1 {
-                            format!("{}::{{{}}}", fake_id, modules)
-                        } else {
-                            format!("{}::{}", fake_id, modules)
-                        }
-                    }
-                }))
+            SuiSubcommand::QueryEventsByType(QueryEventsByTypeCommand {
+                event_type,
+                limit,
+                show_seq,
+            }) => {
+                let state = self.compiled_state();
+                let event_type =
+                    event_type.into_struct_tag(&|s| Some(state.resolve_named_address(s)))?;
+                let limit = limit.unwrap_or(*QUERY_MAX_RESULT_LIMIT);
+                let events = self
+                    .executor
+                    .query_events_by_type_asc(&event_type, limit)
+                    .await?;
+                if events.is_empty() {
+                    return Ok(Some("No events".to_string()));
+                }
+                if !show_seq {
+                    let events: Vec<_> = events.into_iter().map(|(_, event)| event).collect();
+                    return Ok(Some(format!(
+                        "events: {}",
+                        self.list_events(&events, /* summarize */ false)
+                    )));
+                }
+                let tasks: BTreeMap<_, _> = self
+                    .task_transactions
+                    .iter()
+                    .map(|(task, transaction)| (*transaction.digest(), *task))
+                    .collect();
+                let events = events
+                    .into_iter()
+                    .map(|(id, event)| {
+                        let task = match tasks.get(&id.tx_digest) {
+                            Some(task) => format!("task {task}"),
+                            None => "unknown task".to_string(),
+                        };
+                        let event = self.list_events(&[event], /* summarize */ false);
+                        format!("{task} #{}: {event}", id.event_seq)
+                    })
+                    .collect::<Vec<_>>()
+                    .join("\n");
+                Ok(Some(format!("events:\n{events}")))
             }
-            SuiSubcommand::TransferObject(TransferObjectCommand {
-                id: fake_id,
-                recipient,
-                sender,
-                gas_budget,
+            SuiSubcommand::CreateSharedObject(CreateSharedObjectCommand {
+                initial_shared_version,
             }) => {
-                let mut builder = ProgrammableTransactionBuilder::new();
-                let obj_arg = SuiValue::Object(fake_id, None).into_argument(&mut builder, self)?;
-                let recipient = match self.accounts.get(&recipient) {
-                    Some(test_account) => test_account.address,
-                    None => panic!("Unbound account {}", recipient),
+                if !self.is_simulator() {
+                    bail!("Creating shared objects directly is only supported in simulator mode");
+                }
+                ensure!(
+                    initial_shared_version >= OBJECT_START_VERSION.value(),
+                    "Initial shared version must be at least {}",
+                    OBJECT_START_VERSION.value()
+                );
+                let version = SequenceNumber::from_u64(initial_shared_version);
+                // Derive the ID from the task number so it is stable across runs.
+                let id = ObjectID::derive_id(TransactionDigest::genesis(), number as u64);
+                // An empty `sui::bag::Bag`, which holds no coins, so that creating it outside of a
+                // transaction does not change the supply of any of them.
+                let bag_type = StructTag {
+                    address: SUI_FRAMEWORK_ADDRESS,
+                    module: ident_str!("bag").to_owned(),
+                    name: ident_str!("Bag").to_owned(),
+                    type_params: vec![],
                 };
-                let gas_budget = gas_budget.unwrap_or(DEFAULT_GAS_BUDGET);
-                let gas_price = self.gas_price;
-                let transaction = self.sign_txn(sender, |sender, gas| {
-                    let rec_arg = builder.pure(recipient).unwrap();
-                    builder.command(sui_types::transaction::Command::TransferObjects(
-                        vec![obj_arg],
-                        rec_arg,
-                    ));
-                    let pt = builder.finish();
-                    TransactionData::new_programmable(sender, vec![gas], pt, gas_budget, gas_price)
-                });
-                let summary = self.execute_txn(transaction).await?;
-                let output = self.object_summary_output(&summary, /* summarize */ false);
-                Ok(output)
+                // SAFETY: `Bag` has `store`, so it can be transferred publicly
+                let bag = unsafe {
+                    MoveObject::new_from_execution(
+                        bag_type.into(),
+                        true,
+                        version,
+                        bcs::to_bytes(&(id, 0u64))?,
+                        &self.protocol_config,
+                    )?
+                };
+                let object = Object::new_move(
+                    bag,
+                    Owner::Shared {
+                        initial_shared_version: version,
+                    },
+                    TransactionDigest::genesis(),
+                );
+                self.executor.insert_object(object).await?;
+                let fake_id = self.enumerate_fake(id);
+                Ok(Some(format!("created: object({fake_id})")))
             }
-            SuiSubcommand::ConsensusCommitPrologue(ConsensusCommitPrologueCommand {
-                timestamp_ms,
-            }) => {
-                let transaction =
-                    VerifiedTransaction::new_consensus_commit_prologue(0, 0, timestamp_ms);
-                let summary = self.execute_txn(transaction.into()).await?;
-                let output = self.object_summary_output(&summary, /* summarize */ false);
-                Ok(output)
+            SuiSubcommand::ViewTx(ViewTxCommand { task }) => {
+                let Some(transaction) = self.task_transactions.get(&task) else {
+                    bail!("Task {task} did not build a transaction");
+                };
+                let intent_msg = transaction.data().intent_message();
+                let signers = transaction
+                    .data()
+                    .tx_signatures()
+                    .iter()
+                    .map(|sig| Ok(SuiAddress::try_from(sig)?.to_string()))
+                    .collect::<anyhow::Result<Vec<_>>>()?;
+                let output = format!(
+                    "sender: {}\n\
+                    gas owner: {}\n\
+                    signers: {}\n\
+                    intent scope: {:?}",
+                    intent_msg.value.sender(),
+                    intent_msg.value.gas_owner(),
+                    signers.join(", "),
+                    intent_msg.intent.scope,
+                );
+                Ok(Some(self.stabilize_str(output)))
             }
-            SuiSubcommand::ProgrammableTransaction(ProgrammableTransactionCommand {
-                sender,
-                gas_budget,
-                gas_price,
-                dev_inspect,
-                inputs,
-            }) => {
-                if dev_inspect && self.is_simulator() {
-                    bail!("Dev inspect is not supported on simulator mode");
+            SuiSubcommand::DumpStore(DumpStoreCommand { path }) => {
+                if !self.is_simulator() {
+                    bail!("Dumping the object store is only supported in simulator mode");
                 }
+                // The dump outlives the test, so it is not written to the scratch directory.
+                // `join` keeps absolute paths as they are.
+                let path = match &self.dump_dir {
+                    Some(dump_dir) => {
+                        std::fs::create_dir_all(dump_dir)?;
+                        dump_dir.join(path)
+                    }
+                    None if path.is_absolute() => path,
+                    None => bail!(
+                        "Cannot dump to relative path {} without a --dump-dir in init",
+                        path.display()
+                    ),
+                };
+                self.executor.dump_objects(&path)?;
 
-                let inputs = self.compiled_state().resolve_args(inputs)?;
-                let inputs: Vec<CallArg> = inputs
-                    .into_iter()
-                    .map(|arg| arg.into_call_arg(self))
-                    .collect::<anyhow::Result<_>>()?;
-                let file = data.ok_or_else(|| {
-                    anyhow::anyhow!("Missing commands for programmable transaction")
-                })?;
-                let contents = std::fs::read_to_string(file.path())?;
-                let commands = ParsedCommand::parse_vec(&contents)?;
-                let staged = &self.staged_modules;
-                let state = &self.compiled_state;
-                let commands = commands
-                    .into_iter()
-                    .map(|c| {
-                        c.into_command(
-                            &|p| {
-                                let modules = staged
-                                    .get(&Symbol::from(p))?
-                                    .modules
-                                    .iter()
-                                    .map(|(_n, m)| {
-                                        let mut buf = vec![];
-                                        m.serialize(&mut buf).unwrap();
-                                        buf
-                                    })
-                                    .collect();
-                                Some(modules)
-                            },
-                            &|s| Some(state.resolve_named_address(s)),
-                        )
-                    })
-                    .collect::<anyhow::Result<Vec<Command>>>()?;
-                let summary = if !dev_inspect {
-                    let gas_budget = gas_budget.unwrap_or(DEFAULT_GAS_BUDGET);
-                    let gas_price = gas_price.unwrap_or(self.gas_price);
-                    let transaction = self.sign_txn(sender, |sender, gas| {
-                        TransactionData::new_programmable(
-                            sender,
-                            vec![gas],
-                            ProgrammableTransaction { inputs, commands },
-                            gas_budget,
-                            gas_price,
-                        )
-                    });
-                    self.execute_txn(transaction).await?
-                } else {
-                    assert!(
-                        gas_budget.is_none(),
-                        "Meaningless to set gas budget with dev-inspect"
+                // Read the dump back to confirm that every object the test knows about is in it.
+                let dumped: BTreeSet<ObjectID> = InMemoryStore::load_object_dump(&path)?
+                    .iter()
+                    .map(Object::id)
+                    .collect();
+                let mut known: Vec<_> = self
+                    .object_enumeration
+                    .iter()
+                    .filter(|(_, fake)| matches!(fake, FakeID::Enumerated(_, _)))
+                    .map(|(id, fake)| (*fake, *id))
+                    .collect();
+                known.sort();
+                for (fake, id) in &known {
+                    ensure!(
+                        dumped.contains(id),
+                        "object({fake}) is missing from the dump"
                     );
-                    let sender_address = self.get_sender(sender).address;
-                    let transaction =
-                        TransactionKind::ProgrammableTransaction(ProgrammableTransaction {
-                            inputs,
-                            commands,
-                        });
-                    self.dev_inspect(sender_address, transaction, gas_price)
-                        .await?
-                };
-                let output = self.object_summary_output(&summary, /* summarize */ false);
-                Ok(output)
+                }
+                let ids: Vec<_> = known.into_iter().map(|(_, id)| id).collect();
+                Ok(Some(format!("dumped: {}", self.list_objs(&ids, false))))
             }
-            SuiSubcommand::UpgradePackage(UpgradePackageCommand {
-                package,
-                upgrade_capability,
-                dependencies,
-                sender,
-                gas_budget,
-                syntax,
-                policy,
+            SuiSubcommand::WriteCheckpoint(WriteCheckpointCommand {
+                path,
+                sequence_number,
             }) => {
-                let syntax = syntax.unwrap_or_else(|| self.default_syntax());
-                // zero out the package name
-                let zero =
-                    NumericalAddress::new(AccountAddress::ZERO.into_bytes(), NumberFormat::Hex);
-                let before_upgrade = {
-                    // not binding `m` separately results in some strange async capture error
-                    let m = &mut self.compiled_state.named_address_mapping;
-                    let Some(before) = m.insert(package.clone(), zero) else {
-                        panic!("Unbound package '{package}' for upgrade");
-                    };
-                    before
+                let sequence_number = match sequence_number {
+                    Some(sequence_number) => sequence_number,
+                    None => self.executor.get_latest_checkpoint_sequence_number()?,
                 };
+                let checkpoint = get_checkpoint_data(&*self, sequence_number)?;
+                let path = self.scratch_dir.path().join(path);
+                std::fs::write(&path, bcs::to_bytes(&checkpoint)?)?;
+                Ok(Some(format!(
+                    "Checkpoint {sequence_number} written: {} transactions",
+                    checkpoint.transactions.len()
+                )))
+            }
+            SuiSubcommand::LoadCheckpoint(LoadCheckpointCommand { path }) => {
+                if !self.is_simulator() {
+                    bail!("Loading a checkpoint is only supported in simulator mode");
+                }
+                let path = self.scratch_dir.path().join(path);
+                let checkpoint: CheckpointData = bcs::from_bytes(&std::fs::read(&path)?)
+                    .map_err(|e| anyhow!("Invalid checkpoint {}: {e}", path.display()))?;
+                let summary = &checkpoint.checkpoint_summary;
+                let sequence_number = summary.sequence_number;
+                ensure!(
+                    checkpoint.checkpoint_contents.digest() == &summary.content_digest,
+                    "The contents of checkpoint {sequence_number} do not match its summary"
+                );
+                ensure!(
+                    sequence_number > 0,
+                    "The genesis checkpoint cannot be replayed"
+                );
+                let latest_chk = self.executor.get_latest_checkpoint_sequence_number()?;
+                ensure!(
+                    sequence_number <= latest_chk,
+                    "Checkpoint {sequence_number} has not been created, the latest is {latest_chk}"
+                );
+                let epoch = self.current_epoch()?;
+                ensure!(
+                    summary.epoch == epoch && summary.end_of_epoch_data.is_none(),
+                    "Checkpoint {sequence_number} is from epoch {}, and only checkpoints in the \
+                    middle of the current epoch ({epoch}) can be replayed",
+                    summary.epoch,
+                );
 
-                // Override address mappings for compilation when upgrading. Each dependency is set to its
-                // original address (if that dependency had been upgraded previously). This ensures upgraded
-                // dependencies are resolved during compilation--without this workaround, the compiler will
-                // find multiple definitions of the same module). We persist the original package name and
-                // addresses, which we restore before performing an upgrade transaction below.
-                let mut original_package_addrs = vec![];
-                for dep in dependencies.iter() {
-                    let named_address_mapping = &mut self.compiled_state.named_address_mapping;
-                    let dep = &Symbol::from(dep.as_str());
-                    let Some(orig_package) = self.package_upgrade_mapping.get(dep) else {
+                // Rewind the store to how it was before the checkpoint: every object goes back to
+                // the last version written by a transaction in an earlier checkpoint, and objects
+                // that did not exist then are removed.
+                let mut settled = BTreeSet::new();
+                for seq in 0..sequence_number {
+                    let chk = self
+                        .executor
+                        .get_verified_checkpoint_by_sequence_number(seq)?;
+                    let contents = self
+                        .executor
+                        .get_checkpoint_contents(chk.data().content_digest)?;
+                    settled.extend(contents.iter().map(|digests| digests.transaction));
+                }
+                let live: BTreeMap<ObjectID, Object> = self
+                    .executor
+                    .live_objects()
+                    .into_iter()
+                    .map(|obj| (obj.id(), obj))
+                    .collect();
+                let mut rewound: BTreeMap<ObjectID, Option<Object>> = BTreeMap::new();
+                for obj in live.values() {
+                    let before = self.object_before(obj.clone(), &settled)?;
+                    if before.as_ref().map(Object::version) != Some(obj.version()) {
+                        rewound.insert(obj.id(), before);
+                    }
+                }
+                for (id, task) in &self.removed_objects {
+                    if live.contains_key(id) {
+                        continue;
+                    }
+                    // Objects removed by a transaction outside of any task cannot be traced back.
+                    let Ok(effects) = self.task_effects(*task) else {
                         continue;
                     };
-                    let Some(orig_package_address) =
-                        named_address_mapping.insert(orig_package.to_string(), zero)
+                    if settled.contains(effects.transaction_digest()) {
+                        continue;
+                    }
+                    let Some((_, version)) = effects
+                        .modified_at_versions()
+                        .into_iter()
+                        .find(|(modified, _)| modified == id)
                     else {
                         continue;
                     };
-                    original_package_addrs.push((*orig_package, orig_package_address));
-                    let dep_address = named_address_mapping
-                        .insert(dep.to_string(), orig_package_address)
-                        .unwrap_or_else(||
-                            panic!("Internal error: expected dependency {dep} in map when overriding address.")
-                        );
-                    original_package_addrs.push((*dep, dep_address));
+                    let obj = self.get_object(id, Some(version))?;
+                    if let Some(before) = self.object_before(obj, &settled)? {
+                        rewound.insert(*id, Some(before));
+                    }
+                }
+                for (id, before) in rewound {
+                    match before {
+                        Some(obj) => self.executor.insert_object(obj).await?,
+                        None => self.executor.remove_object(id).await?,
+                    }
+                }
+
+                // Then feed the checkpoint's transactions back through, which must reproduce the
+                // effects they had the first time.
+                for tx in &checkpoint.transactions {
+                    let (effects, _) = self.executor.execute_txn(tx.transaction.clone()).await?;
+                    ensure!(
+                        effects.digest() == tx.effects.digest(),
+                        "Transaction {} in checkpoint {sequence_number} had different effects \
+                        when replayed",
+                        tx.transaction.digest(),
+                    );
+                }
+
+                let after: BTreeSet<ObjectID> = self
+                    .executor
+                    .live_objects()
+                    .iter()
+                    .map(Object::id)
+                    .collect();
+                let mut dropped: Vec<_> = live
+                    .keys()
+                    .filter(|id| !after.contains(id))
+                    .copied()
+                    .collect();
+                let mut restored: Vec<_> = after
+                    .iter()
+                    .filter(|id| !live.contains_key(id))
+                    .copied()
+                    .collect();
+                for id in &restored {
+                    self.enumerate_fake(*id);
+                    self.removed_objects.remove(id);
+                }
+                for id in &dropped {
+                    self.removed_objects.insert(*id, self.next_fake.0);
+                }
+                dropped.sort_by_key(|id| self.object_sort_key(id));
+                restored.sort_by_key(|id| self.object_sort_key(id));
+
+                let mut output = format!(
+                    "Checkpoint {sequence_number} loaded: {} transactions replayed",
+                    checkpoint.transactions.len()
+                );
+                if !dropped.is_empty() {
+                    write!(output, "\ndropped: {}", self.list_objs(&dropped, false)).unwrap();
+                }
+                if !restored.is_empty() {
+                    write!(output, "\nrestored: {}", self.list_objs(&restored, false)).unwrap();
+                }
+                Ok(Some(output))
+            }
+            SuiSubcommand::AssertTotalSupply(AssertTotalSupplyCommand {
+                coin_type,
+                expected,
+            }) => {
+                if !self.is_simulator() {
+                    bail!("Asserting total supply is only supported in simulator mode");
+                }
+                let state = self.compiled_state();
+                let coin_type = TypeTag::Struct(Box::new(
+                    coin_type.into_struct_tag(&|s| Some(state.resolve_named_address(s)))?,
+                ));
+                // Coins that are wrapped in other objects are not live, so are not counted
+                let total: u128 = self
+                    .executor
+                    .live_objects()
+                    .iter()
+                    .filter(|obj| obj.coin_type_maybe().as_ref() == Some(&coin_type))
+                    .map(|obj| obj.get_coin_value_unsafe() as u128)
+                    .sum();
+                if total != expected {
+                    bail!(self.stabilize_str(format!(
+                        "Total supply of {coin_type} is {total}, expected {expected}"
+                    )));
+                }
+                Ok(None)
+            }
+            SuiSubcommand::AssertCreatedCount(AssertCreatedCountCommand {
+                task,
+                expected,
+                at_most,
+            }) => {
+                let effects = self.task_effects(task)?;
+                let created = effects.created().len();
+                if at_most {
+                    ensure!(
+                        created <= expected,
+                        "Task {task} created {created} objects, expected at most {expected}"
+                    );
+                } else {
+                    ensure!(
+                        created == expected,
+                        "Task {task} created {created} objects, expected {expected}"
+                    );
+                }
+                Ok(None)
+            }
+            SuiSubcommand::AssertBalanceChange(AssertBalanceChangeCommand {
+                task,
+                account,
+                coin_type,
+                amount,
+                decrease,
+            }) => {
+                let address = self.resolve_account(&account)?;
+                let state = self.compiled_state();
+                let coin_type = TypeTag::Struct(Box::new(
+                    coin_type.into_struct_tag(&|s| Some(state.resolve_named_address(s)))?,
+                ));
+                let effects = self.task_effects(task)?;
+
+                // Only coins the transaction touched can have changed, so the balance before it
+                // ran is read from the versions of those coins it took as input.
+                let balance = |refs: Vec<(ObjectRef, Owner)>| -> anyhow::Result<i128> {
+                    let mut total = 0;
+                    for ((id, version, _), owner) in refs {
+                        if owner != Owner::AddressOwner(address) {
+                            continue;
+                        }
+                        let obj = self.get_object(&id, Some(version))?;
+                        if obj.coin_type_maybe().as_ref() == Some(&coin_type) {
+                            total += obj.get_coin_value_unsafe() as i128;
+                        }
+                    }
+                    Ok(total)
+                };
+                let before = balance(effects.old_object_metadata())?;
+                let after = balance(
+                    effects
+                        .all_changed_objects()
+                        .into_iter()
+                        .map(|(obj_ref, owner, _)| (obj_ref, owner))
+                        .collect(),
+                )?;
+
+                let actual = after - before;
+                let expected = if decrease {
+                    -(amount as i128)
+                } else {
+                    amount as i128
+                };
+                ensure!(
+                    actual == expected,
+                    "Task {task} changed the balance of {account} by {actual:+}, expected {expected:+}"
+                );
+                Ok(None)
+            }
+            SuiSubcommand::AuthenticatorStateUpdate(AuthenticatorStateUpdateCommand {
+                provider,
+            }) => {
+                if !self.is_simulator() {
+                    bail!("Updating the authenticator state is only supported in simulator mode");
+                }
+                let Some(data) = data else {
+                    bail!("Expected a JWK set in the body of authenticator-state-update")
+                };
+                let provider = OIDCProvider::from_str(&provider)
+                    .map_err(|_| anyhow!("Unknown OIDC provider '{provider}'"))?;
+                let jwks = parse_jwks(&std::fs::read(data.path())?, &provider)
+                    .map_err(|e| anyhow!("Invalid JWK set: {e}"))?;
+                let epoch = self.executor.get_system_state()?.epoch();
+                let jwks = jwks
+                    .into_iter()
+                    .map(|(jwk_id, jwk)| ActiveJwk { jwk_id, jwk, epoch })
+                    .collect();
+                self.executor.update_authenticator_state(jwks).await?;
+                Ok(None)
+            }
+            SuiSubcommand::ExecuteBatch(ExecuteBatchCommand { tasks }) => {
+                if self.is_simulator() {
+                    bail!("Executing a batch on the fast path is not supported in simulator mode");
+                }
+                let mut batch = vec![];
+                // The task that first used each owned object, to check that the transactions are
+                // independent of one another.
+                let mut owners: BTreeMap<ObjectID, u64> = BTreeMap::new();
+                for task in tasks {
+                    let Some(transaction) = self.task_transactions.get(&task) else {
+                        bail!("Task {task} did not build a transaction");
+                    };
+                    let data = &transaction.data().intent_message().value;
+                    if data.contains_shared_object() {
+                        bail!("Task {task} uses shared objects, so cannot take the fast path");
+                    }
+                    for kind in data.input_objects()? {
+                        let InputObjectKind::ImmOrOwnedMoveObject((id, _, _)) = kind else {
+                            continue;
+                        };
+                        if let Some(other) = owners.insert(id, task) {
+                            let fake = self
+                                .real_to_fake_object_id(&id)
+                                .unwrap_or(FakeID::Known(id));
+                            bail!("Tasks {other} and {task} both use object({fake})");
+                        }
+                    }
+                    batch.push((task, transaction.clone()));
+                }
+
+                // All the certificates are submitted at once, and each is executed as soon as it
+                // is certified, without waiting on consensus.
+                let mut input_ids = vec![];
+                for (_, transaction) in &batch {
+                    input_ids.push(txn_input_ids(transaction)?);
+                }
+                let (tasks, transactions): (Vec<_>, Vec<_>) = batch.into_iter().unzip();
+                let results = self
+                    .executor
+                    .execute_owned_txns_concurrently(transactions)
+                    .await?;
+
+                // Each transaction stays recorded against the task that built it.
+                let mut output = vec![];
+                for ((task, input_ids), (effects, error_opt)) in
+                    tasks.into_iter().zip(input_ids).zip(results)
+                {
+                    let summary = self
+                        .summarize_effects(
+                            input_ids, /* with_shared */ false, effects, error_opt,
+                        )
+                        .await?;
+                    let mut out = format!("task {task}: fast path");
+                    if let Some(effects) = self.object_summary_output(&summary, false, false) {
+                        write!(out, "\n{effects}").unwrap();
+                    }
+                    output.push(out);
+                }
+                Ok(Some(output.join("\n")))
+            }
+            SuiSubcommand::AssertEventField(AssertEventFieldCommand {
+                task,
+                index,
+                path,
+                expected,
+            }) => {
+                let Some(transaction) = self.task_transactions.get(&task) else {
+                    bail!("Task {task} did not build a transaction");
+                };
+                let digest = *transaction.digest();
+                let expected = match self.resolve_args(vec![expected])?.pop() {
+                    Some(SuiValue::MoveValue(expected)) => expected,
+                    _ => bail!("Expected event fields must be Move values"),
+                };
+                let events = self
+                    .executor
+                    .query_tx_events_asc(&digest, *QUERY_MAX_RESULT_LIMIT)
+                    .await?;
+                let Some(event) = events.get(index) else {
+                    bail!(
+                        "Task {task} emitted {} events, so has no event {index}",
+                        events.len()
+                    )
+                };
+                let event_struct =
+                    Event::move_event_to_move_struct(&event.type_, &event.contents, &&*self)?;
+                let actual = move_value_at_path(MoveValue::Struct(event_struct), &path)
+                    .map_err(|e| anyhow!("Event {index} of task {task}: {e}"))?
+                    .undecorate();
+                if actual != expected {
+                    bail!(self.stabilize_str(format!(
+                        "Event {index} of task {task} has {path}: {actual}, expected {expected}"
+                    )));
+                }
+                Ok(None)
+            }
+            SuiSubcommand::AssertEventTypes(AssertEventTypesCommand { task, event_types }) => {
+                let Some(transaction) = self.task_transactions.get(&task) else {
+                    bail!("Task {task} did not build a transaction");
+                };
+                let digest = *transaction.digest();
+                let state = self.compiled_state();
+                let expected = event_types
+                    .into_iter()
+                    .map(|t| t.into_struct_tag(&|s| Some(state.resolve_named_address(s))))
+                    .collect::<anyhow::Result<BTreeSet<_>>>()?;
+                let actual: BTreeSet<_> = self
+                    .executor
+                    .query_tx_events_asc(&digest, *QUERY_MAX_RESULT_LIMIT)
+                    .await?
+                    .into_iter()
+                    .map(|event| event.type_)
+                    .collect();
+                if actual != expected {
+                    let list = |types: &BTreeSet<StructTag>| {
+                        types
+                            .iter()
+                            .map(|t| t.to_string())
+                            .collect::<Vec<_>>()
+                            .join(", ")
+                    };
+                    bail!(self.stabilize_str(format!(
+                        "Task {task} emitted events of types [{}], expected [{}]",
+                        list(&actual),
+                        list(&expected),
+                    )));
+                }
+                Ok(None)
+            }
+            SuiSubcommand::ViewTxDependencies(ViewTxDependenciesCommand { task }) => {
+                let effects = self.task_effects(task)?;
+                let tasks: BTreeMap<_, _> = self
+                    .task_transactions
+                    .iter()
+                    .map(|(task, transaction)| (*transaction.digest(), *task))
+                    .collect();
+                // Digests of transactions that were not built by a task (e.g. genesis) differ
+                // between runs, so they are only counted.
+                let mut known = BTreeSet::new();
+                let mut other = 0;
+                for digest in effects.dependencies() {
+                    match tasks.get(digest) {
+                        Some(task) => {
+                            known.insert(*task);
+                        }
+                        None => other += 1,
+                    }
+                }
+                let known = known
+                    .into_iter()
+                    .map(|task| format!("task {task}"))
+                    .collect::<Vec<_>>()
+                    .join(", ");
+                Ok(Some(format!(
+                    "dependencies: {known}\nother dependencies: {other}"
+                )))
+            }
+            SuiSubcommand::ViewGasPrice(ViewGasPriceCommand { task }) => {
+                let Some(transaction) = self.task_transactions.get(&task) else {
+                    bail!("Task {task} did not build a transaction");
+                };
+                // The price is charged exactly as requested, as long as it is at least the floor
+                // for the transaction's category, which transactions are rejected for undercutting.
+                let data = transaction.data().transaction_data();
+                let gas_price = data.gas_price();
+                let reference_gas_price = self.get_reference_gas_price()?;
+                let (category, floor) = self.gas_price_floor(data)?;
+                Ok(Some(format!(
+                    "gas price: {gas_price}\n\
+                    reference gas price: {reference_gas_price}\n\
+                    gas price floor: {floor} ({category})"
+                )))
+            }
+            SuiSubcommand::ViewProtocolConfig(ViewProtocolConfigCommand { key }) => {
+                let config = &self.protocol_config;
+                let Some(key) = key else {
+                    let mut output = format!("version: {}", config.version.as_u64());
+                    for (name, value) in config.attr_map() {
+                        let value = value.map_or_else(|| "none".to_string(), |v| v.to_string());
+                        write!(output, "\n{name}: {value}").unwrap();
+                    }
+                    for (name, enabled) in config.feature_map() {
+                        write!(output, "\n{name}: {enabled}").unwrap();
+                    }
+                    return Ok(Some(output));
+                };
+                let value = if key == "version" {
+                    config.version.as_u64().to_string()
+                } else if let Some(enabled) = config.lookup_feature(key.clone()) {
+                    enabled.to_string()
+                } else if let Some((_, value)) =
+                    config.attr_map().into_iter().find(|(n, _)| *n == key)
+                {
+                    value.map_or_else(|| "none".to_string(), |v| v.to_string())
+                } else {
+                    bail!("Unknown protocol config key '{key}'");
+                };
+                Ok(Some(format!("{key}: {value}")))
+            }
+            SuiSubcommand::ReplayTransaction(ReplayTransactionCommand { digest, rpc }) => {
+                if !self.is_simulator() {
+                    bail!("Replaying transactions is only supported in simulator mode");
+                }
+                let digest = TransactionDigest::from_str(&digest)
+                    .map_err(|e| anyhow!("Invalid transaction digest {digest}: {e}"))?;
+                self.replay_transaction(digest, &rpc).await
+            }
+            SuiSubcommand::ViewValidator(ViewValidatorCommand { address }) => {
+                let address = SuiAddress::from(self.compiled_state().resolve_address(&address));
+                let summary = self
+                    .executor
+                    .get_system_state()?
+                    .into_sui_system_state_summary();
+                let Some(validator) = summary
+                    .active_validators
+                    .into_iter()
+                    .find(|v| v.sui_address == address)
+                else {
+                    bail!("No active validator with address {address}");
+                };
+                Ok(Some(format!(
+                    "voting_power: {}\n\
+                    stake: {}\n\
+                    pending_stake: {}\n\
+                    commission_rate: {}\n\
+                    rewards_pool: {}",
+                    validator.voting_power,
+                    validator.staking_pool_sui_balance,
+                    validator.pending_stake,
+                    validator.commission_rate,
+                    validator.rewards_pool,
+                )))
+            }
+            SuiSubcommand::SetGasPriceVote(SetGasPriceVoteCommand { validator, price }) => {
+                if !self.is_simulator() {
+                    bail!("Setting gas price votes is only supported in simulator mode");
+                }
+                let address = SuiAddress::from(self.compiled_state().resolve_address(&validator));
+                let summary = self
+                    .executor
+                    .get_system_state()?
+                    .into_sui_system_state_summary();
+                let Some(validator) = summary
+                    .active_validators
+                    .into_iter()
+                    .find(|v| v.sui_address == address)
+                else {
+                    bail!("No active validator with address {address}");
+                };
+                let Some(validator_key) = self.executor.validator_account_key(&address) else {
+                    bail!("The key of validator {address} is not known");
+                };
+                let cap = self
+                    .get_object(&validator.operation_cap_id, None)?
+                    .compute_object_reference();
+
+                let mut builder = ProgrammableTransactionBuilder::new();
+                builder.move_call(
+                    SUI_SYSTEM_PACKAGE_ID,
+                    Identifier::new("sui_system").unwrap(),
+                    Identifier::new("request_set_gas_price").unwrap(),
+                    vec![],
+                    vec![
+                        CallArg::SUI_SYSTEM_MUT,
+                        CallArg::Object(ObjectArg::ImmOrOwnedObject(cap)),
+                        CallArg::Pure(bcs::to_bytes(&price).unwrap()),
+                    ],
+                )?;
+                // Validators are not given gas, so the default account sponsors the vote
+                let sponsor = &self.default_account;
+                let gas_payment = self
+                    .get_object(&sponsor.gas, None)?
+                    .compute_object_reference();
+                let data = TransactionData::new_programmable_allow_sponsor(
+                    address,
+                    vec![gas_payment],
+                    builder.finish(),
+                    DEFAULT_GAS_BUDGET,
+                    self.gas_price,
+                    sponsor.address,
+                );
+                let intent_msg = IntentMessage::new(Intent::sui_transaction(), data.clone());
+                let signatures = vec![
+                    Signature::new_secure(&intent_msg, &validator_key),
+                    Signature::new_secure(&intent_msg, &sponsor.key_pair),
+                ];
+                let transaction =
+                    Transaction::from_data(data, Intent::sui_transaction(), signatures);
+                self.execute_txn(transaction).await?;
+                Ok(None)
+            }
+            SuiSubcommand::RemoveAccount(RemoveAccountCommand { name }) => {
+                if self.accounts.remove(&name).is_none() {
+                    bail!("Unknown account {name}");
+                }
+                self.compiled_state.named_address_mapping.remove(&name);
+                self.removed_accounts.insert(name);
+                Ok(None)
+            }
+            SuiSubcommand::AssertDevInspectReturn(AssertDevInspectReturnCommand {
+                function,
+                args,
+                sender,
+                expected,
+            }) => {
+                if self.is_simulator() {
+                    bail!("Dev inspect is not supported on simulator mode");
+                }
+                let state = self.compiled_state();
+                let mut call =
+                    function.into_move_call(&|s| Some(state.resolve_named_address(s)))?;
+                let expected = match self.resolve_args(vec![expected])?.pop() {
+                    Some(SuiValue::MoveValue(expected)) => expected,
+                    _ => bail!("Expected return values must be Move values"),
+                };
+                let args = self.resolve_args(args)?;
+                let mut builder = ProgrammableTransactionBuilder::new();
+                call.arguments = args
+                    .into_iter()
+                    .map(|arg| arg.into_argument(&mut builder, self))
+                    .collect::<anyhow::Result<_>>()?;
+                let function = call.function.clone();
+                builder.command(Command::MoveCall(Box::new(call)));
+                let sender = self.get_sender(sender)?.address;
+                let kind = TransactionKind::ProgrammableTransaction(builder.finish());
+                let gas_price = self.get_reference_gas_price()?;
+                let results = self
+                    .executor
+                    .dev_inspect_transaction_block(sender, kind, Some(gas_price), None)
+                    .await?;
+                if let Some(error) = results.error {
+                    bail!(self.stabilize_str(format!("Calling {function} failed: {error}")));
+                }
+                let mut return_values = results
+                    .results
+                    .and_then(|mut results| results.pop())
+                    .map(|result| result.return_values)
+                    .unwrap_or_default();
+                if return_values.len() != 1 {
+                    bail!(
+                        "{function} returned {} values, expected exactly one",
+                        return_values.len()
+                    );
+                }
+                let (bytes, type_) = return_values.pop().unwrap();
+                let type_: TypeTag = type_.try_into()?;
+                let layout = TypeLayoutBuilder::build_with_types(&type_, &&*self)?;
+                let actual = MoveValue::simple_deserialize(&bytes, &layout)?.undecorate();
+                if actual != expected {
+                    bail!(self.stabilize_str(format!(
+                        "{function} returned {actual}, expected {expected}"
+                    )));
+                }
+                Ok(None)
+            }
+            SuiSubcommand::AssertValidatorCount(AssertValidatorCountCommand { expected }) => {
+                let summary = self
+                    .executor
+                    .get_system_state()?
+                    .into_sui_system_state_summary();
+                let count = summary.active_validators.len();
+                ensure!(
+                    count == expected,
+                    "Expected {expected} active validators in epoch {}, found {count}",
+                    summary.epoch
+                );
+                Ok(None)
+            }
+            SuiSubcommand::ViewSystemState => {
+                let summary = self
+                    .executor
+                    .get_system_state()?
+                    .into_sui_system_state_summary();
+                Ok(Some(format!(
+                    "epoch: {}\n\
+                    reference_gas_price: {}\n\
+                    active_validators: {}",
+                    summary.epoch,
+                    summary.reference_gas_price,
+                    summary.active_validators.len(),
+                )))
+            }
+            SuiSubcommand::ListObjects(ListObjectsCommand {
+                owner,
+                cursor,
+                limit,
+            }) => {
+                let owner = self.resolve_account(&owner)?;
+                let cursor = match cursor {
+                    Some(fake) => Some(
+                        self.fake_to_real_object_id(fake)
+                            .ok_or_else(|| anyhow!("Unknown object, object({fake})"))?,
+                    ),
+                    None => None,
+                };
+                let limit = limit.unwrap_or(*QUERY_MAX_RESULT_LIMIT);
+                let objects = self
+                    .executor
+                    .query_objects_by_owner(owner, cursor, limit)
+                    .await?;
+                if objects.is_empty() {
+                    return Ok(Some("No objects".to_string()));
+                }
+                let ids: Vec<_> = objects.iter().map(|obj| obj.id()).collect();
+                let mut out = format!("objects: {}", self.list_objs(&ids, false));
+                // A full page may be followed by more objects
+                if ids.len() == limit {
+                    let last = ids.last().unwrap();
+                    match self.real_to_fake_object_id(last) {
+                        Some(fake) => write!(out, "\nNext cursor: {fake}").unwrap(),
+                        None => write!(out, "\nNext cursor: {last}").unwrap(),
+                    }
+                }
+                Ok(Some(out))
+            }
+            SuiSubcommand::CountObjects(CountObjectsCommand { owner, type_ }) => {
+                if !self.is_simulator() {
+                    bail!("Counting objects is only supported in simulator mode");
+                }
+                let owner = match owner {
+                    Some(owner) => Some(Owner::AddressOwner(self.resolve_account(&owner)?)),
+                    None => None,
+                };
+                let state = self.compiled_state();
+                let type_ = match type_ {
+                    Some(type_) => {
+                        Some(type_.into_struct_tag(&|s| Some(state.resolve_named_address(s)))?)
+                    }
+                    None => None,
+                };
+                // Objects that are wrapped in other objects are not live, so are not counted
+                let count = self
+                    .executor
+                    .live_objects()
+                    .iter()
+                    .filter(|obj| owner.map_or(true, |owner| obj.owner == owner))
+                    .filter(|obj| type_.is_none() || obj.struct_tag() == type_)
+                    .count();
+                Ok(Some(format!("objects: {count}")))
+            }
+            SuiSubcommand::ExecuteSigned(ExecuteSignedCommand { tx_bytes }) => {
+                let bytes = Base64::decode(&tx_bytes)
+                    .map_err(|e| anyhow!("Invalid transaction bytes: {e}"))?;
+                let data: SenderSignedData = bcs::from_bytes(&bytes)?;
+                self.execute_signed_txn(Transaction::new(data)).await
+            }
+            SuiSubcommand::AdvanceClock(AdvanceClockCommand { duration_ns }) => {
+                self.executor
+                    .advance_clock(Duration::from_nanos(duration_ns))
+                    .await?;
+                Ok(None)
+            }
+
+            SuiSubcommand::ViewObject(ViewObjectCommand {
+                id: fake_id,
+                show_size,
+            }) => {
+                let obj = get_obj!(fake_id);
+                let view = self.object_view(fake_id, &obj);
+                Ok(Some(if show_size {
+                    format!(
+                        "{view}\nStorage size: {} bytes",
+                        obj.object_size_for_gas_metering()
+                    )
+                } else {
+                    view
+                }))
+            }
+            SuiSubcommand::ViewNextFakeId => {
+                // Objects are enumerated as `(task, i)`, so the first object new to the next task
+                // is its `0`th.
+                let (task, _) = self.next_fake;
+                Ok(Some(format!(
+                    "next fake id: object({})",
+                    FakeID::Enumerated(task + 1, 0)
+                )))
+            }
+            SuiSubcommand::ViewStorageRebate(ViewStorageRebateCommand { id: fake_id }) => {
+                // The storage cost paid when the object was last written, which is refunded (less
+                // the non-refundable fee) when it is next mutated or deleted.
+                let obj = get_obj!(fake_id);
+                Ok(Some(format!("storage_rebate: {}", obj.storage_rebate)))
+            }
+            SuiSubcommand::AssertPackageDigest(AssertPackageDigestCommand {
+                id: fake_id,
+                expected,
+            }) => {
+                let obj = get_obj!(fake_id);
+                let Some(package) = obj.data.try_as_package() else {
+                    bail!("Object {fake_id} is not a package")
+                };
+                let expected = Hex::decode(expected.trim_start_matches("0x"))
+                    .map_err(|e| anyhow!("Invalid package digest {expected}: {e}"))?;
+                let actual = package.digest(/* hash_modules */ true);
+                ensure!(
+                    expected == actual,
+                    "Package {fake_id} has digest 0x{}, expected 0x{}",
+                    Hex::encode(actual),
+                    Hex::encode(&expected)
+                );
+                Ok(None)
+            }
+            SuiSubcommand::AssertObjectType(AssertObjectTypeCommand {
+                id: fake_id,
+                expected,
+            }) => {
+                let obj = get_obj!(fake_id);
+                let Some(actual) = obj.struct_tag() else {
+                    bail!("Object {fake_id} is a package, so has no type")
+                };
+                let state = self.compiled_state();
+                let expected =
+                    expected.into_struct_tag(&|s| Some(state.resolve_named_address(s)))?;
+                if actual != expected {
+                    bail!(self.stabilize_str(format!(
+                        "Object {fake_id} has an unexpected type\n\
+                        expected: {expected}\n\
+                        actual:   {actual}"
+                    )));
+                }
+                Ok(None)
+            }
+            SuiSubcommand::AssertSameTypeOrigin(AssertSameTypeOriginCommand { first, second }) => {
+                let mut origins = vec![];
+                for fake_id in [first, second] {
+                    let obj = get_obj!(fake_id);
+                    let Some(tag) = obj.struct_tag() else {
+                        bail!("Object {fake_id} is a package, so has no type")
+                    };
+                    let origin = self.type_origin(&tag)?;
+                    origins.push((tag, origin));
+                }
+                let (first_tag, first_origin) = &origins[0];
+                let (second_tag, second_origin) = &origins[1];
+                if (first_origin, &first_tag.module, &first_tag.name)
+                    != (second_origin, &second_tag.module, &second_tag.name)
+                {
+                    bail!(self.stabilize_str(format!(
+                        "Objects {first} and {second} have types with different origins\n\
+                        {first}: {first_tag} (defined in {first_origin})\n\
+                        {second}: {second_tag} (defined in {second_origin})"
+                    )));
+                }
+                Ok(None)
+            }
+            SuiSubcommand::AssertUpgradeable(AssertUpgradeableCommand { package, yes, .. }) => {
+                let Some(package_id) = self.fake_to_real_object_id(package) else {
+                    bail!("Unknown object, object({package})")
+                };
+                // Every `UpgradeCap` the test can see was created by one of its tasks, so it is
+                // enough to look through the enumerated objects.
+                let mut caps = vec![];
+                for id in self.object_enumeration.left_values() {
+                    let Ok(obj) = self.get_object(id, None) else {
+                        continue;
+                    };
+                    let Some(move_obj) = obj.data.try_as_move() else {
+                        continue;
+                    };
+                    if !move_obj.type_().is(&UpgradeCap::type_()) {
+                        continue;
+                    }
+                    let cap: UpgradeCap = bcs::from_bytes(move_obj.contents())?;
+                    if cap.package.bytes == package_id {
+                        caps.push(obj.id());
+                    }
+                }
+                match (yes, caps.as_slice()) {
+                    (true, []) => bail!("Package {package} has no UpgradeCap"),
+                    (false, [cap, ..]) => {
+                        let cap = self.real_to_fake_object_id(cap).unwrap();
+                        bail!("Package {package} is upgradeable, with UpgradeCap object({cap})")
+                    }
+                    _ => Ok(None),
+                }
+            }
+            SuiSubcommand::ViewDynamicField(ViewDynamicFieldCommand { parent, name }) => {
+                let Some(parent_id) = self.fake_to_real_object_id(parent) else {
+                    bail!(
+                        "task {}, lines {}-{}. Unbound fake id {}",
+                        number,
+                        start_line,
+                        command_lines_stop,
+                        parent
+                    )
+                };
+                let name = match self.resolve_args(vec![name])?.pop() {
+                    Some(SuiValue::MoveValue(name)) => name,
+                    _ => bail!("Dynamic field names must be Move values"),
+                };
+                let name_type = move_value_type_tag(&name)?;
+                let name_bytes = name
+                    .simple_serialize()
+                    .ok_or_else(|| anyhow!("Unable to serialize dynamic field name {name}"))?;
+                let field_id = derive_dynamic_field_id(parent_id, &name_type, &name_bytes)?;
+                let Ok(field) = self.get_object(&field_id, None) else {
+                    bail!("No dynamic field with name {name} on object {parent}")
+                };
+                let move_obj = field
+                    .data
+                    .try_as_move()
+                    .ok_or_else(|| anyhow!("Dynamic field {field_id} is not a Move object"))?;
+                let layout = move_obj.get_layout(ObjectFormatOptions::default(), &&*self)?;
+                let field_struct = MoveStruct::simple_deserialize(move_obj.contents(), &layout)?;
+                // Dynamic fields are stored as `Field { id, name, value }`
+                let value = field_struct
+                    .into_fields()
+                    .pop()
+                    .ok_or_else(|| anyhow!("Dynamic field {field_id} has no value"))?;
+                let fake_id = self.enumerate_fake(field_id);
+                Ok(Some(self.stabilize_str(format!(
+                    "Field: object({fake_id})\nValue: {value}"
+                ))))
+            }
+            SuiSubcommand::ViewObjectAtCheckpoint(ViewObjectAtCheckpointCommand {
+                id: fake_id,
+                checkpoint,
+            }) => {
+                let Some(id) = self.fake_to_real_object_id(fake_id) else {
+                    bail!("Unbound fake id {fake_id}")
+                };
+                let latest_chk = self.executor.get_latest_checkpoint_sequence_number()?;
+                ensure!(
+                    checkpoint <= latest_chk,
+                    "Checkpoint {checkpoint} has not been created, the latest is {latest_chk}"
+                );
+
+                // The object's version as of `checkpoint` is the one written by the last
+                // transaction at or before it to touch the object.
+                let mut version = None;
+                'search: for sequence_number in (0..=checkpoint).rev() {
+                    let data = get_checkpoint_data(&*self, sequence_number)?;
+                    for tx in data.transactions.iter().rev() {
+                        if let Some(obj) = tx.output_objects.iter().find(|o| o.id() == id) {
+                            version = Some(obj.version());
+                            break 'search;
+                        }
+                        let effects = &tx.effects;
+                        let removed = effects
+                            .deleted()
+                            .into_iter()
+                            .chain(effects.wrapped())
+                            .chain(effects.unwrapped_then_deleted())
+                            .any(|(removed, _, _)| removed == id);
+                        if removed {
+                            bail!(
+                                "object({fake_id}) was deleted or wrapped as of checkpoint \
+                                {checkpoint}"
+                            );
+                        }
+                    }
+                }
+                let Some(version) = version else {
+                    bail!("object({fake_id}) did not exist at checkpoint {checkpoint}")
+                };
+                let obj = self.get_object(&id, Some(version))?;
+                Ok(Some(self.object_view(fake_id, &obj)))
+            }
+            SuiSubcommand::ExpectUseDeleted(ExpectUseDeletedCommand {
+                id: fake_id,
+                sender,
+            }) => {
+                let Some(id) = self.fake_to_real_object_id(fake_id) else {
+                    bail!("Unbound fake id {fake_id}")
+                };
+                let Some(task) = self.removed_by(&id) else {
+                    bail!("object({fake_id}) has not been deleted or wrapped")
+                };
+                let Some((_, version)) = self
+                    .task_effects(task)?
+                    .modified_at_versions()
+                    .into_iter()
+                    .find(|(modified, _)| *modified == id)
+                else {
+                    bail!("object({fake_id}) was never live before task {task} removed it")
+                };
+
+                // Refer to the object as it was before it was removed, in a transaction that
+                // would otherwise do nothing with it.
+                let obj = self.get_object(&id, Some(version))?;
+                let arg = match obj.owner {
+                    Owner::Shared {
+                        initial_shared_version,
+                    } => ObjectArg::SharedObject {
+                        id,
+                        initial_shared_version,
+                        mutable: true,
+                    },
+                    _ => ObjectArg::ImmOrOwnedObject(obj.compute_object_reference()),
+                };
+                let gas_price = self.gas_price;
+                let transaction = self.sign_txn(sender, |sender, gas| {
+                    let mut builder = ProgrammableTransactionBuilder::new();
+                    builder.obj(arg).unwrap();
+                    builder.command(Command::MakeMoveVec(Some(TypeTag::U64), vec![]));
+                    TransactionData::new_programmable(
+                        sender,
+                        vec![gas],
+                        builder.finish(),
+                        DEFAULT_GAS_BUDGET,
+                        gas_price,
+                    )
+                })?;
+                let error = match self.execute_txn(transaction).await {
+                    Ok(_) => {
+                        bail!("Using object({fake_id}) succeeded after task {task} removed it")
+                    }
+                    Err(error) => error,
+                };
+                let mut error = match error.downcast::<SuiError>() {
+                    Ok(SuiError::UserInputError { error }) => error,
+                    Ok(error) => error.into(),
+                    Err(error) => error,
+                };
+                // A validator keeps the versions of removed objects, but no longer accepts them
+                // as inputs, so it reports them as unavailable rather than deleted.
+                if !self.is_simulator() {
+                    error = match error.downcast::<UserInputError>() {
+                        Ok(UserInputError::ObjectVersionUnavailableForConsumption {
+                            provided_obj_ref,
+                            ..
+                        }) if provided_obj_ref.0 == id => UserInputError::ObjectDeleted {
+                            object_ref: provided_obj_ref,
+                        }
+                        .into(),
+                        Ok(error) => error.into(),
+                        Err(error) => error,
+                    };
+                }
+                let rejected = matches!(
+                    error.downcast_ref::<UserInputError>(),
+                    Some(UserInputError::ObjectDeleted { object_ref }) if object_ref.0 == id
+                );
+                if !rejected {
+                    bail!(self.stabilize_str(format!(
+                        "Using object({fake_id}) failed with an unexpected error: {error}"
+                    )));
+                }
+                Ok(Some(format!(
+                    "object({fake_id}) was removed by task {task}, and cannot be used"
+                )))
+            }
+            SuiSubcommand::ViewStorageAccounting(ViewStorageAccountingCommand { task }) => {
+                let effects = self.task_effects(task)?;
+                let summary = effects.gas_cost_summary();
+                // The storage fund takes in the storage cost and pays out the rebate. The
+                // non-refundable part of the rebate owed on removed and mutated objects stays in
+                // the fund.
+                let fund_change = summary.storage_cost as i128 - summary.storage_rebate as i128;
+                Ok(Some(format!(
+                    "storage cost (into storage fund): {}\n\
+                     storage rebate (out of storage fund): {}\n\
+                     non-refundable storage fee (kept by storage fund): {}\n\
+                     net storage fund change: {fund_change}",
+                    summary.storage_cost,
+                    summary.storage_rebate,
+                    summary.non_refundable_storage_fee,
+                )))
+            }
+            SuiSubcommand::AssertSharedVersion(AssertSharedVersionCommand {
+                id: fake_id,
+                version,
+            }) => {
+                let obj = get_obj!(fake_id);
+                let Owner::Shared {
+                    initial_shared_version,
+                } = obj.owner
+                else {
+                    bail!("object({fake_id}) is not shared")
+                };
+                let actual = obj.version().value();
+                ensure!(
+                    actual == version,
+                    "object({fake_id}) is at version {actual}, expected version {version} \
+                     (initial shared version {})",
+                    initial_shared_version.value()
+                );
+                Ok(None)
+            }
+            SuiSubcommand::AssertTxEpoch(AssertTxEpochCommand { task, epoch }) => {
+                let executed_epoch = self.task_effects(task)?.executed_epoch();
+                ensure!(
+                    executed_epoch == epoch,
+                    "Task {task} was executed in epoch {executed_epoch}, expected epoch {epoch}"
+                );
+                Ok(None)
+            }
+            SuiSubcommand::AssertObjectUnchanged(AssertObjectUnchangedCommand {
+                id: fake_id,
+                task,
+            }) => {
+                let task = match task {
+                    Some(task) => task,
+                    None => match self.task_transactions.keys().next_back() {
+                        Some(task) => *task,
+                        None => bail!("No transaction has been built yet"),
+                    },
+                };
+                let Some(id) = self.fake_to_real_object_id(fake_id) else {
+                    bail!("Unbound fake id {fake_id}")
+                };
+                let effects = self.task_effects(task)?;
+                let Some((_, before)) = effects
+                    .modified_at_versions()
+                    .into_iter()
+                    .find(|(modified, _)| *modified == id)
+                else {
+                    // The transaction did not touch the object at all.
+                    return Ok(None);
+                };
+                let Some(((_, after, _), _, _)) = effects
+                    .all_changed_objects()
+                    .into_iter()
+                    .find(|((changed, _, _), _, _)| *changed == id)
+                else {
+                    bail!("Task {task} deleted or wrapped object({fake_id})")
+                };
+                let before = self.get_object(&id, Some(before))?;
+                let after = self.get_object(&id, Some(after))?;
+                let changes = self.object_changes(fake_id, &before, &after)?;
+                if !changes.is_empty() {
+                    bail!(self.stabilize_str(format!(
+                        "Task {task} changed object({fake_id}):\n{}",
+                        changes.join("\n")
+                    )));
+                }
+                Ok(None)
+            }
+            SuiSubcommand::ViewOwnerChain(ViewOwnerChainCommand { id: fake_id }) => {
+                let Some(mut id) = self.fake_to_real_object_id(fake_id) else {
+                    bail!("Unknown object, object({fake_id})")
+                };
+                let mut seen = BTreeSet::new();
+                let mut chain = vec![format!("object({fake_id})")];
+                loop {
+                    if !seen.insert(id) {
+                        bail!("Objects in the owner chain of object({fake_id}) own each other");
+                    }
+                    let owner = self.get_object(&id, None)?.owner;
+                    let Owner::ObjectOwner(parent) = owner else {
+                        chain.push(format!("-> {owner}"));
+                        break;
+                    };
+                    id = parent.into();
+                    let parent = self.enumerate_fake(id);
+                    chain.push(format!("-> object({parent})"));
+                }
+                Ok(Some(self.stabilize_str(chain.join("\n"))))
+            }
+            SuiSubcommand::ViewDynamicFieldChanges(ViewDynamicFieldChangesCommand {
+                task,
+                parent,
+            }) => {
+                let Some(parent_id) = self.fake_to_real_object_id(parent) else {
+                    bail!(
+                        "task {}, lines {}-{}. Unbound fake id {}",
+                        number,
+                        start_line,
+                        command_lines_stop,
+                        parent
+                    )
+                };
+                let effects = self.task_effects(task)?;
+                let owner = Owner::ObjectOwner(parent_id.into());
+
+                // Fields that were both added and removed by the transaction do not appear in its
+                // effects, so these are already the net changes.
+                let added: Vec<_> = effects
+                    .created()
+                    .into_iter()
+                    .filter(|(_, o)| *o == owner)
+                    .map(|((id, version, _), _)| (id, version))
+                    .collect();
+                let deleted: BTreeSet<_> =
+                    effects.deleted().into_iter().map(|(id, _, _)| id).collect();
+                let removed: Vec<_> = effects
+                    .old_object_metadata()
+                    .into_iter()
+                    .filter(|((id, _, _), o)| *o == owner && deleted.contains(id))
+                    .map(|((id, version, _), _)| (id, version))
+                    .collect();
+
+                let mut output = vec![];
+                for (kind, fields) in [("added", added), ("removed", removed)] {
+                    let mut fields = fields
+                        .into_iter()
+                        .map(|(id, version)| {
+                            let field = self.get_object(&id, Some(version))?;
+                            let move_obj = field.data.try_as_move().unwrap();
+                            let layout =
+                                move_obj.get_layout(ObjectFormatOptions::default(), &&*self)?;
+                            let mut field_struct =
+                                MoveStruct::simple_deserialize(move_obj.contents(), &layout)?
+                                    .into_fields();
+                            // Dynamic fields are stored as `Field { id, name, value }`
+                            let value = field_struct.pop().unwrap();
+                            let name = field_struct.pop().unwrap();
+                            Ok(format!("{kind}: {name} => {value}"))
+                        })
+                        .collect::<anyhow::Result<Vec<_>>>()?;
+                    fields.sort();
+                    output.extend(fields);
+                }
+                if output.is_empty() {
+                    return Ok(Some(format!(
+                        "No dynamic fields of object({parent}) changed"
+                    )));
+                }
+                Ok(Some(self.stabilize_str(output.join("\n"))))
+            }
+            SuiSubcommand::DiffObject(DiffObjectCommand {
+                id: fake_id,
+                from,
+                to,
+            }) => {
+                let from_obj = get_obj!(fake_id, Some(SequenceNumber::from_u64(from)));
+                let to_obj = get_obj!(fake_id, Some(SequenceNumber::from_u64(to)));
+                let changes = self.object_changes(fake_id, &from_obj, &to_obj)?;
+                if changes.is_empty() {
+                    return Ok(Some("No changes".to_string()));
+                }
+                Ok(Some(self.stabilize_str(changes.join("\n"))))
+            }
+            SuiSubcommand::ViewPackageIds(ViewPackageIdsCommand { id: fake_id }) => {
+                let obj = get_obj!(fake_id);
+                let Some(package) = obj.data.try_as_package() else {
+                    bail!("Object {fake_id} is not a package")
+                };
+                // Types keep the original id across upgrades, while calls go to the storage id of
+                // the version being called.
+                Ok(Some(self.stabilize_str(format!(
+                    "original id: {}\nstorage id: {}\nversion: {}",
+                    package.original_package_id(),
+                    package.id(),
+                    package.version().value()
+                ))))
+            }
+            SuiSubcommand::ViewLinkage(ViewLinkageCommand { id: fake_id }) => {
+                let obj = get_obj!(fake_id);
+                let Some(package) = obj.data.try_as_package() else {
+                    bail!("Object {fake_id} is not a package")
+                };
+                let linkage = package
+                    .linkage_table()
+                    .iter()
+                    .map(|(original_id, info)| {
+                        format!(
+                            "{original_id} -> {} (version {})",
+                            info.upgraded_id,
+                            info.upgraded_version.value()
+                        )
+                    })
+                    .collect::<Vec<_>>()
+                    .join("\n");
+                Ok(Some(self.stabilize_str(linkage)))
+            }
+            SuiSubcommand::TransferObject(TransferObjectCommand {
+                id: fake_id,
+                recipient,
+                sender,
+                gas_budget,
+            }) => {
+                // Accounts are named addresses too, so this also covers addresses bound later
+                // with `set-address`
+                if let ParsedAddress::Named(name) = &recipient {
+                    if !self
+                        .compiled_state()
+                        .named_address_mapping
+                        .contains_key(name)
+                    {
+                        bail!("Unbound account {name}")
+                    }
+                }
+                let recipient = SuiAddress::from(self.compiled_state().resolve_address(&recipient));
+                self.transfer_object(fake_id, recipient, sender, gas_budget)
+                    .await
+            }
+            SuiSubcommand::TransferToObject(TransferToObjectCommand {
+                child,
+                parent,
+                sender,
+                gas_budget,
+            }) => {
+                let child_obj = get_obj!(child);
+                match child_obj.data.try_as_move() {
+                    Some(move_obj) if move_obj.has_public_transfer() => (),
+                    Some(_) => bail!(
+                        "Object {} does not have 'store' and cannot be transferred to object {}",
+                        child,
+                        parent
+                    ),
+                    None => bail!("Package {} cannot be transferred to an object", child),
+                }
+                let parent_obj = get_obj!(parent);
+                if parent_obj.is_package() {
+                    bail!("Cannot transfer object {} to package {}", child, parent);
+                }
+                // Objects are owned by other objects by being sent to the address derived from the
+                // parent's id, which results in `Owner::ObjectOwner(parent)`.
+                let recipient = SuiAddress::from(parent_obj.id());
+                self.transfer_object(child, recipient, sender, gas_budget)
+                    .await
+            }
+            SuiSubcommand::MintCoin(MintCoinCommand {
+                treasury,
+                amount,
+                recipient,
+                sender,
+                gas_budget,
+            }) => {
+                let cap = get_obj!(treasury);
+                let coin_type = match cap.type_() {
+                    Some(type_) if type_.is_treasury_cap() => type_.type_params().remove(0),
+                    _ => bail!("Object {treasury} is not a TreasuryCap"),
+                };
+                if let ParsedAddress::Named(name) = &recipient {
+                    if !self
+                        .compiled_state()
+                        .named_address_mapping
+                        .contains_key(name)
+                    {
+                        bail!("Unbound account {name}")
+                    }
+                }
+                let recipient = SuiAddress::from(self.compiled_state().resolve_address(&recipient));
+
+                let mut builder = ProgrammableTransactionBuilder::new();
+                let cap_arg = SuiValue::Object(treasury, None).into_argument(&mut builder, self)?;
+                let amount_arg = builder.pure(amount)?;
+                let recipient_arg = builder.pure(recipient)?;
+                builder.programmable_move_call(
+                    SUI_FRAMEWORK_PACKAGE_ID,
+                    ident_str!("coin").to_owned(),
+                    ident_str!("mint_and_transfer").to_owned(),
+                    vec![coin_type],
+                    vec![cap_arg, amount_arg, recipient_arg],
+                );
+                let pt = builder.finish();
+                let gas_budget = gas_budget.unwrap_or(DEFAULT_GAS_BUDGET);
+                let gas_price = self.gas_price;
+                let transaction = self.sign_txn(sender, |sender, gas| {
+                    TransactionData::new_programmable(sender, vec![gas], pt, gas_budget, gas_price)
+                })?;
+                let summary = self.execute_txn(transaction).await?;
+                Ok(self.object_summary_output(
+                    &summary, /* summarize */ false, /* show_inputs */ false,
+                ))
+            }
+            SuiSubcommand::ConsensusCommitPrologue(ConsensusCommitPrologueCommand {
+                timestamp_ms,
+            }) => {
+                let transaction =
+                    VerifiedTransaction::new_consensus_commit_prologue(0, 0, timestamp_ms);
+                let summary = self.execute_txn(transaction.into()).await?;
+                let output = self.object_summary_output(
+                    &summary, /* summarize */ false, /* show_inputs */ false,
+                );
+                Ok(output)
+            }
+            SuiSubcommand::RandomnessStateUpdate(RandomnessStateUpdateCommand {
+                round,
+                random_bytes,
+            }) => {
+                let random_bytes = Hex::decode(random_bytes.trim_start_matches("0x"))
+                    .map_err(|e| anyhow!("Invalid random bytes for round {round}: {e}"))?;
+                // The randomness state object and its system transaction do not exist in this
+                // version of the framework, so there is nothing to inject the bytes into yet.
+                bail!(
+                    "Cannot update randomness state for round {round} ({} bytes): \
+                    randomness is not supported by this version of the protocol",
+                    random_bytes.len()
+                )
+            }
+            SuiSubcommand::ProgrammableTransaction(ProgrammableTransactionCommand {
+                sender,
+                gas_budget,
+                gas_price,
+                dev_inspect,
+                dev_inspect_gas,
+                auto_gas_budget,
+                compute_digest_only,
+                show_inputs,
+                show_events,
+                multisig_signers,
+                pad_to,
+                inputs,
+            }) => {
+                if dev_inspect && self.is_simulator() {
+                    bail!("Dev inspect is not supported on simulator mode");
+                }
+                if auto_gas_budget && self.is_simulator() {
+                    bail!("Auto gas budget is not supported on simulator mode");
+                }
+
+                let inputs = self.resolve_args(inputs)?;
+                let inputs: Vec<CallArg> = inputs
+                    .into_iter()
+                    .map(|arg| arg.into_call_arg(self))
+                    .collect::<anyhow::Result<_>>()?;
+                let file = data.ok_or_else(|| {
+                    anyhow::anyhow!("Missing commands for programmable transaction")
+                })?;
+                let contents = std::fs::read_to_string(file.path())?;
+                let commands = ParsedCommand::parse_vec(&contents)?;
+                let staged = &self.staged_modules;
+                let state = &self.compiled_state;
+                let commands = commands
+                    .into_iter()
+                    .map(|c| {
+                        c.into_command(
+                            &|p| {
+                                let modules = staged
+                                    .get(&Symbol::from(p))?
+                                    .modules
+                                    .iter()
+                                    .map(|(_n, m)| {
+                                        let mut buf = vec![];
+                                        m.serialize(&mut buf).unwrap();
+                                        buf
+                                    })
+                                    .collect();
+                                Some(modules)
+                            },
+                            &|s| match parse_package_ref(s) {
+                                Some(task) => self.task_package(task).ok().map(|id| id.into()),
+                                None => Some(state.resolve_named_address(s)),
+                            },
+                        )
+                    })
+                    .collect::<anyhow::Result<Vec<Command>>>()?;
+                let multisig = sender
+                    .clone()
+                    .filter(|sender| self.multisig_accounts.contains_key(sender));
+                if multisig.is_none() && multisig_signers.is_some() {
+                    bail!("--multisig-signers requires a multisig --sender");
+                }
+                let zklogin = match (&sender, &self.zklogin_account) {
+                    (Some(sender), Some((name, address))) if sender == name => Some(*address),
+                    _ => None,
+                };
+                if multisig.is_some() && auto_gas_budget {
+                    bail!("--auto-gas-budget is not supported for multisig senders");
+                }
+                // Nobody holds the key for the system address, so it can only be simulated.
+                let system_sender = sender.as_deref() == Some(SYSTEM_SENDER);
+                if system_sender && !dev_inspect {
+                    bail!("The system sender cannot sign transactions, only --dev-inspect them");
+                }
+                let mut chosen_budget = None;
+                let mut summary = if !dev_inspect {
+                    let gas_price = gas_price.unwrap_or(self.gas_price);
+                    let pt = ProgrammableTransaction { inputs, commands };
+                    let gas_budget = if auto_gas_budget {
+                        let budget = self
+                            .estimate_gas_budget(sender.clone(), pt.clone(), gas_price)
+                            .await?;
+                        chosen_budget = Some(budget);
+                        budget
+                    } else {
+                        gas_budget.unwrap_or(DEFAULT_GAS_BUDGET)
+                    };
+                    let sign = |pt: ProgrammableTransaction| -> anyhow::Result<Transaction> {
+                        Ok(if let Some(multisig) = &multisig {
+                            self.sign_multisig_txn(
+                                multisig,
+                                multisig_signers.clone(),
+                                |sender, gas, sponsor| {
+                                    TransactionData::new_programmable_allow_sponsor(
+                                        sender,
+                                        vec![gas],
+                                        pt,
+                                        gas_budget,
+                                        gas_price,
+                                        sponsor,
+                                    )
+                                },
+                            )?
+                        } else if let Some(zklogin) = zklogin {
+                            self.sign_zklogin_txn(zklogin, |sender, gas, sponsor| {
+                                TransactionData::new_programmable_allow_sponsor(
+                                    sender,
+                                    vec![gas],
+                                    pt,
+                                    gas_budget,
+                                    gas_price,
+                                    sponsor,
+                                )
+                            })?
+                        } else {
+                            self.sign_txn(sender.clone(), |sender, gas| {
+                                TransactionData::new_programmable(
+                                    sender,
+                                    vec![gas],
+                                    pt,
+                                    gas_budget,
+                                    gas_price,
+                                )
+                            })?
+                        })
+                    };
+                    let transaction = match pad_to {
+                        Some(target) => {
+                            let max_input_size =
+                                self.protocol_config.max_pure_argument_size() as usize;
+                            pad_transaction(pt, target, max_input_size, sign)?
+                        }
+                        None => sign(pt)?,
+                    };
+                    // Validators refuse to sign for transactions over the limit, before they are
+                    // executed.
+                    let tx_size = bcs::serialized_size(&transaction)?;
+                    let max_tx_size_bytes = self.protocol_config.max_tx_size_bytes();
+                    if tx_size as u64 > max_tx_size_bytes {
+                        bail!(SuiError::UserInputError {
+                            error: UserInputError::SizeLimitExceeded {
+                                limit: format!(
+                                    "serialized transaction size exceeded maximum of \
+                                    {max_tx_size_bytes}"
+                                ),
+                                value: tx_size.to_string(),
+                            }
+                        });
+                    }
+                    if compute_digest_only {
+                        self.task_transactions
+                            .insert(self.next_fake.0, transaction.clone());
+                        return Ok(Some(format!(
+                            "Transaction digest: {}",
+                            transaction.digest()
+                        )));
+                    }
+                    self.execute_txn(transaction).await?
+                } else {
+                    assert!(
+                        gas_budget.is_none(),
+                        "Meaningless to set gas budget with dev-inspect"
+                    );
+                    let sender_address = match multisig {
+                        Some(multisig) => self.multisig_accounts[&multisig].address,
+                        None if system_sender => SuiAddress::ZERO,
+                        None => self.get_sender(sender)?.address,
+                    };
+                    let transaction =
+                        TransactionKind::ProgrammableTransaction(ProgrammableTransaction {
+                            inputs,
+                            commands,
+                        });
+                    let gas_object = match dev_inspect_gas {
+                        Some(fake) => Some(
+                            self.fake_to_real_object_id(fake)
+                                .ok_or_else(|| anyhow!("Unknown object, object({fake})"))?,
+                        ),
+                        None => None,
+                    };
+                    self.dev_inspect(sender_address, transaction, gas_price, gas_object)
+                        .await?
+                };
+                let events = if show_events {
+                    let events = std::mem::take(&mut summary.events)
+                        .into_iter()
+                        .enumerate()
+                        .map(|(seq, event)| {
+                            let event = self.list_events(&[event], /* summarize */ false);
+                            format!("task {number} #{seq}: {event}\n")
+                        })
+                        .collect::<String>();
+                    Some(if events.is_empty() {
+                        "No events\n".to_string()
+                    } else {
+                        format!("events:\n{events}")
+                    })
+                } else {
+                    None
+                };
+                let output = merge_output(
+                    events,
+                    self.object_summary_output(&summary, /* summarize */ false, show_inputs),
+                );
+                Ok(match chosen_budget {
+                    Some(budget) => merge_output(Some(format!("Gas budget: {budget}\n")), output),
+                    None => output,
+                })
+            }
+            SuiSubcommand::UpgradePackage(UpgradePackageCommand {
+                package,
+                upgrade_capability,
+                dependencies,
+                sender,
+                gas_budget,
+                syntax,
+                policy,
+            }) => {
+                let syntax = syntax.unwrap_or_else(|| self.default_syntax());
+                // zero out the package name
+                let zero =
+                    NumericalAddress::new(AccountAddress::ZERO.into_bytes(), NumberFormat::Hex);
+                let before_upgrade = {
+                    // not binding `m` separately results in some strange async capture error
+                    let m = &mut self.compiled_state.named_address_mapping;
+                    let Some(before) = m.insert(package.clone(), zero) else {
+                        panic!("Unbound package '{package}' for upgrade");
+                    };
+                    before
+                };
+
+                // Override address mappings for compilation when upgrading. Each dependency is set to its
+                // original address (if that dependency had been upgraded previously). This ensures upgraded
+                // dependencies are resolved during compilation--without this workaround, the compiler will
+                // find multiple definitions of the same module). We persist the original package name and
+                // addresses, which we restore before performing an upgrade transaction below.
+                let mut original_package_addrs = vec![];
+                for dep in dependencies.iter() {
+                    let named_address_mapping = &mut self.compiled_state.named_address_mapping;
+                    let dep = &Symbol::from(dep.as_str());
+                    let Some(orig_package) = self.package_upgrade_mapping.get(dep) else {
+                        continue;
+                    };
+                    let Some(orig_package_address) =
+                        named_address_mapping.insert(orig_package.to_string(), zero)
+                    else {
+                        continue;
+                    };
+                    original_package_addrs.push((*orig_package, orig_package_address));
+                    let dep_address = named_address_mapping
+                        .insert(dep.to_string(), orig_package_address)
+                        .unwrap_or_else(||
+                            panic!("Internal error: expected dependency {dep} in map when overriding address.")
+                        );
+                    original_package_addrs.push((*dep, dep_address));
                 }
 
                 let result = compile_any(
@@ -854,6 +2790,8 @@ impl<'a> MoveTestAdapter<'a> for SuiTestAdapter<'a> {
                     SuiValue::Digest(_) => bail!("digest is not supported as an input"),
                     SuiValue::ObjVec(_) => bail!("obj vec is not supported as an input"),
                     SuiValue::Receiving(_, _) => bail!("receiving is not supported as an input"),
+                    SuiValue::ObjectId(_) => bail!("id is not supported as an input"),
+                    SuiValue::NamedStruct(..) => bail!("struct is not supported as an input"),
                 };
                 let value = NumericalAddress::new(value.into_bytes(), NumberFormat::Hex);
                 self.compiled_state
@@ -877,6 +2815,19 @@ impl<'a> MoveTestAdapter<'a> for SuiTestAdapter<'a> {
     }
 }
 
+/// The ids of the objects `transaction` takes as input, other than packages.
+fn txn_input_ids(transaction: &Transaction) -> anyhow::Result<Vec<ObjectID>> {
+    Ok(transaction
+        .data()
+        .intent_message()
+        .value
+        .input_objects()?
+        .into_iter()
+        .filter(|kind| !matches!(kind, InputObjectKind::MovePackage(_)))
+        .map(|kind| kind.object_id())
+        .collect())
+}
+
 fn merge_output(left: Option<String>, right: Option<String>) -> Option<String> {
     match (left, right) {
         (None, right) => right,
@@ -888,6 +2839,89 @@ fn merge_output(left: Option<String>, right: Option<String>) -> Option<String> {
     }
 }
 
+/// Record a line in `changes` for every field that differs between `from` and `to`, named by its
+/// dotted path from the root struct. Structs are compared field by field, and any other value is
+/// reported whole.
+fn diff_move_values(path: &str, from: &MoveValue, to: &MoveValue, changes: &mut Vec<String>) {
+    fn fields(value: &MoveValue) -> Option<&[(Identifier, MoveValue)]> {
+        match value {
+            MoveValue::Struct(
+                MoveStruct::WithFields(fields) | MoveStruct::WithTypes { fields, .. },
+            ) => Some(fields),
+            _ => None,
+        }
+    }
+    match (fields(from), fields(to)) {
+        (Some(from_fields), Some(to_fields)) => {
+            for ((name, from_field), (_, to_field)) in from_fields.iter().zip(to_fields) {
+                let path = if path.is_empty() {
+                    name.to_string()
+                } else {
+                    format!("{path}.{name}")
+                };
+                diff_move_values(&path, from_field, to_field, changes);
+            }
+        }
+        _ if from != to => changes.push(format!("{path}: {from} -> {to}")),
+        _ => (),
+    }
+}
+
+/// The value found by following `path`, a dot separated sequence of struct field names and vector
+/// indices, from `value`.
+fn move_value_at_path(mut value: MoveValue, path: &str) -> anyhow::Result<MoveValue> {
+    let mut prefix = String::new();
+    for segment in path.split('.') {
+        value = match value {
+            MoveValue::Struct(
+                MoveStruct::WithFields(fields) | MoveStruct::WithTypes { fields, .. },
+            ) => match fields
+                .into_iter()
+                .find(|(name, _)| name.as_str() == segment)
+            {
+                Some((_, field)) => field,
+                None => bail!("no field '{segment}' at '{prefix}'"),
+            },
+            MoveValue::Vector(mut elems) => match segment.parse::<usize>() {
+                Ok(i) if i < elems.len() => elems.swap_remove(i),
+                _ => bail!(
+                    "no element '{segment}' in vector of length {} at '{prefix}'",
+                    elems.len()
+                ),
+            },
+            _ => bail!("no field '{segment}' at '{prefix}', which is not a struct or vector"),
+        };
+        if !prefix.is_empty() {
+            prefix.push('.');
+        }
+        prefix.push_str(segment);
+    }
+    Ok(value)
+}
+
+/// The type of `value`, for values whose type can be read off the value itself.
+fn move_value_type_tag(value: &MoveValue) -> anyhow::Result<TypeTag> {
+    Ok(match value {
+        MoveValue::U8(_) => TypeTag::U8,
+        MoveValue::U16(_) => TypeTag::U16,
+        MoveValue::U32(_) => TypeTag::U32,
+        MoveValue::U64(_) => TypeTag::U64,
+        MoveValue::U128(_) => TypeTag::U128,
+        MoveValue::U256(_) => TypeTag::U256,
+        MoveValue::Bool(_) => TypeTag::Bool,
+        MoveValue::Address(_) => TypeTag::Address,
+        MoveValue::Signer(_) => TypeTag::Signer,
+        MoveValue::Vector(elems) => match elems.first() {
+            Some(elem) => TypeTag::Vector(Box::new(move_value_type_tag(elem)?)),
+            None => bail!("Cannot infer the element type of an empty vector"),
+        },
+        MoveValue::Struct(MoveStruct::WithTypes { type_, .. }) => {
+            TypeTag::Struct(Box::new(type_.clone()))
+        }
+        MoveValue::Struct(_) => bail!("Cannot infer the type of struct value {value}"),
+    })
+}
+
 impl<'a> SuiTestAdapter<'a> {
     pub fn is_simulator(&self) -> bool {
         self.is_simulator
@@ -901,6 +2935,41 @@ impl<'a> SuiTestAdapter<'a> {
         self.executor
     }
 
+    /// The reference to the latest version of the object `fake_id` refers to, or `None` if there
+    /// is no such object, e.g. because it was deleted or wrapped.
+    pub fn get_object_ref(&self, fake_id: FakeID) -> SuiResult<Option<ObjectRef>> {
+        let Some(id) = self.fake_to_real_object_id(fake_id) else {
+            return Ok(None);
+        };
+        Ok(
+            sui_types::storage::ObjectStore::get_object(&*self.executor, &id)?
+                .map(|obj| obj.compute_object_reference()),
+        )
+    }
+
+    /// Deserialize the contents of the object `fake_id` refers to into `T`, after checking that
+    /// it is a Move object of type `expected_type`. `T` must mirror the layout of the Move type,
+    /// field for field, for BCS to decode it.
+    pub fn read_object_as<T: DeserializeOwned>(
+        &self,
+        fake_id: FakeID,
+        expected_type: &StructTag,
+    ) -> anyhow::Result<T> {
+        let Some(id) = self.fake_to_real_object_id(fake_id) else {
+            bail!("Unknown object, object({fake_id})")
+        };
+        let obj = self.get_object(&id, None)?;
+        let Some(move_obj) = obj.data.try_as_move() else {
+            bail!("object({fake_id}) is a package, not a Move object")
+        };
+        ensure!(
+            move_obj.is_type(expected_type),
+            "object({fake_id}) has type {}, expected {expected_type}",
+            move_obj.type_()
+        );
+        Ok(bcs::from_bytes(move_obj.contents())?)
+    }
+
     async fn upgrade_package(
         &mut self,
         before_upgrade: NumericalAddress,
@@ -948,73 +3017,364 @@ impl<'a> SuiTestAdapter<'a> {
         let upgrade_receipt =
             builder.upgrade(package_id, upgrade_ticket, dependencies, modules_bytes);
 
-        builder.programmable_move_call(
-            SUI_FRAMEWORK_PACKAGE_ID,
-            ident_str!("package").to_owned(),
-            ident_str!("commit_upgrade").to_owned(),
-            vec![],
-            vec![Argument::Input(0), upgrade_receipt],
-        );
+        builder.programmable_move_call(
+            SUI_FRAMEWORK_PACKAGE_ID,
+            ident_str!("package").to_owned(),
+            ident_str!("commit_upgrade").to_owned(),
+            vec![],
+            vec![Argument::Input(0), upgrade_receipt],
+        );
+
+        let pt = builder.finish();
+
+        let gas_price = self.gas_price;
+        let data = |sender, gas| {
+            TransactionData::new_programmable(sender, vec![gas], pt, gas_budget, gas_price)
+        };
+
+        let transaction = self.sign_txn(Some(sender), data)?;
+        let summary = self.execute_txn(transaction).await?;
+        let created_package = summary
+            .created
+            .iter()
+            .find_map(|id| {
+                let object = self.get_object(id, None).unwrap();
+                let package = object.data.try_as_package()?;
+                Some(package.id())
+            })
+            .unwrap();
+        let package_addr = NumericalAddress::new(created_package.into_bytes(), NumberFormat::Hex);
+        if let Some(new_package_name) = modules[0].0 {
+            let prev_package = self
+                .compiled_state
+                .named_address_mapping
+                .insert(new_package_name.to_string(), package_addr);
+            match prev_package.map(|a| a.into_inner()) {
+                Some(addr) if addr != AccountAddress::ZERO => panic!(
+                    "Cannot reuse named address '{}' for multiple packages. \
+                It should be set to 0 initially",
+                    new_package_name
+                ),
+                _ => (),
+            }
+        }
+        let output = self.object_summary_output(
+            &summary, /* summarize */ false, /* show_inputs */ false,
+        );
+        Ok(output)
+    }
+
+    /// Submit a transaction that was signed outside of the adapter, as-is. Its signatures are
+    /// checked like any other transaction's, so this can exercise authentication failures.
+    pub async fn execute_signed_txn(
+        &mut self,
+        transaction: Transaction,
+    ) -> anyhow::Result<Option<String>> {
+        let summary = self.execute_txn(transaction).await?;
+        let output = self.object_summary_output(
+            &summary, /* summarize */ false, /* show_inputs */ false,
+        );
+        Ok(output)
+    }
+
+    async fn transfer_object(
+        &mut self,
+        fake_id: FakeID,
+        recipient: SuiAddress,
+        sender: Option<String>,
+        gas_budget: Option<u64>,
+    ) -> anyhow::Result<Option<String>> {
+        let mut builder = ProgrammableTransactionBuilder::new();
+        let obj_arg = SuiValue::Object(fake_id, None).into_argument(&mut builder, self)?;
+        let gas_budget = gas_budget.unwrap_or(DEFAULT_GAS_BUDGET);
+        let gas_price = self.gas_price;
+        let transaction = self.sign_txn(sender, |sender, gas| {
+            let rec_arg = builder.pure(recipient).unwrap();
+            builder.command(sui_types::transaction::Command::TransferObjects(
+                vec![obj_arg],
+                rec_arg,
+            ));
+            let pt = builder.finish();
+            TransactionData::new_programmable(sender, vec![gas], pt, gas_budget, gas_price)
+        })?;
+        let summary = self.execute_txn(transaction).await?;
+        let output = self.object_summary_output(
+            &summary, /* summarize */ false, /* show_inputs */ false,
+        );
+        Ok(output)
+    }
+
+    /// Pick a gas budget for running `pt` as `sender`: the gas a dry run of it is charged, plus
+    /// `AUTO_GAS_BUDGET_MARGIN_PERCENT` to absorb differences between the dry run and the real
+    /// execution.
+    async fn estimate_gas_budget(
+        &self,
+        sender: Option<String>,
+        pt: ProgrammableTransaction,
+        gas_price: u64,
+    ) -> anyhow::Result<u64> {
+        let test_account = self.get_sender(sender)?;
+        let gas_payment = self
+            .get_object(&test_account.gas, None)?
+            .compute_object_reference();
+        let data = TransactionData::new_programmable(
+            test_account.address,
+            vec![gas_payment],
+            pt,
+            DEFAULT_GAS_BUDGET,
+            gas_price,
+        );
+        let effects = self.executor.dry_run_transaction_block(data).await?;
+        let cost = effects.gas_cost_summary();
+        let charged = cost.computation_cost + cost.storage_cost;
+        Ok(charged + charged * AUTO_GAS_BUDGET_MARGIN_PERCENT / 100)
+    }
+
+    fn sign_txn(
+        &self,
+        sender: Option<String>,
+        txn_data: impl FnOnce(/* sender */ SuiAddress, /* gas */ ObjectRef) -> TransactionData,
+    ) -> anyhow::Result<Transaction> {
+        let test_account = self.get_sender(sender)?;
+        let gas_payment = self
+            .get_object(&test_account.gas, None)?
+            .compute_object_reference();
+        let data = txn_data(test_account.address, gas_payment);
+        Ok(to_sender_signed_transaction(data, &test_account.key_pair))
+    }
+
+    /// Sign a transaction sent by the multisig account `name`, combining signatures from
+    /// `signers`, or from its members in declaration order until the threshold is met. Multisig
+    /// accounts have no gas of their own, so the default account sponsors the transaction.
+    fn sign_multisig_txn(
+        &self,
+        name: &str,
+        signers: Option<Vec<String>>,
+        txn_data: impl FnOnce(
+            /* sender */ SuiAddress,
+            /* gas */ ObjectRef,
+            /* sponsor */ SuiAddress,
+        ) -> TransactionData,
+    ) -> anyhow::Result<Transaction> {
+        let multisig = &self.multisig_accounts[name];
+        let signers = match signers {
+            Some(signers) => {
+                let mut indices = signers
+                    .iter()
+                    .map(|signer| {
+                        multisig
+                            .members
+                            .iter()
+                            .position(|member| member == signer)
+                            .ok_or_else(|| {
+                                anyhow!("{signer} is not a member of multisig account {name}")
+                            })
+                    })
+                    .collect::<anyhow::Result<Vec<_>>>()?;
+                // Signatures must be combined in the order of the members' keys
+                indices.sort();
+                indices.dedup();
+                indices
+            }
+            None => {
+                let mut signers = vec![];
+                let mut weight = 0;
+                for (i, (_, member_weight)) in multisig.public_key.pubkeys().iter().enumerate() {
+                    if weight >= *multisig.public_key.threshold() {
+                        break;
+                    }
+                    signers.push(i);
+                    weight += *member_weight as u16;
+                }
+                signers
+            }
+        };
+
+        let sponsor = &self.default_account;
+        let gas_payment = self
+            .get_object(&sponsor.gas, None)?
+            .compute_object_reference();
+        let data = txn_data(multisig.address, gas_payment, sponsor.address);
+        let intent_msg = IntentMessage::new(Intent::sui_transaction(), data.clone());
+        let signatures = signers
+            .into_iter()
+            .map(|i| {
+                let member = self.get_sender(Some(multisig.members[i].clone()))?;
+                Ok(Signature::new_secure(&intent_msg, &member.key_pair))
+            })
+            .collect::<anyhow::Result<_>>()?;
+        let multisig_signature = MultiSig::combine(signatures, multisig.public_key.clone())?;
+        let sponsor_signature = Signature::new_secure(&intent_msg, &sponsor.key_pair);
+        Ok(Transaction::from_generic_sig_data(
+            data,
+            Intent::sui_transaction(),
+            vec![
+                GenericSignature::MultiSig(multisig_signature),
+                GenericSignature::Signature(sponsor_signature),
+            ],
+        ))
+    }
+
+    /// Sign a transaction sent by the zkLogin identity at `address`, using the fixture ephemeral
+    /// key and proof. Like multisig accounts, it has no gas of its own, so the default account
+    /// sponsors the transaction.
+    fn sign_zklogin_txn(
+        &self,
+        address: SuiAddress,
+        txn_data: impl FnOnce(
+            /* sender */ SuiAddress,
+            /* gas */ ObjectRef,
+            /* sponsor */ SuiAddress,
+        ) -> TransactionData,
+    ) -> anyhow::Result<Transaction> {
+        let sponsor = &self.default_account;
+        let gas_payment = self
+            .get_object(&sponsor.gas, None)?
+            .compute_object_reference();
+        let data = txn_data(address, gas_payment, sponsor.address);
+        let intent_msg = IntentMessage::new(Intent::sui_transaction(), data.clone());
+        let (_, _, zklogin_signature) = sign_zklogin_tx(data.clone(), /* legacy */ false);
+        let sponsor_signature = Signature::new_secure(&intent_msg, &sponsor.key_pair);
+        Ok(Transaction::from_generic_sig_data(
+            data,
+            Intent::sui_transaction(),
+            vec![
+                zklogin_signature,
+                GenericSignature::Signature(sponsor_signature),
+            ],
+        ))
+    }
+
+    /// The package that first defined the struct `tag` refers to, according to the type origin
+    /// table of the package `tag`'s address refers to.
+    fn type_origin(&self, tag: &StructTag) -> anyhow::Result<ObjectID> {
+        let package = self.get_object(&ObjectID::from(tag.address), None)?;
+        let Some(package) = package.data.try_as_package() else {
+            bail!("{} is not a package", tag.address)
+        };
+        package
+            .type_origin_table()
+            .iter()
+            .find(|origin| {
+                origin.module_name == tag.module.as_str() && origin.struct_name == tag.name.as_str()
+            })
+            .map(|origin| origin.package)
+            .ok_or_else(|| anyhow!("Package {} has no type origin for {tag}", tag.address))
+    }
+
+    /// The category `data` falls under for gas pricing, and the lowest gas price the authority
+    /// accepts for it. Transaction input checks hold both categories to the reference gas price of
+    /// the epoch the transaction runs in (see `SuiGasStatus::new`), and reject anything below it
+    /// with `GasPriceUnderRGP`.
+    fn gas_price_floor(&self, data: &TransactionData) -> anyhow::Result<(&'static str, u64)> {
+        let category = if data.contains_shared_object() {
+            "shared object"
+        } else {
+            "owned object"
+        };
+        Ok((category, self.get_reference_gas_price()?))
+    }
+
+    /// The reference gas price of the current epoch. Dev-inspect runs at this price when none is
+    /// given, the same as the authority would, so that the gas it reports is stable.
+    fn get_reference_gas_price(&self) -> anyhow::Result<u64> {
+        Ok(self.executor.get_system_state()?.reference_gas_price())
+    }
 
-        let pt = builder.finish();
+    /// `object` as it was before any transaction outside of `settled` was executed, following its
+    /// versions back through the transactions that wrote them, or `None` if it did not exist yet.
+    /// Objects written straight into the store, rather than by a transaction, are kept as they are.
+    fn object_before(
+        &self,
+        mut object: Object,
+        settled: &BTreeSet<TransactionDigest>,
+    ) -> anyhow::Result<Option<Object>> {
+        let id = object.id();
+        loop {
+            let digest = object.previous_transaction;
+            if settled.contains(&digest) {
+                return Ok(Some(object));
+            }
+            let Some(Some(effects)) = self.executor.multi_get_executed_effects(&[digest])?.pop()
+            else {
+                return Ok(Some(object));
+            };
+            let Some((_, version)) = effects
+                .modified_at_versions()
+                .into_iter()
+                .find(|(modified, _)| *modified == id)
+            else {
+                return Ok(None);
+            };
+            object = self.get_object(&id, Some(version))?;
+        }
+    }
 
-        let gas_price = self.gas_price;
-        let data = |sender, gas| {
-            TransactionData::new_programmable(sender, vec![gas], pt, gas_budget, gas_price)
+    /// The effects of executing the transaction built by `task`.
+    fn task_effects(&self, task: u64) -> anyhow::Result<sui_types::effects::TransactionEffects> {
+        let Some(transaction) = self.task_transactions.get(&task) else {
+            bail!("Task {task} did not build a transaction");
+        };
+        let Some(Some(effects)) = self
+            .executor
+            .multi_get_executed_effects(&[*transaction.digest()])?
+            .pop()
+        else {
+            bail!("Task {task} was not executed");
         };
+        Ok(effects)
+    }
 
-        let transaction = self.sign_txn(Some(sender), data);
-        let summary = self.execute_txn(transaction).await?;
-        let created_package = summary
-            .created
-            .iter()
-            .find_map(|id| {
-                let object = self.get_object(id, None).unwrap();
-                let package = object.data.try_as_package()?;
-                Some(package.id())
-            })
-            .unwrap();
-        let package_addr = NumericalAddress::new(created_package.into_bytes(), NumberFormat::Hex);
-        if let Some(new_package_name) = modules[0].0 {
-            let prev_package = self
-                .compiled_state
-                .named_address_mapping
-                .insert(new_package_name.to_string(), package_addr);
-            match prev_package.map(|a| a.into_inner()) {
-                Some(addr) if addr != AccountAddress::ZERO => panic!(
-                    "Cannot reuse named address '{}' for multiple packages. \
-                It should be set to 0 initially",
-                    new_package_name
-                ),
-                _ => (),
+    /// The package published (or upgraded) by `task`.
+    fn task_package(&self, task: u64) -> anyhow::Result<ObjectID> {
+        let effects = self.task_effects(task)?;
+        for ((id, version, _), owner) in effects.created() {
+            if owner == Owner::Immutable && self.get_object(&id, Some(version))?.is_package() {
+                return Ok(id);
             }
         }
-        let output = self.object_summary_output(&summary, /* summarize */ false);
-        Ok(output)
+        bail!("Task {task} did not publish a package")
     }
 
-    fn sign_txn(
+    /// The address of an account, multisig account or named address called `name`.
+    fn resolve_account(&self, name: &str) -> anyhow::Result<SuiAddress> {
+        if let Some(account) = self.accounts.get(name) {
+            return Ok(account.address);
+        }
+        if let Some(multisig) = self.multisig_accounts.get(name) {
+            return Ok(multisig.address);
+        }
+        match self.compiled_state().named_address_mapping.get(name) {
+            Some(addr) => Ok(SuiAddress::from(addr.into_inner())),
+            None => bail!("Unbound account {name}"),
+        }
+    }
+
+    /// Resolve `args` like `CompiledState::resolve_args`, except that the names of removed accounts
+    /// are unbound rather than falling back to the default address.
+    fn resolve_args(
         &self,
-        sender: Option<String>,
-        txn_data: impl FnOnce(/* sender */ SuiAddress, /* gas */ ObjectRef) -> TransactionData,
-    ) -> Transaction {
-        let test_account = self.get_sender(sender);
-        let gas_payment = self
-            .get_object(&test_account.gas, None)
-            .unwrap()
-            .compute_object_reference();
-        let data = txn_data(test_account.address, gas_payment);
-        to_sender_signed_transaction(data, &test_account.key_pair)
+        args: Vec<ParsedValue<SuiExtraValueArgs>>,
+    ) -> anyhow::Result<Vec<SuiValue>> {
+        let mapping = |name: &str| {
+            (!self.removed_accounts.contains(name))
+                .then(|| self.compiled_state.resolve_named_address(name))
+        };
+        args.into_iter()
+            .map(|arg| arg.into_concrete_value(&mapping))
+            .collect()
     }
 
-    fn get_sender(&self, sender: Option<String>) -> &TestAccount {
-        match sender {
+    /// Resolve the account signing a command: the explicit `--sender` if given, otherwise the
+    /// `--default-sender` declared in `init`, falling back to the unnamed default account.
+    fn get_sender(&self, sender: Option<String>) -> anyhow::Result<&TestAccount> {
+        Ok(match sender.or_else(|| self.default_sender.clone()) {
             Some(n) => match self.accounts.get(&n) {
                 Some(test_account) => test_account,
-                None => panic!("Unbound account {}", n),
+                None => bail!("Unknown account {n}"),
             },
             None => &self.default_account,
-        }
+        })
     }
 
     async fn execute_txn(&mut self, transaction: Transaction) -> anyhow::Result<TxnSummary> {
@@ -1023,7 +3383,23 @@ impl<'a> SuiTestAdapter<'a> {
             .intent_message()
             .value
             .contains_shared_object();
+        let input_ids = txn_input_ids(&transaction)?;
+        self.task_transactions
+            .insert(self.next_fake.0, transaction.clone());
         let (effects, error_opt) = self.executor.execute_txn(transaction).await?;
+        self.summarize_effects(input_ids, with_shared, effects, error_opt)
+            .await
+    }
+
+    /// Assign fake ids to the objects `effects` brought into storage and summarize them, along
+    /// with the transaction's events, or fail with its execution error.
+    async fn summarize_effects(
+        &mut self,
+        input_ids: Vec<ObjectID>,
+        with_shared: bool,
+        effects: TransactionEffects,
+        error_opt: Option<ExecutionError>,
+    ) -> anyhow::Result<TxnSummary> {
         let digest = effects.transaction_digest();
         let mut created_ids: Vec<_> = effects
             .created()
@@ -1047,6 +3423,15 @@ impl<'a> SuiTestAdapter<'a> {
             .map(|(id, _, _)| *id)
             .collect();
         let mut wrapped_ids: Vec<_> = effects.wrapped().iter().map(|(id, _, _)| *id).collect();
+        let modified_ids: BTreeSet<_> = effects
+            .modified_at_versions()
+            .into_iter()
+            .map(|(id, _)| id)
+            .collect();
+        let mut inputs: Vec<_> = input_ids
+            .into_iter()
+            .map(|id| (id, modified_ids.contains(&id)))
+            .collect();
         let gas_summary = effects.gas_cost_summary();
 
         // make sure objects that have previously not been in storage get assigned a fake id.
@@ -1057,7 +3442,7 @@ impl<'a> SuiTestAdapter<'a> {
             .collect();
 
         // Use a stable sort before assigning fake ids, so test output remains stable.
-        might_need_fake_id.sort_by_key(|id| self.get_object_sorting_key(id));
+        might_need_fake_id.sort_by_key(|id| (self.get_object_sorting_key(id), *id));
         for id in might_need_fake_id {
             self.enumerate_fake(id);
         }
@@ -1066,12 +3451,22 @@ impl<'a> SuiTestAdapter<'a> {
         // refer to them at their id in storage).
 
         // sort by fake id
-        created_ids.sort_by_key(|id| self.real_to_fake_object_id(id));
-        mutated_ids.sort_by_key(|id| self.real_to_fake_object_id(id));
-        unwrapped_ids.sort_by_key(|id| self.real_to_fake_object_id(id));
-        deleted_ids.sort_by_key(|id| self.real_to_fake_object_id(id));
-        unwrapped_then_deleted_ids.sort_by_key(|id| self.real_to_fake_object_id(id));
-        wrapped_ids.sort_by_key(|id| self.real_to_fake_object_id(id));
+        created_ids.sort_by_key(|id| self.object_sort_key(id));
+        mutated_ids.sort_by_key(|id| self.object_sort_key(id));
+        unwrapped_ids.sort_by_key(|id| self.object_sort_key(id));
+        deleted_ids.sort_by_key(|id| self.object_sort_key(id));
+        unwrapped_then_deleted_ids.sort_by_key(|id| self.object_sort_key(id));
+        wrapped_ids.sort_by_key(|id| self.object_sort_key(id));
+        inputs.sort_by_key(|(id, _)| self.object_sort_key(id));
+
+        // Remember which task removed each object, to tell apart using a removed object from using
+        // one that never existed.
+        for id in &unwrapped_ids {
+            self.removed_objects.remove(id);
+        }
+        for id in deleted_ids.iter().chain(&wrapped_ids) {
+            self.removed_objects.insert(*id, self.next_fake.0);
+        }
 
         match effects.status() {
             ExecutionStatus::Success { .. } => {
@@ -1088,6 +3483,7 @@ impl<'a> SuiTestAdapter<'a> {
                     deleted: deleted_ids,
                     unwrapped_then_deleted: unwrapped_then_deleted_ids,
                     wrapped: wrapped_ids,
+                    inputs,
                 })
             }
             ExecutionStatus::Failure { error, command } => {
@@ -1103,15 +3499,203 @@ impl<'a> SuiTestAdapter<'a> {
         }
     }
 
+    /// Fetch transaction `digest` from the fullnode at `rpc`, along with the objects it read that
+    /// are missing from the store at the versions it read them, then execute it again and compare
+    /// its effects with the fullnode's. Fails listing every divergence if they differ.
+    async fn replay_transaction(
+        &mut self,
+        digest: TransactionDigest,
+        rpc: &str,
+    ) -> anyhow::Result<Option<String>> {
+        let client = HttpClientBuilder::default().build(rpc)?;
+        let response = client
+            .get_transaction_block(
+                digest,
+                Some(
+                    SuiTransactionBlockResponseOptions::new()
+                        .with_raw_input()
+                        .with_effects(),
+                ),
+            )
+            .await?;
+        let Some(remote) = response.effects else {
+            bail!("Fullnode returned no effects for transaction {digest}");
+        };
+        let data: SenderSignedData = bcs::from_bytes(&response.raw_transaction)
+            .map_err(|e| anyhow!("Invalid raw transaction {digest}: {e}"))?;
+        let transaction = Transaction::new(data);
+        ensure!(
+            *transaction.digest() == digest,
+            "Fullnode returned transaction {} for {digest}",
+            transaction.digest()
+        );
+
+        // Owned inputs are read at the versions the transaction refers to, and shared inputs at
+        // the versions they were sequenced at, which only the effects record.
+        let tx_data = &transaction.data().intent_message().value;
+        let mut versioned = vec![];
+        let mut packages = vec![];
+        for input in tx_data.input_objects()? {
+            match input {
+                InputObjectKind::ImmOrOwnedMoveObject((id, version, _)) => {
+                    versioned.push((id, version))
+                }
+                InputObjectKind::MovePackage(id) => packages.push(id),
+                InputObjectKind::SharedMoveObject { .. } => (),
+            }
+        }
+        versioned.extend(
+            tx_data
+                .receiving_objects()
+                .into_iter()
+                .map(|(id, v, _)| (id, v)),
+        );
+        versioned.extend(
+            remote
+                .shared_objects()
+                .iter()
+                .map(|o| (o.object_id, o.version)),
+        );
+
+        let mut fetched: Vec<Object> = vec![];
+        for (id, version) in versioned {
+            if sui_types::storage::ObjectStore::get_object_by_key(&*self.executor, &id, version)?
+                .is_some()
+            {
+                continue;
+            }
+            let response = client
+                .try_get_past_object(id, version, Some(SuiObjectDataOptions::bcs_lossless()))
+                .await?;
+            let SuiPastObjectResponse::VersionFound(data) = response else {
+                bail!("Fullnode does not have object {id} at version {version}");
+            };
+            fetched.push(data.try_into()?);
+        }
+        // Packages are immutable, but the ones a package links against are not inputs of the
+        // transaction, so follow the linkage of every package fetched.
+        while let Some(id) = packages.pop() {
+            if sui_types::storage::ObjectStore::get_object(&*self.executor, &id)?.is_some()
+                || fetched.iter().any(|o| o.id() == id)
+            {
+                continue;
+            }
+            let data = client
+                .get_object(id, Some(SuiObjectDataOptions::bcs_lossless()))
+                .await?
+                .into_object()
+                .map_err(|e| anyhow!("Fullnode does not have package {id}: {e}"))?;
+            let package: Object = data.try_into()?;
+            if let Some(p) = package.data.try_as_package() {
+                packages.extend(p.linkage_table().values().map(|info| info.upgraded_id));
+            }
+            fetched.push(package);
+        }
+        for object in fetched {
+            self.enumerate_fake(object.id());
+            self.executor.insert_object(object).await?;
+        }
+
+        let summary = match (self.execute_txn(transaction).await, remote.status()) {
+            (Ok(summary), SuiExecutionStatus::Success) => summary,
+            (Err(e), SuiExecutionStatus::Failure { .. }) => {
+                return Ok(Some(format!("Failed, as on the fullnode\n{e}")));
+            }
+            (Ok(_), SuiExecutionStatus::Failure { error }) => {
+                bail!("Succeeded, but failed on the fullnode with {error}")
+            }
+            (Err(e), SuiExecutionStatus::Success) => {
+                bail!("Failed, but succeeded on the fullnode\n{e}")
+            }
+        };
+
+        let remote_ids =
+            |refs: &[OwnedObjectRef]| -> Vec<_> { refs.iter().map(|o| o.object_id()).collect() };
+        let remote_deleted_ids =
+            |refs: &[SuiObjectRef]| -> Vec<_> { refs.iter().map(|o| o.object_id).collect() };
+        let changes = [
+            ("created", &summary.created, remote_ids(remote.created())),
+            ("mutated", &summary.mutated, remote_ids(remote.mutated())),
+            (
+                "unwrapped",
+                &summary.unwrapped,
+                remote_ids(remote.unwrapped()),
+            ),
+            (
+                "deleted",
+                &summary.deleted,
+                remote_deleted_ids(remote.deleted()),
+            ),
+            (
+                "unwrapped_then_deleted",
+                &summary.unwrapped_then_deleted,
+                remote_deleted_ids(remote.unwrapped_then_deleted()),
+            ),
+            (
+                "wrapped",
+                &summary.wrapped,
+                remote_deleted_ids(remote.wrapped()),
+            ),
+        ];
+        let mut divergences = vec![];
+        for (kind, local, mut remote) in changes {
+            remote.sort_by_key(|id| self.object_sort_key(id));
+            if local.iter().collect::<BTreeSet<_>>() != remote.iter().collect::<BTreeSet<_>>() {
+                divergences.push(format!(
+                    "{kind}: {} locally, {} on the fullnode",
+                    self.list_objs(local, false),
+                    self.list_objs(&remote, false),
+                ));
+            }
+        }
+        if summary.gas_summary != *remote.gas_cost_summary() {
+            divergences.push(format!(
+                "gas summary: {} locally, {} on the fullnode",
+                summary.gas_summary,
+                remote.gas_cost_summary()
+            ));
+        }
+        ensure!(
+            divergences.is_empty(),
+            "Effects diverge from the fullnode's\n{}",
+            divergences.join("\n")
+        );
+        Ok(merge_output(
+            self.object_summary_output(&summary, /* summarize */ false, false),
+            Some("\nEffects match the fullnode's".to_string()),
+        ))
+    }
+
+    /// The epoch the executor is currently in. The latest checkpoint belongs to the previous
+    /// epoch if it is the one that closed it.
+    fn current_epoch(&self) -> anyhow::Result<EpochId> {
+        let latest_chk = self.executor.get_latest_checkpoint_sequence_number()?;
+        let chk = self
+            .executor
+            .get_verified_checkpoint_by_sequence_number(latest_chk)?;
+        Ok(match chk.data().end_of_epoch_data {
+            Some(_) => chk.data().epoch + 1,
+            None => chk.data().epoch,
+        })
+    }
+
+    /// Dev-inspect `transaction_kind` as `sender`. When no gas price is given the reference gas
+    /// price of the current epoch is used rather than leaving the choice to the executor, so the
+    /// gas figures reported in the output are stable across runs.
     async fn dev_inspect(
         &mut self,
         sender: SuiAddress,
         transaction_kind: TransactionKind,
         gas_price: Option<u64>,
+        gas_object: Option<ObjectID>,
     ) -> anyhow::Result<TxnSummary> {
+        let gas_price = match gas_price {
+            Some(gas_price) => gas_price,
+            None => self.get_reference_gas_price()?,
+        };
         let results = self
             .executor
-            .dev_inspect_transaction_block(sender, transaction_kind, gas_price)
+            .dev_inspect_transaction_block(sender, transaction_kind, Some(gas_price), gas_object)
             .await?;
         let DevInspectResults {
             effects, events, ..
@@ -1136,7 +3720,7 @@ impl<'a> SuiTestAdapter<'a> {
             .collect();
 
         // Use a stable sort before assigning fake ids, so test output remains stable.
-        might_need_fake_id.sort_by_key(|id| self.get_object_sorting_key(id));
+        might_need_fake_id.sort_by_key(|id| (self.get_object_sorting_key(id), *id));
         for id in might_need_fake_id {
             self.enumerate_fake(id);
         }
@@ -1145,12 +3729,12 @@ impl<'a> SuiTestAdapter<'a> {
         // refer to them at their id in storage).
 
         // sort by fake id
-        created_ids.sort_by_key(|id| self.real_to_fake_object_id(id));
-        mutated_ids.sort_by_key(|id| self.real_to_fake_object_id(id));
-        unwrapped_ids.sort_by_key(|id| self.real_to_fake_object_id(id));
-        deleted_ids.sort_by_key(|id| self.real_to_fake_object_id(id));
-        unwrapped_then_deleted_ids.sort_by_key(|id| self.real_to_fake_object_id(id));
-        wrapped_ids.sort_by_key(|id| self.real_to_fake_object_id(id));
+        created_ids.sort_by_key(|id| self.object_sort_key(id));
+        mutated_ids.sort_by_key(|id| self.object_sort_key(id));
+        unwrapped_ids.sort_by_key(|id| self.object_sort_key(id));
+        deleted_ids.sort_by_key(|id| self.object_sort_key(id));
+        unwrapped_then_deleted_ids.sort_by_key(|id| self.object_sort_key(id));
+        wrapped_ids.sort_by_key(|id| self.object_sort_key(id));
 
         match effects.status() {
             SuiExecutionStatus::Success { .. } => {
@@ -1168,6 +3752,7 @@ impl<'a> SuiTestAdapter<'a> {
                     deleted: deleted_ids,
                     unwrapped_then_deleted: unwrapped_then_deleted_ids,
                     wrapped: wrapped_ids,
+                    inputs: vec![],
                 })
             }
             SuiExecutionStatus::Failure { error } => Err(anyhow::anyhow!(self.stabilize_str(
@@ -1202,6 +3787,82 @@ impl<'a> SuiTestAdapter<'a> {
         }
     }
 
+    /// A line for every difference in owner or fields between `from` and `to`, two versions of
+    /// the Move object bound to `fake_id`.
+    fn object_changes(
+        &self,
+        fake_id: FakeID,
+        from: &Object,
+        to: &Object,
+    ) -> anyhow::Result<Vec<String>> {
+        let (Some(from_move), Some(to_move)) = (from.data.try_as_move(), to.data.try_as_move())
+        else {
+            bail!("Object {fake_id} is not a Move object")
+        };
+        let layout = from_move
+            .get_layout(ObjectFormatOptions::default(), &&*self)
+            .unwrap();
+        let from_struct = MoveStruct::simple_deserialize(from_move.contents(), &layout).unwrap();
+        let to_struct = MoveStruct::simple_deserialize(to_move.contents(), &layout).unwrap();
+
+        let mut changes = vec![];
+        if from.owner != to.owner {
+            changes.push(format!("Owner: {} -> {}", from.owner, to.owner));
+        }
+        diff_move_values(
+            "",
+            &MoveValue::Struct(from_struct),
+            &MoveValue::Struct(to_struct),
+            &mut changes,
+        );
+        Ok(changes)
+    }
+
+    /// How `view-object` prints `obj`, which is bound to `fake_id`.
+    fn object_view(&self, fake_id: FakeID, obj: &Object) -> String {
+        match &obj.data {
+            object::Data::Move(move_obj) => {
+                let layout = move_obj
+                    .get_layout(ObjectFormatOptions::default(), &&*self)
+                    .unwrap();
+                let move_struct =
+                    MoveStruct::simple_deserialize(move_obj.contents(), &layout).unwrap();
+                self.stabilize_str(format!(
+                    "Owner: {}\nVersion: {}\nContents: {}",
+                    &obj.owner,
+                    obj.version().value(),
+                    move_struct
+                ))
+            }
+            object::Data::Package(package) => {
+                let num_modules = package.serialized_module_map().len();
+                let modules = package
+                    .serialized_module_map()
+                    .keys()
+                    .cloned()
+                    .collect::<Vec<_>>()
+                    .join(", ");
+                assert!(!modules.is_empty());
+                if num_modules > 1 {
+                    format!("{}::{{{}}}", fake_id, modules)
+                } else {
+                    format!("{}::{}", fake_id, modules)
+                }
+            }
+        }
+    }
+
+    /// Orders objects by fake id, falling back to their real ids for objects without one, so that
+    /// the order does not depend on the order the effects listed them in.
+    fn object_sort_key(&self, id: &ObjectID) -> (Option<FakeID>, ObjectID) {
+        (self.real_to_fake_object_id(id), *id)
+    }
+
+    /// The task that deleted or wrapped object `id`, if it has not been unwrapped since.
+    pub(crate) fn removed_by(&self, id: &ObjectID) -> Option<u64> {
+        self.removed_objects.get(id).copied()
+    }
+
     pub(crate) fn fake_to_real_object_id(&self, fake_id: FakeID) -> Option<ObjectID> {
         self.object_enumeration.get_by_right(&fake_id).copied()
     }
@@ -1233,36 +3894,74 @@ impl<'a> SuiTestAdapter<'a> {
             deleted,
             unwrapped_then_deleted,
             wrapped,
+            inputs,
         }: &TxnSummary,
         summarize: bool,
+        show_inputs: bool,
     ) -> Option<String> {
+        // Effects do not promise an order for the objects they list, so sort them here rather
+        // than trusting every summary to have been built sorted.
+        let sorted = |objs: &[ObjectID]| {
+            let mut objs = objs.to_vec();
+            objs.sort_by_key(|id| self.object_sort_key(id));
+            objs
+        };
+        let mut inputs = inputs.clone();
+        inputs.sort_by_key(|(id, _)| self.object_sort_key(id));
+
         let mut out = String::new();
         if !events.is_empty() {
             write!(out, "events: {}", self.list_events(events, summarize)).unwrap();
         }
+        if show_inputs && !inputs.is_empty() {
+            if !out.is_empty() {
+                out.push('\n')
+            }
+            write!(out, "inputs: {}", self.list_inputs(&inputs, summarize)).unwrap();
+        }
         if !created.is_empty() {
             if !out.is_empty() {
                 out.push('\n')
             }
-            write!(out, "created: {}", self.list_objs(created, summarize)).unwrap();
+            write!(
+                out,
+                "created: {}",
+                self.list_objs(&sorted(created), summarize)
+            )
+            .unwrap();
         }
         if !mutated.is_empty() {
             if !out.is_empty() {
                 out.push('\n')
             }
-            write!(out, "mutated: {}", self.list_objs(mutated, summarize)).unwrap();
+            write!(
+                out,
+                "mutated: {}",
+                self.list_objs(&sorted(mutated), summarize)
+            )
+            .unwrap();
         }
         if !unwrapped.is_empty() {
             if !out.is_empty() {
                 out.push('\n')
             }
-            write!(out, "unwrapped: {}", self.list_objs(unwrapped, summarize)).unwrap();
+            write!(
+                out,
+                "unwrapped: {}",
+                self.list_objs(&sorted(unwrapped), summarize)
+            )
+            .unwrap();
         }
         if !deleted.is_empty() {
             if !out.is_empty() {
                 out.push('\n')
             }
-            write!(out, "deleted: {}", self.list_objs(deleted, summarize)).unwrap();
+            write!(
+                out,
+                "deleted: {}",
+                self.list_objs(&sorted(deleted), summarize)
+            )
+            .unwrap();
         }
         if !unwrapped_then_deleted.is_empty() {
             if !out.is_empty() {
@@ -1271,7 +3970,7 @@ impl<'a> SuiTestAdapter<'a> {
             write!(
                 out,
                 "unwrapped_then_deleted: {}",
-                self.list_objs(unwrapped_then_deleted, summarize)
+                self.list_objs(&sorted(unwrapped_then_deleted), summarize)
             )
             .unwrap();
         }
@@ -1279,7 +3978,12 @@ impl<'a> SuiTestAdapter<'a> {
             if !out.is_empty() {
                 out.push('\n')
             }
-            write!(out, "wrapped: {}", self.list_objs(wrapped, summarize)).unwrap();
+            write!(
+                out,
+                "wrapped: {}",
+                self.list_objs(&sorted(wrapped), summarize)
+            )
+            .unwrap();
         }
         out.push('\n');
         write!(out, "gas summary: {}", gas_summary).unwrap();
@@ -1302,6 +4006,24 @@ impl<'a> SuiTestAdapter<'a> {
             .join(", ")
     }
 
+    fn list_inputs(&self, inputs: &[(ObjectID, bool)], summarize: bool) -> String {
+        if summarize {
+            return format!("{}", inputs.len());
+        }
+        inputs
+            .iter()
+            .map(|(id, modified)| {
+                let obj = self.list_objs(&[*id], /* summarize */ false);
+                if *modified {
+                    format!("{obj} (modified)")
+                } else {
+                    format!("{obj} (read-only)")
+                }
+            })
+            .collect::<Vec<_>>()
+            .join(", ")
+    }
+
     fn list_objs(&self, objs: &[ObjectID], summarize: bool) -> String {
         if summarize {
             return format!("{}", objs.len());
@@ -1319,7 +4041,7 @@ impl<'a> SuiTestAdapter<'a> {
             .join(", ")
     }
 
-    fn stabilize_str(&self, input: impl AsRef<str>) -> String {
+    pub(crate) fn stabilize_str(&self, input: impl AsRef<str>) -> String {
         fn candidate_is_hex(s: &str) -> bool {
             const HEX_STR_LENGTH: usize = SUI_ADDRESS_LENGTH * 2;
             let n = s.len();
@@ -1526,6 +4248,7 @@ async fn create_validator_fullnode(
 async fn create_val_fullnode_executor(
     protocol_config: &ProtocolConfig,
     objects: &[Object],
+    shared_object_retries: u32,
 ) -> ValidatorWithFullnode {
     let (validator, fullnode) = create_validator_fullnode(protocol_config, objects).await;
 
@@ -1539,20 +4262,70 @@ async fn create_val_fullnode_executor(
         validator,
         fullnode,
         kv_store,
+        shared_object_retries,
+    }
+}
+
+/// Sign `pt` with extra, unused pure inputs, sized so that the signed transaction is exactly
+/// `target` bytes when serialized. No input is made `max_input_size` bytes or more.
+fn pad_transaction(
+    mut pt: ProgrammableTransaction,
+    target: usize,
+    max_input_size: usize,
+    sign: impl Fn(ProgrammableTransaction) -> anyhow::Result<Transaction>,
+) -> anyhow::Result<Transaction> {
+    fn uleb_len(mut n: usize) -> usize {
+        let mut len = 1;
+        while n >= 0x80 {
+            n >>= 7;
+            len += 1;
+        }
+        len
+    }
+
+    // An empty input contributes just the one byte of its length prefix, so an input of `len`
+    // bytes contributes `uleb_len(len) + len`
+    pt.inputs.push(CallArg::Pure(vec![]));
+    let unpadded = bcs::serialized_size(&sign(pt.clone())?)?;
+    let Some(mut room) = (target + 1).checked_sub(unpadded) else {
+        bail!("Transaction is already {unpadded} bytes, cannot pad it to {target}");
+    };
+    let full = max_input_size - 1;
+    let mut padding = vec![];
+    while room > full + uleb_len(full) {
+        padding.push(CallArg::Pure(vec![0; full]));
+        room -= full + uleb_len(full);
     }
+    let mut len = room.saturating_sub(1);
+    for _ in 0..3 {
+        len = room.saturating_sub(uleb_len(len));
+    }
+    *pt.inputs.last_mut().unwrap() = CallArg::Pure(vec![0; len]);
+    pt.inputs.extend(padding);
+
+    let transaction = sign(pt)?;
+    let size = bcs::serialized_size(&transaction)?;
+    ensure!(
+        size == target,
+        "Cannot pad transaction to exactly {target} bytes, the closest is {size}"
+    );
+    Ok(transaction)
 }
 
 struct AccountSetup {
     pub default_account: TestAccount,
     pub named_address_mapping: BTreeMap<String, NumericalAddress>,
     pub objects: Vec<Object>,
-    pub account_objects: BTreeMap<String, ObjectID>,
+    /// The gas coins of each named account, starting with the one it pays for gas with.
+    pub account_objects: BTreeMap<String, Vec<ObjectID>>,
     pub accounts: BTreeMap<String, TestAccount>,
 }
 
 fn create_accounts_objects(
     rng: &mut StdRng,
     account_names: BTreeSet<String>,
+    imported_accounts: Vec<(String, AccountKeyPair)>,
+    gas_coins_per_account: usize,
     additional_mapping: BTreeMap<String, NumericalAddress>,
 ) -> AccountSetup {
     // Initial list of named addresses with specified values
@@ -1561,33 +4334,51 @@ fn create_accounts_objects(
     let mut accounts = BTreeMap::new();
     let mut objects = vec![];
 
-    // Closure to create accounts with gas objects of value `GAS_FOR_TESTING`
-    let mut mk_account = || {
-        let (address, key_pair) = get_key_pair_from_rng(rng);
-        let obj = Object::with_id_owner_gas_for_testing(
-            ObjectID::new(rng.gen()),
-            address,
-            GAS_FOR_TESTING,
-        );
+    // Closure to create accounts with `coins` gas objects of value `GAS_FOR_TESTING`, the first
+    // of which it pays for gas with, signing with `key_pair` if one is provided, or a new one
+    // otherwise
+    let mut mk_account = |coins: usize, key_pair: Option<AccountKeyPair>| {
+        let (address, key_pair) = match key_pair {
+            Some(key_pair) => ((&key_pair.public()).into(), key_pair),
+            None => get_key_pair_from_rng(rng),
+        };
+        let gas_ids: Vec<_> = (0..coins)
+            .map(|_| {
+                let obj = Object::with_id_owner_gas_for_testing(
+                    ObjectID::new(rng.gen()),
+                    address,
+                    GAS_FOR_TESTING,
+                );
+                let id = obj.id();
+                objects.push(obj);
+                id
+            })
+            .collect();
         let test_account = TestAccount {
             address,
             key_pair,
-            gas: obj.id(),
+            gas: gas_ids[0],
         };
-        objects.push(obj);
-        test_account
+        (test_account, gas_ids)
     };
 
     // For each named Sui account without an address value, create an account with an adddress
-    // and a gas object
+    // and its gas objects
     for n in account_names {
-        let test_account = mk_account();
-        account_objects.insert(n.clone(), test_account.gas);
+        let (test_account, gas_ids) = mk_account(gas_coins_per_account, None);
+        account_objects.insert(n.clone(), gas_ids);
+        accounts.insert(n, test_account);
+    }
+    // Imported accounts are created after the others, so declaring them does not change the
+    // addresses the others are given
+    for (n, key_pair) in imported_accounts {
+        let (test_account, gas_ids) = mk_account(gas_coins_per_account, Some(key_pair));
+        account_objects.insert(n.clone(), gas_ids);
         accounts.insert(n, test_account);
     }
 
     // Make a default account with a gas object
-    let default_account = mk_account();
+    let (default_account, _) = mk_account(1, None);
 
     // For mappings where the address is specified, populate the named address mapping
     let additional_mapping =
@@ -1621,11 +4412,26 @@ fn create_accounts_objects(
 async fn init_val_fullnode_executor(
     mut rng: StdRng,
     account_names: BTreeSet<String>,
+    imported_accounts: Vec<(String, AccountKeyPair)>,
+    gas_coins_per_account: usize,
     additional_mapping: BTreeMap<String, NumericalAddress>,
     protocol_config: &ProtocolConfig,
+    shared_object_retries: u32,
+    genesis_objects: Vec<Object>,
 ) -> (Box<dyn TransactionalAdapter>, AccountSetup) {
-    let acc_setup = create_accounts_objects(&mut rng, account_names, additional_mapping);
-    let executor = create_val_fullnode_executor(protocol_config, &acc_setup.objects).await;
+    let mut acc_setup = create_accounts_objects(
+        &mut rng,
+        account_names,
+        imported_accounts,
+        gas_coins_per_account,
+        additional_mapping,
+    );
+    // Fixture objects go into genesis after the accounts' gas objects, so they are enumerated
+    // after them
+    acc_setup.objects.extend(genesis_objects);
+    let executor =
+        create_val_fullnode_executor(protocol_config, &acc_setup.objects, shared_object_retries)
+            .await;
     (Box::new(executor), acc_setup)
 }
 
@@ -1635,8 +4441,11 @@ async fn init_val_fullnode_executor(
 fn init_sim_executor(
     mut rng: StdRng,
     account_names: BTreeSet<String>,
+    imported_accounts: Vec<(String, AccountKeyPair)>,
+    gas_coins_per_account: usize,
     additional_mapping: BTreeMap<String, NumericalAddress>,
     protocol_config: &ProtocolConfig,
+    genesis_objects: Vec<Object>,
 ) -> (Box<dyn TransactionalAdapter>, AccountSetup) {
     // Initial list of named addresses with specified values
     let mut named_address_mapping = NAMED_ADDRESSES.clone();
@@ -1666,6 +4475,15 @@ fn init_sim_executor(
         let test_account = mk_account();
         accounts.insert(n, test_account);
     }
+    // Imported accounts' gas objects are created by the simulator, along with everyone else's
+    for (n, key_pair) in imported_accounts {
+        let test_account = TestAccount {
+            address: (&key_pair.public()).into(),
+            key_pair,
+            gas: ObjectID::ZERO,
+        };
+        accounts.insert(n, test_account);
+    }
 
     // Make a default account with a gas object
     let mut default_account = mk_account();
@@ -1674,7 +4492,7 @@ fn init_sim_executor(
         .values()
         .map(|acc| AccountConfig {
             address: Some(acc.address),
-            gas_amounts: vec![GAS_FOR_TESTING],
+            gas_amounts: vec![GAS_FOR_TESTING; gas_coins_per_account],
         })
         .collect::<Vec<_>>();
     acc_cfgs.push(AccountConfig {
@@ -1682,7 +4500,7 @@ fn init_sim_executor(
         gas_amounts: vec![GAS_FOR_TESTING],
     });
 
-    let sim = Simulacrum::new_with_protocol_version_and_accounts(
+    let mut sim = Simulacrum::new_with_protocol_version_and_accounts(
         rng,
         DEFAULT_CHAIN_START_TIMESTAMP,
         protocol_config.version,
@@ -1691,10 +4509,11 @@ fn init_sim_executor(
 
     // Get the actual object values from the simulator
     for (name, acc) in accounts.iter_mut() {
-        let o = sim.store().owned_objects(acc.address).next().unwrap();
-        acc.gas = o.id();
-        objects.push(o.clone());
-        account_objects.insert(name.clone(), o.id());
+        let mut coins: Vec<_> = sim.store().owned_objects(acc.address).cloned().collect();
+        coins.sort_by_key(|o| o.id());
+        acc.gas = coins[0].id();
+        account_objects.insert(name.clone(), coins.iter().map(|o| o.id()).collect());
+        objects.extend(coins);
     }
     let o = sim
         .store()
@@ -1704,6 +4523,13 @@ fn init_sim_executor(
     default_account.gas = o.id();
     objects.push(o.clone());
 
+    // Fixture objects are written straight into the store before any transaction runs, which is
+    // indistinguishable from them having been part of genesis
+    for object in genesis_objects {
+        sim.insert_object(object.clone());
+        objects.push(object);
+    }
+
     // For mappings where the address is specified, populate the named address mapping
     let additional_mapping =
         additional_mapping
@@ -1797,3 +4623,185 @@ impl sui_rest_api::node_state_getter::NodeStateGetter for SuiTestAdapter<'_> {
         sui_types::storage::ObjectStore::get_object(&*self.executor, object_id)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use clap::Parser;
+    use move_core_types::value::MoveStructLayout;
+    use move_transactional_test_runner::tasks::{InitCommand, TaskInput};
+    use shared_crypto::intent::Intent;
+    use sui_json_rpc_types::{SuiObjectData, SuiTransactionBlockResponse};
+    use sui_types::{
+        clock::Clock, effects::TransactionEffects, gas_coin::GasCoin,
+        programmable_transaction_builder::ProgrammableTransactionBuilder, transaction::GasData,
+    };
+
+    #[tokio::test]
+    async fn read_object_as_typed_struct() {
+        let (adapter, _) =
+            SuiTestAdapter::init(SyntaxChoice::Source, Some(&*PRE_COMPILED), None).await;
+
+        // Without any accounts, the default account's gas coin is the first object enumerated
+        let gas = FakeID::Enumerated(0, 0);
+        let coin: GasCoin = adapter.read_object_as(gas, &GasCoin::type_()).unwrap();
+        assert_eq!(coin.value(), GAS_FOR_TESTING);
+
+        let err = adapter
+            .read_object_as::<Clock>(gas, &Clock::type_())
+            .unwrap_err();
+        assert!(err.to_string().contains("expected 0x2::clock::Clock"));
+        assert!(adapter
+            .read_object_as::<GasCoin>(FakeID::Enumerated(7, 0), &GasCoin::type_())
+            .is_err());
+    }
+
+    #[tokio::test]
+    async fn get_object_ref_matches_object() {
+        let (adapter, _) =
+            SuiTestAdapter::init(SyntaxChoice::Source, Some(&*PRE_COMPILED), None).await;
+
+        let gas = FakeID::Enumerated(0, 0);
+        let id = adapter.fake_to_real_object_id(gas).unwrap();
+        let expected = adapter
+            .get_object(&id, None)
+            .unwrap()
+            .compute_object_reference();
+        assert_eq!(adapter.get_object_ref(gas).unwrap(), Some(expected));
+        assert_eq!(
+            adapter.get_object_ref(FakeID::Enumerated(7, 0)).unwrap(),
+            None
+        );
+    }
+
+    #[tokio::test]
+    async fn effects_output_independent_of_effects_order() {
+        let (mut adapter, _) =
+            SuiTestAdapter::init(SyntaxChoice::Source, Some(&*PRE_COMPILED), None).await;
+
+        // Several objects of the same type, which only their ids tell apart
+        let transaction = adapter
+            .sign_txn(None, |sender, gas| {
+                let mut builder = ProgrammableTransactionBuilder::new();
+                builder.pay_sui(vec![sender; 3], vec![1, 2, 3]).unwrap();
+                TransactionData::new_programmable(
+                    sender,
+                    vec![gas],
+                    builder.finish(),
+                    DEFAULT_GAS_BUDGET,
+                    adapter.gas_price,
+                )
+            })
+            .unwrap();
+        let mut summary = adapter.execute_txn(transaction).await.unwrap();
+        let expected = adapter.object_summary_output(&summary, false, true);
+
+        summary.created.reverse();
+        summary.mutated.reverse();
+        summary.inputs.reverse();
+        assert_eq!(
+            adapter.object_summary_output(&summary, false, true),
+            expected
+        );
+    }
+
+    /// Serve `transaction`, its `effects` and the `objects` it read over JSON-RPC, like a
+    /// fullnode would.
+    async fn mock_fullnode(
+        transaction: &Transaction,
+        effects: TransactionEffects,
+        objects: Vec<Object>,
+    ) -> (String, jsonrpsee::server::ServerHandle) {
+        let mut response = SuiTransactionBlockResponse::new(*transaction.digest());
+        response.raw_transaction = bcs::to_bytes(transaction.data()).unwrap();
+        response.effects = Some(effects.try_into().unwrap());
+
+        let mut module = jsonrpsee::RpcModule::new(());
+        module
+            .register_method("sui_getTransactionBlock", move |_, _| Ok(response.clone()))
+            .unwrap();
+        module
+            .register_method("sui_tryGetPastObject", move |params, _| {
+                let (id, version, _): (ObjectID, SequenceNumber, Option<SuiObjectDataOptions>) =
+                    params.parse()?;
+                let object = objects
+                    .iter()
+                    .find(|o| o.id() == id && o.version() == version);
+                Ok(match object {
+                    Some(o) => SuiPastObjectResponse::VersionFound(
+                        SuiObjectData::try_from((
+                            o.compute_object_reference(),
+                            o.clone(),
+                            None::<MoveStructLayout>,
+                            SuiObjectDataOptions::bcs_lossless(),
+                        ))
+                        .unwrap(),
+                    ),
+                    None => SuiPastObjectResponse::VersionNotFound(id, version),
+                })
+            })
+            .unwrap();
+
+        let server = jsonrpsee::server::ServerBuilder::default()
+            .build("127.0.0.1:0")
+            .await
+            .unwrap();
+        let url = format!("http://{}", server.local_addr().unwrap());
+        (url, server.start(module).unwrap())
+    }
+
+    #[tokio::test]
+    async fn replay_transaction_from_mock_fullnode() {
+        // Transfer from a gas coin on another network, which the adapter has never seen
+        let mut source = Simulacrum::new_with_rng(StdRng::from_seed([7; 32]));
+        let recipient = SuiAddress::random_for_testing_only();
+        let (gas, transaction) = {
+            let (sender, key) = source.keystore().accounts().next().unwrap();
+            let gas = source
+                .store()
+                .owned_objects(*sender)
+                .find(|o| o.is_gas_coin())
+                .unwrap()
+                .clone();
+            let mut builder = ProgrammableTransactionBuilder::new();
+            builder.transfer_sui(recipient, Some(1000));
+            let gas_data = GasData {
+                payment: vec![gas.compute_object_reference()],
+                owner: *sender,
+                price: source.reference_gas_price(),
+                budget: DEFAULT_GAS_BUDGET,
+            };
+            let kind = TransactionKind::ProgrammableTransaction(builder.finish());
+            let data = TransactionData::new_with_gas_data(kind, *sender, gas_data);
+            let transaction =
+                Transaction::from_data_and_signer(data, Intent::sui_transaction(), vec![key]);
+            (gas, transaction)
+        };
+        let (effects, _) = source.execute_transaction(transaction.clone()).unwrap();
+        let (url, _handle) = mock_fullnode(&transaction, effects, vec![gas]).await;
+
+        let init = TaskInput {
+            command: (
+                InitCommand {
+                    named_addresses: vec![],
+                },
+                SuiInitArgs::parse_from(["init", "--simulator"]),
+            ),
+            name: "init".to_string(),
+            number: 0,
+            start_line: 1,
+            command_lines_stop: 1,
+            stop_line: 1,
+            data: None,
+        };
+        let (mut adapter, _) =
+            SuiTestAdapter::init(SyntaxChoice::Source, Some(&*PRE_COMPILED), Some(init)).await;
+
+        let output = adapter
+            .replay_transaction(*transaction.digest(), &url)
+            .await
+            .unwrap()
+            .unwrap();
+        assert!(output.ends_with("Effects match the fullnode's"), "{output}");
+    }
+}
@@ -286,6 +286,8 @@ pub enum SuiObjectResponseError {
     Unknown,
     #[error("Display Error: {:?}", error)]
     DisplayError { error: String },
+    #[error("Contents Decode Error: {:?}", error)]
+    ContentsDecodeError { error: String },
     // TODO: also integrate SuiPastObjectResponse (VersionNotFound,  VersionTooHigh)
 }
 
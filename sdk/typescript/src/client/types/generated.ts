@@ -584,6 +584,10 @@ export type ObjectResponseError =
 	| {
 			code: 'displayError';
 			error: string;
+	  }
+	| {
+			code: 'contentsDecodeError';
+			error: string;
 	  };
 export interface SuiObjectResponseQuery {
 	/** If None, no filter will be applied */